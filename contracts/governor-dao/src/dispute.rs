@@ -1,8 +1,6 @@
 use crate::{errors::GovernorError, storage};
 use soroban_sdk::{panic_with_error, Address, Env, String};
 
-const TIME_ONE_DAY: u64 = 24 * 60 * 60;
-
 // Import the lance-protocol contract client
 mod lance_protocol {
     soroban_sdk::contractimport!(
@@ -11,7 +9,8 @@ mod lance_protocol {
 }
 
 /// Create a dispute for a proposal in the lance-protocol contract.
-/// This enables proposals to be disputed and resolved through anonymous voting.
+/// This enables proposals to be disputed and resolved through the voting mode,
+/// durations and court of the template this governor is registered with.
 pub fn create_dispute_for_proposal(
     env: &Env,
     creator: Address,
@@ -33,26 +32,22 @@ pub fn create_dispute_for_proposal(
         .get::<String, Address>(&String::from_str(env, "LanceProtocolContract"))
         .unwrap_or_else(|| panic_with_error!(env, GovernorError::InvalidKey));
 
-    // Calculate voting end time (e.g., 7 days from now)
-    let voting_ends_at = env.ledger().timestamp() + (7 * TIME_ONE_DAY);
-
     // Determine counterpart (for governance, it's typically the proposal creator)
     let counterpart = proposal_data.creator.clone();
 
     // Call lance-protocol contract to create the dispute
     let lance_client = lance_protocol::Client::new(env, &lance_protocol_contract);
-    let dispute = lance_client.create_dispute_demo(
+    let dispute = lance_client.create_template_dispute(
         &proposal_id,                    // project_id (using proposal_id as project_id)
         &creator,                        // creator (the one creating the dispute)
         &counterpart,                    // counterpart (the proposal creator)
         &proof,                          // proof/evidence
-        &voting_ends_at,                 // voting_ends_at
         &env.current_contract_address(), // called_contract (this governor contract)
-        // Note: `create_dispute_demo` in lance-protocol does not accept an `amount` argument.
-        // The `amount` parameter is currently unused here but kept in the function
-        // signature for compatibility; suppress unused-variable warning below.
     );
 
+    // Note: `create_template_dispute` in lance-protocol does not accept an `amount` argument.
+    // The `amount` parameter is currently unused here but kept in the function
+    // signature for compatibility; suppress unused-variable warning below.
     // suppress unused variable warning for `amount` until it's needed
     let _ = amount;
 
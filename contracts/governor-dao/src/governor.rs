@@ -137,6 +137,7 @@ pub trait Governor {
     /// ### Panics
     /// * If the proposal_id is invalid
     /// * If the lance-protocol contract address is not set
+    /// * If the governor is not registered as a caller on lance-protocol
     fn create_dispute_for_proposal(
         e: Env,
         creator: Address,
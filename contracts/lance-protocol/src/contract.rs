@@ -1,16 +1,64 @@
-use crate::methods::dispute::{claim_reward, execute, proof};
+// Entrypoints mirror the dispute parameters one to one, and the generated
+// client and argument helpers cannot be annotated individually.
+#![allow(clippy::too_many_arguments)]
+
+use crate::methods::dispute::{
+    claim_reward, claim_rewards, execute, get_ruling, proof, settle_jurors,
+};
 use crate::methods::{
-    admin::anonymous_voting_setup,
-    balance::{get_balance, redeem},
-    dispute::{create_dispute, create_dispute_demo},
+    admin::{anonymous_voting_setup, set_governance},
+    balance::{get_balance, redeem, redeem_to},
+    ballot::{
+        add_ballot_voters, draw_ballot_voters, finalize_ballot, get_ballot_votes, is_ballot_voter,
+        open_ballot, vote_ballot,
+    },
+    config::set_protocol_config,
+    court::{create_court, draw_jurors, join_court, leave_court},
+    dispute::{create_dispute, create_dispute_demo, create_template_dispute},
+    eligibility::{
+        check_juror, delegate_vote, reinstate_voter, set_eligibility_rules, suspend_voter,
+        undelegate_vote,
+    },
+    emission::{claim_emission, fund_emissions, reclaim_emission, set_emission_schedule},
+    fee::{
+        arbitration_cost, claim_default_ruling, get_reward_dust, pay_arbitration_fee,
+        set_arbitration_fee, sweep_reward_dust, sweep_unclaimed_rewards,
+    },
     initialize::initialize,
-    vote::{build_commitments_from_votes, commit_vote, register_to_vote, reveal_votes, vote},
+    registry::{register_caller, set_caller_policy, unregister_caller},
+    reputation::get_leaderboard,
+    settlement::{accept_settlement, propose_settlement},
+    token::{allow_token, is_token_allowed},
+    ttl::{bump, bump_ballot, bump_dispute},
+    vault::{get_stake, request_unstake, stake, withdraw_stake},
+    vote::{
+        build_commitments_from_votes, commit_vote, justify_anonymous_vote, prove_coherent_vote,
+        register_to_vote, reveal_justified_votes, reveal_votes, vote,
+    },
+};
+use crate::storage::ballot::{Ballot, BallotConfig, BallotStatus, BallotVote, get_ballot};
+use crate::storage::config::{ProtocolConfig, get_protocol_config};
+use crate::storage::court::{Court, get_court, is_court_juror, is_court_member};
+use crate::storage::dispute::{
+    DisputeRecord, JurorVote, Ruling, Settlement, get_anonymous_justification, get_dispute,
+    get_dispute_jurors, get_dispute_record, get_dispute_votes, get_justification, get_settlement,
 };
-use crate::storage::dispute::get_dispute;
 use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::emission::{
+    EmissionEpoch, EmissionSchedule, Emissions, get_emission_epoch, get_emission_schedule,
+    get_emissions,
+};
+use crate::storage::eligibility::{
+    EligibilityRules, Suspension, get_eligibility_rules, get_suspension,
+};
+use crate::storage::registry::{
+    CallerPolicy, DisputeTemplate, get_caller_policy, get_caller_template,
+};
+use crate::storage::vault::StakeBalance;
 use crate::storage::vote::{
-    AnonymousVoteConfig, VoteAnon, get_anonymous_voting_config as get_anon_config,
+    AnonymousVoteConfig, VoteAnon, VotingMode, get_anonymous_voting_config as get_anon_config,
 };
+use crate::storage::reputation::{JurorStats, LeaderboardEntry, get_juror_stats};
 use crate::storage::voter::{get_voter, set_voter};
 use crate::storage::{Dispute, Voter, error::Error};
 use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, contract, contractimpl};
@@ -26,8 +74,28 @@ pub trait ProtocolContractTrait {
 
     fn get_user(env: Env, user: Address) -> Result<Voter, Error>;
 
+    fn get_juror_stats(env: Env, juror: Address) -> JurorStats;
+
+    fn get_leaderboard(env: Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry>;
+
     fn get_dispute(env: Env, dispute_id: u32) -> Result<Dispute, Error>;
 
+    fn get_dispute_jurors(
+        env: Env,
+        dispute_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error>;
+
+    fn get_dispute_votes(
+        env: Env,
+        dispute_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<JurorVote>, Error>;
+
+    fn get_ruling(env: Env, dispute_id: u32) -> Result<Ruling, Error>;
+
     fn anonymous_voting_setup(env: Env, maintainer: Address, project_id: u32, public_key: String);
 
     fn get_anonymous_voting_config(env: Env, project_id: u32) -> AnonymousVoteConfig;
@@ -45,9 +113,10 @@ pub trait ProtocolContractTrait {
         creator: Address,
         counterpart: Address,
         proof: String,
-        voting_ends_at: u64,
+        voting_duration: u64,
         called_contract: Address,
-        // amount: i128,
+        voting_mode: VotingMode,
+        court_id: Option<u32>,
     ) -> Result<Dispute, Error>;
 
     fn create_dispute_demo(
@@ -56,22 +125,100 @@ pub trait ProtocolContractTrait {
         creator: Address,
         counterpart: Address,
         proof: String,
-        voting_ends_at: u64,
+        voting_duration: u64,
         called_contract: Address,
-    ) -> Result<Dispute, Error>;
+        voting_mode: VotingMode,
+        court_id: Option<u32>,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn create_template_dispute(
+        env: &Env,
+        project_id: u32,
+        creator: Address,
+        counterpart: Address,
+        proof: String,
+        called_contract: Address,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn register_caller(
+        env: Env,
+        admin: Address,
+        caller: Address,
+        template: DisputeTemplate,
+    ) -> Result<(), Error>;
+
+    fn unregister_caller(env: Env, admin: Address, caller: Address) -> Result<(), Error>;
+
+    fn get_caller_template(env: Env, caller: Address) -> Option<DisputeTemplate>;
+
+    fn set_caller_policy(env: Env, admin: Address, policy: CallerPolicy) -> Result<(), Error>;
+
+    fn get_caller_policy(env: Env) -> CallerPolicy;
+
+    fn arbitration_cost(env: Env, court_id: Option<u32>) -> Result<i128, Error>;
+
+    fn set_arbitration_fee(env: Env, admin: Address, fee: i128) -> Result<(), Error>;
+
+    fn allow_token(env: Env, admin: Address, token: Address, allowed: bool) -> Result<(), Error>;
+
+    fn is_token_allowed(env: Env, token: Address) -> bool;
+
+    fn set_protocol_config(env: Env, admin: Address, config: ProtocolConfig) -> Result<(), Error>;
+
+    fn get_protocol_config(env: Env) -> ProtocolConfig;
+
+    fn pay_arbitration_fee(
+        env: Env,
+        counterpart: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn claim_default_ruling(
+        env: Env,
+        creator: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn propose_settlement(
+        env: Env,
+        party: Address,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn accept_settlement(
+        env: Env,
+        party: Address,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn get_settlement(env: Env, dispute_id: u32) -> Option<Settlement>;
 
-    fn get_balance(env: &Env, employee: Address) -> i128;
+    fn get_balance(env: &Env, employee: Address, token: Address) -> i128;
 
-    fn redeem(env: &Env, employee: Address) -> Result<i128, Error>;
+    fn redeem(env: &Env, employee: Address, token: Address) -> Result<i128, Error>;
 
-    fn register_to_vote(env: &Env, creator: Address, dispute_id: u32) -> Result<Dispute, Error>;
+    fn redeem_to(
+        env: &Env,
+        owner: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error>;
+
+    fn register_to_vote(
+        env: &Env,
+        creator: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error>;
 
     fn commit_vote(
         env: &Env,
         voter: Address,
         dispute_id: u32,
         commit_hash: BytesN<32>,
-    ) -> Result<Dispute, Error>;
+    ) -> Result<DisputeRecord, Error>;
 
     fn reveal_votes(
         env: &Env,
@@ -79,10 +226,41 @@ pub trait ProtocolContractTrait {
         dispute_id: u32,
         votes: Vec<bool>,
         secrets: Vec<Bytes>,
-    ) -> Result<Dispute, Error>;
+    ) -> Result<DisputeRecord, Error>;
+
+    fn reveal_justified_votes(
+        env: &Env,
+        creator: Address,
+        dispute_id: u32,
+        votes: Vec<bool>,
+        secrets: Vec<Bytes>,
+        justifications: Vec<Option<BytesN<32>>>,
+    ) -> Result<DisputeRecord, Error>;
 
     fn vote(env: Env, voter: Address, dispute_id: u32, vote_data: VoteAnon);
 
+    fn justify_anonymous_vote(
+        env: Env,
+        dispute_id: u32,
+        nullifier: BytesN<32>,
+        justification: BytesN<32>,
+    ) -> Result<(), Error>;
+
+    fn prove_coherent_vote(
+        env: Env,
+        juror: Address,
+        dispute_id: u32,
+        seed: u128,
+    ) -> Result<(), Error>;
+
+    fn get_justification(env: Env, dispute_id: u32, juror: Address) -> Option<BytesN<32>>;
+
+    fn get_anonymous_justification(
+        env: Env,
+        dispute_id: u32,
+        nullifier: BytesN<32>,
+    ) -> Option<BytesN<32>>;
+
     fn execute(
         env: Env,
         maintainer: Address,
@@ -91,9 +269,152 @@ pub trait ProtocolContractTrait {
         seeds: Option<Vec<u128>>,
     ) -> DisputeStatus;
 
+    fn settle_jurors(env: Env, dispute_id: u32, limit: u32) -> Result<DisputeRecord, Error>;
+
     fn claim_reward(env: Env, voter: Address, dispute_id: u32) -> Result<(), Error>;
 
+    fn claim_rewards(env: Env, voter: Address, dispute_ids: Vec<u32>) -> Result<i128, Error>;
+
+    fn get_reward_dust(env: Env, token: Address) -> i128;
+
+    fn sweep_reward_dust(
+        env: Env,
+        admin: Address,
+        token: Address,
+        recipient: Address,
+    ) -> Result<i128, Error>;
+
+    fn sweep_unclaimed_rewards(env: Env, dispute_id: u32) -> Result<(i128, i128), Error>;
+
+    fn set_emission_schedule(
+        env: Env,
+        moderator: Address,
+        rate: i128,
+        epoch_duration: u64,
+    ) -> Result<(), Error>;
+
+    fn get_emission_schedule(env: Env) -> Option<EmissionSchedule>;
+
+    fn fund_emissions(env: Env, funder: Address, amount: i128) -> Result<i128, Error>;
+
+    fn get_emissions(env: Env) -> Emissions;
+
+    fn get_emission_epoch(env: Env, epoch: u32) -> Option<EmissionEpoch>;
+
+    fn claim_emission(env: Env, juror: Address, epoch: u32) -> Result<i128, Error>;
+
+    fn reclaim_emission(env: Env, epoch: u32) -> Result<i128, Error>;
+
     fn proof(env: Env, dispute_id: u32, tallies: Vec<u128>, seeds: Vec<u128>) -> bool;
+
+    fn create_court(
+        env: Env,
+        admin: Address,
+        name: String,
+        parent: Option<u32>,
+        fee: i128,
+        min_stake: i128,
+        token: Option<Address>,
+    ) -> Result<Court, Error>;
+
+    fn get_court(env: Env, court_id: u32) -> Result<Court, Error>;
+
+    fn join_court(env: Env, juror: Address, court_id: u32) -> Result<i128, Error>;
+
+    fn leave_court(env: Env, juror: Address, court_id: u32) -> Result<(), Error>;
+
+    fn is_court_member(env: Env, court_id: u32, juror: Address) -> bool;
+
+    fn is_court_juror(env: Env, court_id: u32, juror: Address) -> bool;
+
+    fn stake(env: Env, juror: Address, amount: i128) -> Result<StakeBalance, Error>;
+
+    fn request_unstake(env: Env, juror: Address, amount: i128) -> Result<StakeBalance, Error>;
+
+    fn withdraw_stake(env: Env, juror: Address) -> Result<i128, Error>;
+
+    fn get_stake(env: Env, juror: Address) -> StakeBalance;
+
+    fn draw_jurors(
+        env: Env,
+        moderator: Address,
+        dispute_id: u32,
+        count: u32,
+    ) -> Result<DisputeRecord, Error>;
+
+    fn set_eligibility_rules(env: Env, admin: Address, rules: EligibilityRules)
+    -> Result<(), Error>;
+
+    fn get_eligibility_rules(env: Env) -> EligibilityRules;
+
+    fn set_governance(env: Env, admin: Address, governance: Option<Address>)
+    -> Result<(), Error>;
+
+    fn suspend_voter(
+        env: Env,
+        moderator: Address,
+        voter: Address,
+        until: Option<u64>,
+    ) -> Result<(), Error>;
+
+    fn reinstate_voter(env: Env, moderator: Address, voter: Address) -> Result<(), Error>;
+
+    fn get_suspension(env: Env, voter: Address) -> Option<Suspension>;
+
+    fn delegate_vote(env: Env, delegator: Address, judge: Address) -> Result<(), Error>;
+
+    fn undelegate_vote(env: Env, delegator: Address, judge: Address) -> Result<(), Error>;
+
+    fn check_juror(env: Env, dispute_id: u32, juror: Address) -> Result<(), Error>;
+
+    fn bump(env: Env, user: Address, token: Address);
+
+    fn bump_dispute(env: Env, dispute_id: u32, offset: u32, limit: u32) -> Result<(), Error>;
+
+    fn open_ballot(
+        env: Env,
+        creator: Address,
+        project_id: u32,
+        config: BallotConfig,
+    ) -> Result<Ballot, Error>;
+
+    fn vote_ballot(env: Env, voter: Address, ballot_id: u32, vote: BallotVote)
+    -> Result<(), Error>;
+
+    fn finalize_ballot(
+        env: Env,
+        maintainer: Address,
+        ballot_id: u32,
+        tallies: Option<Vec<u128>>,
+        seeds: Option<Vec<u128>>,
+    ) -> Result<BallotStatus, Error>;
+
+    fn get_ballot(env: Env, ballot_id: u32) -> Result<Ballot, Error>;
+
+    fn is_ballot_voter(env: Env, ballot_id: u32, voter: Address) -> Result<bool, Error>;
+
+    fn add_ballot_voters(
+        env: Env,
+        creator: Address,
+        ballot_id: u32,
+        voters: Vec<Address>,
+    ) -> Result<Ballot, Error>;
+
+    fn draw_ballot_voters(
+        env: Env,
+        moderator: Address,
+        ballot_id: u32,
+        count: u32,
+    ) -> Result<Ballot, Error>;
+
+    fn get_ballot_votes(
+        env: Env,
+        ballot_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BallotVote>, Error>;
+
+    fn bump_ballot(env: Env, ballot_id: u32, offset: u32, limit: u32) -> Result<(), Error>;
 }
 
 #[contract]
@@ -135,12 +456,42 @@ impl ProtocolContractTrait for ProtocolContract {
         get_voter(&env, user)
     }
 
+    fn get_juror_stats(env: Env, juror: Address) -> JurorStats {
+        get_juror_stats(&env, &juror)
+    }
+
+    fn get_leaderboard(env: Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        get_leaderboard(&env, offset, limit)
+    }
+
     fn get_dispute(env: Env, dispute_id: u32) -> Result<Dispute, Error> {
         get_dispute(&env, dispute_id)
     }
 
-    fn get_balance(env: &Env, employee: Address) -> i128 {
-        get_balance(env, &employee)
+    fn get_dispute_jurors(
+        env: Env,
+        dispute_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, Error> {
+        get_dispute_jurors(&env, dispute_id, offset, limit)
+    }
+
+    fn get_dispute_votes(
+        env: Env,
+        dispute_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<JurorVote>, Error> {
+        get_dispute_votes(&env, dispute_id, offset, limit)
+    }
+
+    fn get_ruling(env: Env, dispute_id: u32) -> Result<Ruling, Error> {
+        get_ruling(&env, dispute_id)
+    }
+
+    fn get_balance(env: &Env, employee: Address, token: Address) -> i128 {
+        get_balance(env, &employee, &token)
     }
 
     fn create_dispute(
@@ -149,9 +500,10 @@ impl ProtocolContractTrait for ProtocolContract {
         creator: Address,
         counterpart: Address,
         proof: String,
-        voting_ends_at: u64,
+        voting_duration: u64,
         called_contract: Address,
-        // amount: i128,
+        voting_mode: VotingMode,
+        court_id: Option<u32>,
     ) -> Result<Dispute, Error> {
         create_dispute(
             env,
@@ -159,9 +511,10 @@ impl ProtocolContractTrait for ProtocolContract {
             creator,
             counterpart,
             proof,
-            voting_ends_at,
+            voting_duration,
             called_contract,
-            // amount,
+            voting_mode,
+            court_id,
         )
     }
 
@@ -171,25 +524,148 @@ impl ProtocolContractTrait for ProtocolContract {
         creator: Address,
         counterpart: Address,
         proof: String,
-        voting_ends_at: u64,
+        voting_duration: u64,
         called_contract: Address,
-    ) -> Result<Dispute, Error> {
+        voting_mode: VotingMode,
+        court_id: Option<u32>,
+    ) -> Result<DisputeRecord, Error> {
         create_dispute_demo(
             env,
             project_id,
             creator,
             counterpart,
             proof,
-            voting_ends_at,
+            voting_duration,
+            called_contract,
+            voting_mode,
+            court_id,
+        )
+    }
+
+    fn create_template_dispute(
+        env: &Env,
+        project_id: u32,
+        creator: Address,
+        counterpart: Address,
+        proof: String,
+        called_contract: Address,
+    ) -> Result<DisputeRecord, Error> {
+        create_template_dispute(
+            env,
+            project_id,
+            creator,
+            counterpart,
+            proof,
             called_contract,
         )
     }
 
-    fn redeem(env: &Env, employee: Address) -> Result<i128, Error> {
-        redeem(env, employee)
+    fn register_caller(
+        env: Env,
+        admin: Address,
+        caller: Address,
+        template: DisputeTemplate,
+    ) -> Result<(), Error> {
+        register_caller(&env, admin, caller, template)
+    }
+
+    fn unregister_caller(env: Env, admin: Address, caller: Address) -> Result<(), Error> {
+        unregister_caller(&env, admin, caller)
+    }
+
+    fn get_caller_template(env: Env, caller: Address) -> Option<DisputeTemplate> {
+        get_caller_template(&env, &caller)
+    }
+
+    fn set_caller_policy(env: Env, admin: Address, policy: CallerPolicy) -> Result<(), Error> {
+        set_caller_policy(&env, admin, policy)
+    }
+
+    fn get_caller_policy(env: Env) -> CallerPolicy {
+        get_caller_policy(&env)
     }
 
-    fn register_to_vote(env: &Env, creator: Address, dispute_id: u32) -> Result<Dispute, Error> {
+    fn arbitration_cost(env: Env, court_id: Option<u32>) -> Result<i128, Error> {
+        arbitration_cost(&env, court_id)
+    }
+
+    fn set_arbitration_fee(env: Env, admin: Address, fee: i128) -> Result<(), Error> {
+        set_arbitration_fee(&env, admin, fee)
+    }
+
+    fn allow_token(env: Env, admin: Address, token: Address, allowed: bool) -> Result<(), Error> {
+        allow_token(&env, admin, token, allowed)
+    }
+
+    fn is_token_allowed(env: Env, token: Address) -> bool {
+        is_token_allowed(&env, &token)
+    }
+
+    fn set_protocol_config(env: Env, admin: Address, config: ProtocolConfig) -> Result<(), Error> {
+        set_protocol_config(&env, admin, config)
+    }
+
+    fn get_protocol_config(env: Env) -> ProtocolConfig {
+        get_protocol_config(&env)
+    }
+
+    fn pay_arbitration_fee(
+        env: Env,
+        counterpart: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error> {
+        pay_arbitration_fee(&env, counterpart, dispute_id)
+    }
+
+    fn claim_default_ruling(
+        env: Env,
+        creator: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error> {
+        claim_default_ruling(&env, creator, dispute_id)
+    }
+
+    fn propose_settlement(
+        env: Env,
+        party: Address,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<DisputeRecord, Error> {
+        propose_settlement(&env, party, dispute_id, creator_share_bps)
+    }
+
+    fn accept_settlement(
+        env: Env,
+        party: Address,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<DisputeRecord, Error> {
+        accept_settlement(&env, party, dispute_id, creator_share_bps)
+    }
+
+    fn get_settlement(env: Env, dispute_id: u32) -> Option<Settlement> {
+        get_settlement(&env, dispute_id)
+    }
+
+    fn redeem(env: &Env, employee: Address, token: Address) -> Result<i128, Error> {
+        redeem(env, employee, token)
+    }
+
+    fn redeem_to(
+        env: &Env,
+        owner: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        redeem_to(env, owner, recipient, token, amount)
+    }
+
+    fn register_to_vote(
+        env: &Env,
+        creator: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeRecord, Error> {
         register_to_vote(env, creator, dispute_id)
     }
 
@@ -198,7 +674,7 @@ impl ProtocolContractTrait for ProtocolContract {
         voter: Address,
         dispute_id: u32,
         commit_hash: BytesN<32>,
-    ) -> Result<Dispute, Error> {
+    ) -> Result<DisputeRecord, Error> {
         commit_vote(env, voter, dispute_id, commit_hash)
     }
 
@@ -208,14 +684,55 @@ impl ProtocolContractTrait for ProtocolContract {
         dispute_id: u32,
         votes: Vec<bool>,
         secrets: Vec<Bytes>,
-    ) -> Result<Dispute, Error> {
+    ) -> Result<DisputeRecord, Error> {
         reveal_votes(env, creator, dispute_id, votes, secrets)
     }
 
+    fn reveal_justified_votes(
+        env: &Env,
+        creator: Address,
+        dispute_id: u32,
+        votes: Vec<bool>,
+        secrets: Vec<Bytes>,
+        justifications: Vec<Option<BytesN<32>>>,
+    ) -> Result<DisputeRecord, Error> {
+        reveal_justified_votes(env, creator, dispute_id, votes, secrets, justifications)
+    }
+
     fn vote(env: Env, voter: Address, dispute_id: u32, vote_data: VoteAnon) {
         vote(env, voter, dispute_id, vote_data);
     }
 
+    fn justify_anonymous_vote(
+        env: Env,
+        dispute_id: u32,
+        nullifier: BytesN<32>,
+        justification: BytesN<32>,
+    ) -> Result<(), Error> {
+        justify_anonymous_vote(&env, dispute_id, nullifier, justification)
+    }
+
+    fn prove_coherent_vote(
+        env: Env,
+        juror: Address,
+        dispute_id: u32,
+        seed: u128,
+    ) -> Result<(), Error> {
+        prove_coherent_vote(&env, juror, dispute_id, seed)
+    }
+
+    fn get_justification(env: Env, dispute_id: u32, juror: Address) -> Option<BytesN<32>> {
+        get_justification(&env, dispute_id, &juror)
+    }
+
+    fn get_anonymous_justification(
+        env: Env,
+        dispute_id: u32,
+        nullifier: BytesN<32>,
+    ) -> Option<BytesN<32>> {
+        get_anonymous_justification(&env, dispute_id, &nullifier)
+    }
+
     fn execute(
         env: Env,
         maintainer: Address,
@@ -226,12 +743,251 @@ impl ProtocolContractTrait for ProtocolContract {
         execute(env, maintainer, dispute_id, tallies, seeds)
     }
 
+    fn settle_jurors(env: Env, dispute_id: u32, limit: u32) -> Result<DisputeRecord, Error> {
+        settle_jurors(&env, dispute_id, limit)
+    }
+
     fn claim_reward(env: Env, voter: Address, dispute_id: u32) -> Result<(), Error> {
         claim_reward(env, voter, dispute_id)
     }
 
+    fn claim_rewards(env: Env, voter: Address, dispute_ids: Vec<u32>) -> Result<i128, Error> {
+        claim_rewards(env, voter, dispute_ids)
+    }
+
+    fn get_reward_dust(env: Env, token: Address) -> i128 {
+        get_reward_dust(&env, token)
+    }
+
+    fn sweep_reward_dust(
+        env: Env,
+        admin: Address,
+        token: Address,
+        recipient: Address,
+    ) -> Result<i128, Error> {
+        sweep_reward_dust(&env, admin, token, recipient)
+    }
+
+    fn sweep_unclaimed_rewards(env: Env, dispute_id: u32) -> Result<(i128, i128), Error> {
+        sweep_unclaimed_rewards(&env, dispute_id)
+    }
+
+    fn set_emission_schedule(
+        env: Env,
+        moderator: Address,
+        rate: i128,
+        epoch_duration: u64,
+    ) -> Result<(), Error> {
+        set_emission_schedule(&env, moderator, rate, epoch_duration)
+    }
+
+    fn get_emission_schedule(env: Env) -> Option<EmissionSchedule> {
+        get_emission_schedule(&env)
+    }
+
+    fn fund_emissions(env: Env, funder: Address, amount: i128) -> Result<i128, Error> {
+        fund_emissions(&env, funder, amount)
+    }
+
+    fn get_emissions(env: Env) -> Emissions {
+        get_emissions(&env)
+    }
+
+    fn get_emission_epoch(env: Env, epoch: u32) -> Option<EmissionEpoch> {
+        get_emission_epoch(&env, epoch)
+    }
+
+    fn claim_emission(env: Env, juror: Address, epoch: u32) -> Result<i128, Error> {
+        claim_emission(&env, juror, epoch)
+    }
+
+    fn reclaim_emission(env: Env, epoch: u32) -> Result<i128, Error> {
+        reclaim_emission(&env, epoch)
+    }
+
     fn proof(env: Env, dispute_id: u32, tallies: Vec<u128>, seeds: Vec<u128>) -> bool {
-        let dispute = get_dispute(&env, dispute_id).unwrap();
+        let dispute = get_dispute_record(&env, dispute_id).unwrap();
         proof(env, dispute, tallies, seeds)
     }
+
+    fn create_court(
+        env: Env,
+        admin: Address,
+        name: String,
+        parent: Option<u32>,
+        fee: i128,
+        min_stake: i128,
+        token: Option<Address>,
+    ) -> Result<Court, Error> {
+        create_court(&env, admin, name, parent, fee, min_stake, token)
+    }
+
+    fn get_court(env: Env, court_id: u32) -> Result<Court, Error> {
+        get_court(&env, court_id)
+    }
+
+    fn join_court(env: Env, juror: Address, court_id: u32) -> Result<i128, Error> {
+        join_court(&env, juror, court_id)
+    }
+
+    fn leave_court(env: Env, juror: Address, court_id: u32) -> Result<(), Error> {
+        leave_court(&env, juror, court_id)
+    }
+
+    fn is_court_member(env: Env, court_id: u32, juror: Address) -> bool {
+        is_court_member(&env, court_id, &juror)
+    }
+
+    fn is_court_juror(env: Env, court_id: u32, juror: Address) -> bool {
+        is_court_juror(&env, court_id, &juror)
+    }
+
+    fn stake(env: Env, juror: Address, amount: i128) -> Result<StakeBalance, Error> {
+        stake(&env, juror, amount)
+    }
+
+    fn request_unstake(env: Env, juror: Address, amount: i128) -> Result<StakeBalance, Error> {
+        request_unstake(&env, juror, amount)
+    }
+
+    fn withdraw_stake(env: Env, juror: Address) -> Result<i128, Error> {
+        withdraw_stake(&env, juror)
+    }
+
+    fn get_stake(env: Env, juror: Address) -> StakeBalance {
+        get_stake(&env, juror)
+    }
+
+    fn draw_jurors(
+        env: Env,
+        moderator: Address,
+        dispute_id: u32,
+        count: u32,
+    ) -> Result<DisputeRecord, Error> {
+        draw_jurors(&env, moderator, dispute_id, count)
+    }
+
+    fn set_eligibility_rules(
+        env: Env,
+        admin: Address,
+        rules: EligibilityRules,
+    ) -> Result<(), Error> {
+        set_eligibility_rules(&env, admin, rules)
+    }
+
+    fn get_eligibility_rules(env: Env) -> EligibilityRules {
+        get_eligibility_rules(&env)
+    }
+
+    fn set_governance(
+        env: Env,
+        admin: Address,
+        governance: Option<Address>,
+    ) -> Result<(), Error> {
+        set_governance(&env, admin, governance)
+    }
+
+    fn suspend_voter(
+        env: Env,
+        moderator: Address,
+        voter: Address,
+        until: Option<u64>,
+    ) -> Result<(), Error> {
+        suspend_voter(&env, moderator, voter, until)
+    }
+
+    fn reinstate_voter(env: Env, moderator: Address, voter: Address) -> Result<(), Error> {
+        reinstate_voter(&env, moderator, voter)
+    }
+
+    fn get_suspension(env: Env, voter: Address) -> Option<Suspension> {
+        get_suspension(&env, &voter)
+    }
+
+    fn delegate_vote(env: Env, delegator: Address, judge: Address) -> Result<(), Error> {
+        delegate_vote(&env, delegator, judge)
+    }
+
+    fn undelegate_vote(env: Env, delegator: Address, judge: Address) -> Result<(), Error> {
+        undelegate_vote(&env, delegator, judge)
+    }
+
+    fn check_juror(env: Env, dispute_id: u32, juror: Address) -> Result<(), Error> {
+        check_juror(&env, dispute_id, juror)
+    }
+
+    fn bump(env: Env, user: Address, token: Address) {
+        bump(&env, user, token)
+    }
+
+    fn bump_dispute(env: Env, dispute_id: u32, offset: u32, limit: u32) -> Result<(), Error> {
+        bump_dispute(&env, dispute_id, offset, limit)
+    }
+
+    fn open_ballot(
+        env: Env,
+        creator: Address,
+        project_id: u32,
+        config: BallotConfig,
+    ) -> Result<Ballot, Error> {
+        open_ballot(&env, creator, project_id, config)
+    }
+
+    fn vote_ballot(
+        env: Env,
+        voter: Address,
+        ballot_id: u32,
+        vote: BallotVote,
+    ) -> Result<(), Error> {
+        vote_ballot(&env, voter, ballot_id, vote)
+    }
+
+    fn finalize_ballot(
+        env: Env,
+        maintainer: Address,
+        ballot_id: u32,
+        tallies: Option<Vec<u128>>,
+        seeds: Option<Vec<u128>>,
+    ) -> Result<BallotStatus, Error> {
+        finalize_ballot(&env, maintainer, ballot_id, tallies, seeds)
+    }
+
+    fn get_ballot(env: Env, ballot_id: u32) -> Result<Ballot, Error> {
+        get_ballot(&env, ballot_id)
+    }
+
+    fn is_ballot_voter(env: Env, ballot_id: u32, voter: Address) -> Result<bool, Error> {
+        is_ballot_voter(&env, ballot_id, voter)
+    }
+
+    fn add_ballot_voters(
+        env: Env,
+        creator: Address,
+        ballot_id: u32,
+        voters: Vec<Address>,
+    ) -> Result<Ballot, Error> {
+        add_ballot_voters(&env, creator, ballot_id, voters)
+    }
+
+    fn draw_ballot_voters(
+        env: Env,
+        moderator: Address,
+        ballot_id: u32,
+        count: u32,
+    ) -> Result<Ballot, Error> {
+        draw_ballot_voters(&env, moderator, ballot_id, count)
+    }
+
+    fn get_ballot_votes(
+        env: Env,
+        ballot_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BallotVote>, Error> {
+        get_ballot_votes(&env, ballot_id, offset, limit)
+    }
+
+    fn bump_ballot(env: Env, ballot_id: u32, offset: u32, limit: u32) -> Result<(), Error> {
+        bump_ballot(&env, ballot_id, offset, limit)
+    }
 }
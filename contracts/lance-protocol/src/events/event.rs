@@ -1,4 +1,6 @@
-use soroban_sdk::{Address, String, contractevent};
+use soroban_sdk::{Address, BytesN, String, contractevent};
+
+use crate::storage::ballot::BallotStatus;
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,3 +27,245 @@ pub struct VoteCast {
     pub dispute_id: u32,
     pub voter: Address,
 }
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BallotOpened {
+    #[topic]
+    pub ballot_id: u32,
+    pub creator: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BallotVoteCast {
+    #[topic]
+    pub ballot_id: u32,
+    pub voter: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BallotFinalized {
+    #[topic]
+    pub ballot_id: u32,
+    pub status: BallotStatus,
+    pub winning_option: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationFeePaid {
+    #[topic]
+    pub dispute_id: u32,
+    pub payer: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesSettled {
+    #[topic]
+    pub dispute_id: u32,
+    pub winner: Option<Address>,
+    pub reward_pool: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsSwept {
+    #[topic]
+    pub dispute_id: u32,
+    pub reward: i128,
+    pub slashed: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementProposed {
+    #[topic]
+    pub dispute_id: u32,
+    pub proposer: Address,
+    pub creator_share_bps: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeSettled {
+    #[topic]
+    pub dispute_id: u32,
+    pub creator_share_bps: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourtCreated {
+    #[topic]
+    pub court_id: u32,
+    pub parent: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourtJoined {
+    #[topic]
+    pub court_id: u32,
+    pub juror: Address,
+    pub stake: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoterSuspended {
+    #[topic]
+    pub voter: Address,
+    pub until: Option<u64>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoterReinstated {
+    #[topic]
+    pub voter: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurorPenalized {
+    #[topic]
+    pub dispute_id: u32,
+    pub juror: Address,
+    pub penalty: u32,
+    pub reputation: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourtLeft {
+    #[topic]
+    pub court_id: u32,
+    pub juror: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardClaimed {
+    #[topic]
+    pub dispute_id: u32,
+    pub voter: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub slashed_share: i128, // In the protocol token
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redeemed {
+    #[topic]
+    pub owner: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallerRegistered {
+    #[topic]
+    pub caller: Address,
+    pub court_id: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallerUnregistered {
+    #[topic]
+    pub caller: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteJustified {
+    #[topic]
+    pub dispute_id: u32,
+    pub juror: Address,
+    pub justification: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoherentVoteProven {
+    #[topic]
+    pub dispute_id: u32,
+    pub juror: Address,
+    pub weight: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousVoteJustified {
+    #[topic]
+    pub dispute_id: u32,
+    pub nullifier: BytesN<32>,
+    pub justification: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionsFunded {
+    #[topic]
+    pub funder: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionClaimed {
+    #[topic]
+    pub epoch: u32,
+    pub juror: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionReclaimed {
+    #[topic]
+    pub epoch: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Staked {
+    #[topic]
+    pub juror: Address,
+    pub amount: i128,
+    pub staked: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnstakeRequested {
+    #[topic]
+    pub juror: Address,
+    pub amount: i128,
+    pub unbonding_ends_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeWithdrawn {
+    #[topic]
+    pub juror: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurorSlashed {
+    #[topic]
+    pub dispute_id: u32,
+    pub juror: Address,
+    pub amount: i128,
+}
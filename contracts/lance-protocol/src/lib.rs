@@ -8,4 +8,18 @@ mod storage;
 mod tests;
 // mod utils;
 
-pub use crate::contract::ProtocolContract;
+pub use crate::contract::{ProtocolContract, ProtocolContractClient};
+pub use crate::storage::{
+    ballot::{Ballot, BallotConfig, BallotStatus, BallotVote, Eligibility, PublicBallotVote},
+    config::ProtocolConfig,
+    court::Court,
+    dispute::{Dispute, Ruling, Settlement},
+    dispute_status::{DisputePhase, DisputeStatus},
+    eligibility::{EligibilityRules, Suspension},
+    emission::{EmissionEpoch, EmissionSchedule, Emissions},
+    error::Error,
+    registry::{CallerPolicy, DisputeTemplate},
+    reputation::{JurorStats, LeaderboardEntry},
+    vault::StakeBalance,
+    vote::{AnonymousVote, AnonymousVoteConfig, PublicVote, VoteAnon, VoteChoice, VotingMode},
+};
@@ -6,19 +6,72 @@ use crate::{
     events::event,
     storage::{
         DataKey,
-        // error::{self, Error},
+        error::Error,
+        storage::extend_instance,
         vote,
     },
 };
 
-// pub(crate) fn read_admin(env: &Env) -> Result<Address, Error> {
-//     let key = DataKey::Admin;
+pub(crate) fn read_admin(env: &Env) -> Result<Address, Error> {
+    let key = DataKey::Admin;
 
-//     env.storage()
-//         .instance()
-//         .get(&key)
-//         .ok_or(Error::AdminNotFound)
-// }
+    env.storage()
+        .instance()
+        .get(&key)
+        .ok_or(Error::AdminNotFound)
+}
+
+/// Require the authorization of the contract admin.
+pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    if *admin != read_admin(env)? {
+        return Err(Error::NotAuthorized);
+    }
+    Ok(())
+}
+
+pub(crate) fn read_governance(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Governance)
+}
+
+/// Let a governance contract moderate voters alongside the admin.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `governance` - The governance contract, or None to remove it
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the governance was updated
+pub(crate) fn set_governance(
+    env: &Env,
+    admin: Address,
+    governance: Option<Address>,
+) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    match governance {
+        Some(governance) => env
+            .storage()
+            .instance()
+            .set(&DataKey::Governance, &governance),
+        None => env.storage().instance().remove(&DataKey::Governance),
+    }
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Require the authorization of the contract admin or the governance.
+pub(crate) fn require_moderator(env: &Env, moderator: &Address) -> Result<(), Error> {
+    moderator.require_auth();
+
+    if *moderator != read_admin(env)? && Some(moderator.clone()) != read_governance(env) {
+        return Err(Error::NotAuthorized);
+    }
+    Ok(())
+}
 
 pub(crate) fn has_admin(env: &Env) -> bool {
     let key = DataKey::Admin;
@@ -102,6 +155,7 @@ pub(crate) fn anonymous_voting_setup(
     env.storage()
         .instance()
         .set(&DataKey::AnonymousVoteConfig(project_id), &vote_config);
+    extend_instance(&env);
 
     // // Emit event for anonymous voting setup
     event::AnonymousVotingSetup {
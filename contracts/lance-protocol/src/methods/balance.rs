@@ -1,29 +1,90 @@
 use soroban_sdk::{Env, Address};
-use crate::storage::{storage::DataKey, error::Error};
+use crate::storage::{storage::{DataKey, extend_instance, extend_persistent}, error::Error};
 use crate::methods::token::token_transfer;
+use crate::events;
 
-pub fn set_balance(env: &Env, user: &Address, amount: i128) {
-    let key = DataKey::Balances(user.clone());
+pub fn set_balance(env: &Env, user: &Address, token: &Address, amount: i128) {
+    let key = DataKey::Balances(user.clone(), token.clone());
     env.storage().persistent().set(&key, &amount);
+    extend_persistent(env, &key);
 }
 
-pub fn get_balance(env: &Env, user: &Address) -> i128 {
-    let key = DataKey::Balances(user.clone());
+pub fn get_balance(env: &Env, user: &Address, token: &Address) -> i128 {
+    let key = DataKey::Balances(user.clone(), token.clone());
+    extend_persistent(env, &key);
     env.storage().persistent().get(&key).unwrap_or(0)
 }
 
-pub fn redeem(env: &Env, employee: Address) -> Result<i128, Error> {
+pub fn redeem(env: &Env, employee: Address, token: Address) -> Result<i128, Error> {
     employee.require_auth();
+    extend_instance(env);
 
-    let balance = get_balance(env, &employee);
+    let balance = get_balance(env, &employee, &token);
 
     if balance == 0 {
         return Err(Error::BalanceIsZero);
     }
 
-    set_balance(env, &employee, 0);
-
-    token_transfer(env, &env.current_contract_address(), &employee, &balance)?;
+    pay_out(env, &employee, &employee, &token, balance)?;
 
     Ok(balance)
+}
+
+/// Redeem part of a balance, paying it to any address.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `owner` - The owner of the balance
+/// * `recipient` - The address receiving the tokens
+/// * `token` - The token of the balance
+/// * `amount` - The amount to redeem, at most the whole balance
+///
+/// # Returns
+/// * `Result<i128, Error>` - The balance left to the owner
+pub fn redeem_to(
+    env: &Env,
+    owner: Address,
+    recipient: Address,
+    token: Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    owner.require_auth();
+    extend_instance(env);
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    let balance = get_balance(env, &owner, &token);
+    if balance == 0 {
+        return Err(Error::BalanceIsZero);
+    }
+    if amount > balance {
+        return Err(Error::InsufficientBalance);
+    }
+
+    pay_out(env, &owner, &recipient, &token, amount)?;
+
+    Ok(balance - amount)
+}
+
+/// Debit a balance and transfer the tokens out of the contract.
+fn pay_out(
+    env: &Env,
+    owner: &Address,
+    recipient: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    set_balance(env, owner, token, get_balance(env, owner, token) - amount);
+    token_transfer(env, token, &env.current_contract_address(), recipient, &amount)?;
+
+    events::Redeemed {
+        owner: owner.clone(),
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount,
+    }
+    .publish(env);
+
+    Ok(())
 }
\ No newline at end of file
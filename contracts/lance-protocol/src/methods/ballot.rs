@@ -0,0 +1,458 @@
+use soroban_sdk::{Address, Env, Map, Vec};
+
+use crate::{
+    events,
+    methods::{
+        admin::require_moderator,
+        court::DRAW_ATTEMPTS_PER_JUROR,
+        tally::{count_anonymous_vote, count_public_vote, majority_option, new_tally, open_tally},
+        vote::MAX_VOTES_PER_PROPOSAL,
+    },
+    storage::{
+        DataKey,
+        ballot::{
+            Ballot, BallotConfig, BallotStatus, BallotVote, Eligibility, add_ballot_vote,
+            add_eligible_voter, get_ballot, get_ballot_votes as read_ballot_votes, has_ballot_vote,
+            is_eligible, set_ballot,
+        },
+        config::get_protocol_config,
+        eligibility::is_suspended,
+        error::Error,
+        reputation::{get_registered_voter_at, get_voter_count},
+        storage::extend_instance,
+        vault::get_stake_vault,
+        vote::{VotingMode, get_anonymous_voting_config},
+    },
+};
+
+const MIN_BALLOT_OPTIONS: u32 = 2;
+const MAX_BALLOT_OPTIONS: u32 = 10;
+
+/// Listed voters registered by one call, each writing two entries
+pub(crate) const MAX_LISTED_VOTERS_PER_CALL: u32 = 20;
+/// Jurors drawn by one call, each sampling up to `DRAW_ATTEMPTS_PER_JUROR`
+/// voters
+pub(crate) const MAX_DRAWN_VOTERS_PER_CALL: u32 = 5;
+
+// Same cap as dispute votes until voting power is implemented
+const MAX_VOTE_WEIGHT: u32 = 100;
+
+/// Open a new ballot.
+///
+/// Any dApp can open a ballot over its own options. Votes are either public,
+/// tallied on-chain, or anonymous, proven at finalization against BLS12-381
+/// commitments built with the project's anonymous voting configuration.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `creator` - The address opening the ballot
+/// * `project_id` - The project holding the anonymous voting configuration
+/// * `config` - Title, options, eligibility, mode, deadlines and quorum
+///
+/// # Returns
+/// * `Result<Ballot, Error>` - The new ballot
+pub fn open_ballot(
+    env: &Env,
+    creator: Address,
+    project_id: u32,
+    config: BallotConfig,
+) -> Result<Ballot, Error> {
+    creator.require_auth();
+    extend_instance(env);
+
+    let options = config.options.len();
+    if !(MIN_BALLOT_OPTIONS..=MAX_BALLOT_OPTIONS).contains(&options) {
+        return Err(Error::InvalidBallotOptions);
+    }
+
+    if config.voting_starts_at >= config.voting_ends_at
+        || config.voting_ends_at <= env.ledger().timestamp()
+    {
+        return Err(Error::InvalidDuration);
+    }
+
+    match config.voting_mode {
+        VotingMode::Public => (),
+        VotingMode::Anonymous => {
+            // fail early rather than when the first vote is proven
+            get_anonymous_voting_config(env, project_id);
+        }
+        // the reveal is tied to the dispute creator, ballots are public or anonymous
+        VotingMode::CommitReveal => return Err(Error::WrongVoteType),
+    }
+
+    let (voters, jurors) = match config.eligibility {
+        Eligibility::Voters(voters) => (voters, 0),
+        Eligibility::JurorDraw(jurors) => {
+            // jurors are drawn before voting starts
+            if jurors == 0
+                || jurors > MAX_VOTES_PER_PROPOSAL
+                || config.voting_starts_at <= env.ledger().timestamp()
+            {
+                return Err(Error::InvalidEligibility);
+            }
+            (Vec::new(env), jurors)
+        }
+    };
+    if jurors == 0 && voters.is_empty() {
+        return Err(Error::InvalidEligibility);
+    }
+
+    let ballot_id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::BallotId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::BallotId, &ballot_id);
+
+    let mut ballot = Ballot {
+        ballot_id,
+        project_id,
+        creator: creator.clone(),
+        title: config.title,
+        options: config.options,
+        voting_mode: config.voting_mode,
+        voting_starts_at: config.voting_starts_at,
+        voting_ends_at: config.voting_ends_at,
+        quorum: config.quorum,
+        status: BallotStatus::Open,
+        winning_option: None,
+        tally: new_tally(env, options),
+        voter_count: 0,
+        vote_count: 0,
+        jurors,
+    };
+    add_listed_voters(env, &mut ballot, &voters)?;
+
+    set_ballot(env, &ballot);
+
+    events::BallotOpened { ballot_id, creator }.publish(env);
+
+    Ok(ballot)
+}
+
+/// Allow a page of listed voters on a ballot.
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the voters were added, `BatchTooLarge` past
+///   `MAX_LISTED_VOTERS_PER_CALL`, or `InvalidEligibility` if a voter is
+///   repeated, already eligible or over `MAX_VOTES_PER_PROPOSAL`
+fn add_listed_voters(env: &Env, ballot: &mut Ballot, voters: &Vec<Address>) -> Result<(), Error> {
+    if voters.len() > MAX_LISTED_VOTERS_PER_CALL {
+        return Err(Error::BatchTooLarge);
+    }
+    // a repeated voter would be counted twice
+    require_distinct(env, voters)?;
+    if ballot.voter_count + voters.len() > MAX_VOTES_PER_PROPOSAL {
+        return Err(Error::InvalidEligibility);
+    }
+
+    for voter in voters.iter() {
+        if is_eligible(env, ballot.ballot_id, &voter) {
+            return Err(Error::InvalidEligibility);
+        }
+        add_eligible_voter(env, ballot, &voter);
+    }
+    Ok(())
+}
+
+/// Read a ballot whose eligible voters can still change.
+fn get_unstarted_ballot(env: &Env, ballot_id: u32) -> Result<Ballot, Error> {
+    let ballot = get_ballot(env, ballot_id)?;
+
+    if env.ledger().timestamp() >= ballot.voting_starts_at {
+        return Err(Error::ProposalVotingTime);
+    }
+    Ok(ballot)
+}
+
+/// List more voters on a ballot before voting starts.
+///
+/// Voters are registered in pages of at most `MAX_LISTED_VOTERS_PER_CALL`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `creator` - The creator of the ballot
+/// * `ballot_id` - The ID of a ballot with listed voters
+/// * `voters` - The voters to add
+///
+/// # Returns
+/// * `Result<Ballot, Error>` - The ballot, or `InvalidEligibility` if its
+///   jurors are drawn
+pub fn add_ballot_voters(
+    env: &Env,
+    creator: Address,
+    ballot_id: u32,
+    voters: Vec<Address>,
+) -> Result<Ballot, Error> {
+    creator.require_auth();
+    extend_instance(env);
+
+    let mut ballot = get_unstarted_ballot(env, ballot_id)?;
+    if creator != ballot.creator {
+        return Err(Error::NotAuthorized);
+    }
+    if ballot.jurors > 0 {
+        return Err(Error::InvalidEligibility);
+    }
+
+    add_listed_voters(env, &mut ballot, &voters)?;
+    set_ballot(env, &ballot);
+
+    Ok(ballot)
+}
+
+/// Draw jurors of a ballot at random from the registered voters.
+///
+/// A moderator draws the jurors before voting starts, in pages of at most
+/// `MAX_DRAWN_VOTERS_PER_CALL`. The contract prng is not secure randomness,
+/// so the draw is kept away from the ballot creator and the voters. Each
+/// attempt samples a registered voter still in the pool; voters already
+/// drawn, suspended, the creator, or whose bonded stake does not cover the
+/// juror stake of the protocol config leave the pool. The draw stops after
+/// `DRAW_ATTEMPTS_PER_JUROR` attempts per juror to draw, so the cost does not
+/// grow with the pool.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `moderator` - The contract admin or the governance
+/// * `ballot_id` - The ID of a ballot with drawn jurors
+/// * `count` - The number of jurors to draw
+///
+/// # Returns
+/// * `Result<Ballot, Error>` - The ballot, or `InvalidEligibility` if not
+///   enough eligible jurors were drawn
+pub fn draw_ballot_voters(
+    env: &Env,
+    moderator: Address,
+    ballot_id: u32,
+    count: u32,
+) -> Result<Ballot, Error> {
+    require_moderator(env, &moderator)?;
+    extend_instance(env);
+
+    let mut ballot = get_unstarted_ballot(env, ballot_id)?;
+    if count > MAX_DRAWN_VOTERS_PER_CALL {
+        return Err(Error::BatchTooLarge);
+    }
+    if count == 0 || ballot.voter_count + count > ballot.jurors {
+        return Err(Error::InvalidEligibility);
+    }
+
+    // Sparse Fisher-Yates shuffle of the registered voters, as for courts
+    let pool = get_voter_count(env);
+    let juror_stake = get_protocol_config(env).juror_stake;
+    let mut moved: Map<u32, u32> = Map::new(env);
+    let mut drawn = 0;
+    for next in 0..pool.min(count * DRAW_ATTEMPTS_PER_JUROR) {
+        if drawn == count {
+            break;
+        }
+        let pick = env.prng().gen_range::<u64>(next as u64..pool as u64) as u32;
+        let voter = get_registered_voter_at(env, moved.get(pick).unwrap_or(pick));
+        moved.set(pick, moved.get(next).unwrap_or(next));
+
+        if voter == ballot.creator
+            || is_eligible(env, ballot_id, &voter)
+            || is_suspended(env, &voter)
+            || get_stake_vault(env, &voter).staked < juror_stake
+        {
+            continue;
+        }
+        add_eligible_voter(env, &mut ballot, &voter);
+        drawn += 1;
+    }
+    if drawn < count {
+        return Err(Error::InvalidEligibility);
+    }
+    set_ballot(env, &ballot);
+
+    Ok(ballot)
+}
+
+/// Cast a vote on a ballot.
+///
+/// Public votes pick an option index and are tallied on-chain. Anonymous
+/// votes carry one commitment per option which is folded, weighted, into the
+/// running tally commitments.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `voter` - The address of the voter
+/// * `ballot_id` - The ID of the ballot to vote on
+/// * `vote` - The vote data (public or anonymous)
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the vote was recorded
+pub fn vote_ballot(
+    env: &Env,
+    voter: Address,
+    ballot_id: u32,
+    vote: BallotVote,
+) -> Result<(), Error> {
+    voter.require_auth();
+    extend_instance(env);
+
+    let mut ballot = get_ballot(env, ballot_id)?;
+
+    if ballot.status != BallotStatus::Open {
+        return Err(Error::BallotNotOpen);
+    }
+
+    let curr_timestamp = env.ledger().timestamp();
+    if curr_timestamp < ballot.voting_starts_at || curr_timestamp >= ballot.voting_ends_at {
+        return Err(Error::ProposalVotingTime);
+    }
+
+    if !is_eligible(env, ballot_id, &voter) {
+        return Err(Error::NotEligible);
+    }
+
+    if has_ballot_vote(env, ballot_id, &voter) {
+        return Err(Error::AlreadyVoted);
+    }
+
+    if *vote.address() != voter {
+        return Err(Error::WrongVoter);
+    }
+
+    let vote_weight = vote.weight();
+    if vote_weight > MAX_VOTE_WEIGHT {
+        return Err(Error::VoterWeight);
+    }
+
+    match (ballot.voting_mode, &vote) {
+        (VotingMode::Public, BallotVote::PublicVote(public_vote)) => {
+            count_public_vote(&mut ballot.tally, public_vote.option, vote_weight)?
+        }
+        (VotingMode::Anonymous, BallotVote::AnonymousVote(anonymous_vote)) => count_anonymous_vote(
+            env,
+            &mut ballot.tally,
+            &anonymous_vote.commitments,
+            vote_weight,
+        )?,
+        _ => return Err(Error::WrongVoteType),
+    }
+
+    add_ballot_vote(env, &mut ballot, &voter, &vote);
+
+    set_ballot(env, &ballot);
+
+    events::BallotVoteCast { ballot_id, voter }.publish(env);
+
+    Ok(())
+}
+
+/// Finalize a ballot after the voting period ends.
+///
+/// Ballots which did not reach the quorum end without a result. Otherwise,
+/// public ballots use the on-chain tallies and anonymous ballots must provide
+/// the decoded tallies and seeds, which are checked against the accumulated
+/// commitments. An option needs more than half of all votes to win.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainer` - The address finalizing the ballot
+/// * `ballot_id` - The ID of the ballot to finalize
+/// * [`Option<tallies>`] - decoded tally values (scaled by weights), one per option
+/// * [`Option<seeds>`] - decoded seed values (scaled by weights), one per option
+///
+/// # Returns
+/// * `Result<BallotStatus, Error>` - The final status of the ballot
+pub fn finalize_ballot(
+    env: &Env,
+    maintainer: Address,
+    ballot_id: u32,
+    tallies: Option<Vec<u128>>,
+    seeds: Option<Vec<u128>>,
+) -> Result<BallotStatus, Error> {
+    maintainer.require_auth();
+    extend_instance(env);
+
+    let mut ballot = get_ballot(env, ballot_id)?;
+
+    if ballot.status != BallotStatus::Open {
+        return Err(Error::BallotNotOpen);
+    }
+    if env.ledger().timestamp() < ballot.voting_ends_at {
+        return Err(Error::ProposalVotingTime);
+    }
+
+    if ballot.tally.total_weight < ballot.quorum {
+        ballot.status = BallotStatus::NoQuorum;
+    } else {
+        if ballot.voting_mode == VotingMode::Anonymous {
+            open_tally(env, ballot.project_id, &mut ballot.tally, tallies, seeds)?;
+        }
+
+        ballot.winning_option = majority_option(&ballot.tally.tallies);
+        ballot.status = match ballot.winning_option {
+            Some(_) => BallotStatus::Decided,
+            None => BallotStatus::Tied,
+        };
+    }
+
+    set_ballot(env, &ballot);
+
+    events::BallotFinalized {
+        ballot_id,
+        status: ballot.status,
+        winning_option: ballot.winning_option,
+    }
+    .publish(env);
+
+    Ok(ballot.status)
+}
+
+/// Reject a list of addresses holding the same address twice.
+fn require_distinct(env: &Env, addresses: &Vec<Address>) -> Result<(), Error> {
+    let mut seen = Map::new(env);
+    for address in addresses.iter() {
+        if seen.contains_key(address.clone()) {
+            return Err(Error::InvalidEligibility);
+        }
+        seen.set(address, ());
+    }
+    Ok(())
+}
+
+/// Check whether an address can vote on a ballot.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `ballot_id` - The ID of the ballot
+/// * `voter` - The address to check
+///
+/// # Returns
+/// * `Result<bool, Error>` - True if the address is eligible
+pub fn is_ballot_voter(env: &Env, ballot_id: u32, voter: Address) -> Result<bool, Error> {
+    get_ballot(env, ballot_id)?;
+
+    Ok(is_eligible(env, ballot_id, &voter))
+}
+
+/// Page through the votes cast on a ballot.
+///
+/// Maintainers decrypt the anonymous votes off-chain to compute the tallies
+/// and seeds needed to finalize the ballot.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `ballot_id` - The ID of the ballot
+/// * `offset` - The index of the first vote
+/// * `limit` - The page size, at most `BALLOT_PAGE_SIZE`
+///
+/// # Returns
+/// * `Result<Vec<BallotVote>, Error>` - The votes, in the order they were
+///   cast, empty past the last one
+pub fn get_ballot_votes(
+    env: &Env,
+    ballot_id: u32,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<BallotVote>, Error> {
+    let ballot = get_ballot(env, ballot_id)?;
+
+    Ok(read_ballot_votes(env, &ballot, offset, limit))
+}
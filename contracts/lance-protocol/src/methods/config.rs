@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    methods::{admin::require_admin, vault::MAX_SLASH_BPS},
+    storage::{
+        config::{
+            ProtocolConfig, get_protocol_config, set_protocol_config as write_protocol_config,
+        },
+        error::Error,
+        storage::extend_instance,
+    },
+};
+
+/// Configure the timing of new disputes.
+///
+/// The voting bounds must leave room for a vote and the counterpart must be
+/// able to match the fee within the shortest voting period.
+///
+/// The creation limits apply to disputes created from now on, disputes
+/// already open keep their bond.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `config` - The new timing, applied to disputes created from now on
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the configuration was updated
+pub fn set_protocol_config(env: &Env, admin: Address, config: ProtocolConfig) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if config.min_voting_duration == 0
+        || config.min_voting_duration > config.max_voting_duration
+        || config.evidence_duration == 0
+        || config.evidence_duration > config.min_voting_duration
+        || config.reveal_duration == 0
+    {
+        return Err(Error::InvalidDuration);
+    }
+    if config.creation_bond < 0
+        || config.juror_stake < 0
+        || config.missed_vote_slash_bps > MAX_SLASH_BPS
+        || config.incoherent_slash_bps > MAX_SLASH_BPS
+    {
+        return Err(Error::InvalidAmount);
+    }
+
+    write_protocol_config(env, &config);
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// End of the voting period of a dispute created now.
+///
+/// # Returns
+/// * `Result<u64, Error>` - The end of the voting period, or `InvalidDuration`
+///   if the duration is outside the configured bounds
+pub(crate) fn voting_ends_at(env: &Env, voting_duration: u64) -> Result<u64, Error> {
+    let config = get_protocol_config(env);
+
+    if voting_duration < config.min_voting_duration || voting_duration > config.max_voting_duration
+    {
+        return Err(Error::InvalidDuration);
+    }
+    Ok(env.ledger().timestamp() + voting_duration)
+}
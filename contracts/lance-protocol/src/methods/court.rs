@@ -0,0 +1,281 @@
+use soroban_sdk::{Address, Env, Map, String, Vec};
+
+use crate::{
+    events,
+    methods::{
+        admin::{require_admin, require_moderator},
+        eligibility::check_juror_eligibility,
+        phase::require_phase,
+        token::payment_token,
+        vault::{lock_stake, stake_balance},
+    },
+    storage::{
+        DataKey,
+        court::{
+            Court, add_court_juror, get_court, get_court_juror_at, is_court_member,
+            remove_court_juror, set_court, set_court_member,
+        },
+        dispute::{DisputeRecord, add_juror, get_dispute_record, is_juror, set_dispute_record},
+        dispute_status::DisputePhase,
+        error::Error,
+        storage::extend_instance,
+        vault::get_stake_vault,
+        voter::get_voter,
+    },
+};
+
+/// Pool indices sampled per juror to draw
+pub(crate) const DRAW_ATTEMPTS_PER_JUROR: u32 = 4;
+/// Jurors drawn by one call, each sampling up to `DRAW_ATTEMPTS_PER_JUROR`
+/// jurors of the pool
+pub(crate) const MAX_DRAWN_JURORS_PER_CALL: u32 = 3;
+/// Draw weight of a juror without reputation
+const DRAW_BASE_WEIGHT: u32 = 20;
+/// Reputation above which the draw weight stops growing
+const DRAW_REPUTATION_CAP: u32 = 20;
+
+/// Create a court, optionally as a child of an existing court.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `name` - The category of disputes the court handles
+/// * `parent` - The parent court, if any
+/// * `fee` - The arbitration fee of the court's disputes
+/// * `min_stake` - The minimum stake to join, at least the parent's
+/// * `token` - The allowed token of the court's fees, the protocol token if None
+///
+/// # Returns
+/// * `Result<Court, Error>` - The new court
+pub fn create_court(
+    env: &Env,
+    admin: Address,
+    name: String,
+    parent: Option<u32>,
+    fee: i128,
+    min_stake: i128,
+    token: Option<Address>,
+) -> Result<Court, Error> {
+    require_admin(env, &admin)?;
+    extend_instance(env);
+
+    let token = payment_token(env, token)?;
+    if fee < 0 || min_stake < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if let Some(parent_id) = parent {
+        // jurors of a child court also sit in its parent
+        if min_stake < get_court(env, parent_id)?.min_stake {
+            return Err(Error::InsufficientStake);
+        }
+    }
+
+    let court_id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::CourtId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::CourtId, &court_id);
+
+    let court = Court {
+        court_id,
+        name,
+        parent,
+        fee,
+        min_stake,
+        juror_count: 0,
+        token,
+    };
+    set_court(env, &court);
+
+    events::CourtCreated { court_id, parent }.publish(env);
+
+    Ok(court)
+}
+
+/// A court followed by all of its ancestors.
+fn court_path(env: &Env, court_id: u32) -> Result<Vec<Court>, Error> {
+    let mut path = Vec::new(env);
+    let mut next = Some(court_id);
+    while let Some(court_id) = next {
+        let court = get_court(env, court_id)?;
+        next = court.parent;
+        path.push_back(court);
+    }
+    Ok(path)
+}
+
+/// Join a court's pool and the pools of its parent courts.
+///
+/// Membership is backed by the juror's vault stake, which must cover the
+/// court's minimum stake. The stake stays in the vault, where it is locked
+/// while the juror sits on a dispute and slashed like any vault stake.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - A registered voter
+/// * `court_id` - The court to join
+///
+/// # Returns
+/// * `Result<i128, Error>` - The juror's bonded stake
+pub fn join_court(env: &Env, juror: Address, court_id: u32) -> Result<i128, Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    get_voter(env, juror.clone())?;
+
+    let path = court_path(env, court_id)?;
+    if is_court_member(env, court_id, &juror) {
+        return Err(Error::JudgeAlreadyVoted);
+    }
+    let stake = get_stake_vault(env, &juror).staked;
+    if stake < path.get(0).unwrap().min_stake {
+        return Err(Error::InsufficientStake);
+    }
+
+    set_court_member(env, court_id, &juror, true);
+    for mut court in path.iter() {
+        add_court_juror(env, &mut court, &juror);
+        set_court(env, &court);
+    }
+
+    events::CourtJoined {
+        court_id,
+        juror,
+        stake,
+    }
+    .publish(env);
+
+    Ok(stake)
+}
+
+/// Leave the pool of a court.
+///
+/// The juror stays in the pools of parent courts while a member of another of
+/// their child courts. The vault stake is untouched: it stays locked by the
+/// disputes the juror already sits on and unbonds through the vault.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - The juror leaving the court
+/// * `court_id` - The court to leave
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the juror left the court
+pub fn leave_court(env: &Env, juror: Address, court_id: u32) -> Result<(), Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    let path = court_path(env, court_id)?;
+    if !is_court_member(env, court_id, &juror) {
+        return Err(Error::JudgeNotFound);
+    }
+
+    set_court_member(env, court_id, &juror, false);
+    for mut court in path.iter() {
+        remove_court_juror(env, &mut court, &juror);
+        set_court(env, &court);
+    }
+
+    events::CourtLeft { court_id, juror }.publish(env);
+
+    Ok(())
+}
+
+/// Draw jurors at random from the pool of the dispute's court, weighted by
+/// reputation.
+///
+/// A moderator draws the jurors once both parties paid the arbitration fee,
+/// in pages of at most `MAX_DRAWN_JURORS_PER_CALL`. Court disputes only seat
+/// drawn jurors. Each attempt samples a juror still in the pool and keeps
+/// them with a chance of their draw weight, `DRAW_BASE_WEIGHT` plus their
+/// reputation up to `DRAW_REPUTATION_CAP`, over the largest weight: seats go to jurors in
+/// proportion to their weight, and a juror at the cap is twice as likely to
+/// sit as a juror without reputation. Jurors kept but already on the dispute,
+/// failing the eligibility rules, including the parties, or whose free vault
+/// stake does not cover the court's minimum stake leave the pool. The draw
+/// stops after `DRAW_ATTEMPTS_PER_JUROR` attempts per juror to draw, so the
+/// cost does not grow with the pool. Drawn jurors lock the minimum stake on
+/// the dispute.
+///
+/// The draw uses the contract prng, seeded from the ledger. It is not secure
+/// randomness: the caller can simulate the draw and pick the ledger to submit
+/// it in. Only the admin and the governance can draw, so neither the parties
+/// nor the jurors can grind for seats.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `moderator` - The contract admin or the governance
+/// * `dispute_id` - The ID of a dispute created in a court
+/// * `count` - The number of jurors to draw
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The dispute with the drawn jurors,
+///   `BatchTooLarge` past `MAX_DRAWN_JURORS_PER_CALL`, or
+///   `InvalidEligibility` if not enough eligible jurors were drawn
+pub fn draw_jurors(
+    env: &Env,
+    moderator: Address,
+    dispute_id: u32,
+    count: u32,
+) -> Result<DisputeRecord, Error> {
+    require_moderator(env, &moderator)?;
+    extend_instance(env);
+
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+    let court = get_court(env, dispute.court_id.ok_or(Error::CourtNotFound)?)?;
+
+    require_phase(
+        env,
+        &mut dispute,
+        &[DisputePhase::Registration, DisputePhase::Voting],
+    )?;
+    if count > MAX_DRAWN_JURORS_PER_CALL {
+        return Err(Error::BatchTooLarge);
+    }
+    if count == 0 || dispute.juror_count + count > dispute.max_jurors {
+        return Err(Error::InvalidEligibility);
+    }
+
+    // Sparse Fisher-Yates shuffle of the pool, only moved indices are stored.
+    // The first `next` positions hold the jurors who left the pool.
+    let pool = court.juror_count;
+    let max_weight = DRAW_BASE_WEIGHT + DRAW_REPUTATION_CAP;
+    let mut moved: Map<u32, u32> = Map::new(env);
+    let mut next = 0;
+    let mut drawn = 0;
+    for _ in 0..count * DRAW_ATTEMPTS_PER_JUROR {
+        if drawn == count || next == pool {
+            break;
+        }
+        let pick = env.prng().gen_range::<u64>(next as u64..pool as u64) as u32;
+        let index = moved.get(pick).unwrap_or(pick);
+        let juror = get_court_juror_at(env, court.court_id, index);
+
+        // a juror passed over by the weighting stays in the pool
+        let reputation = get_voter(env, juror.clone()).map_or(0, |voter| voter.reputation);
+        let weight = DRAW_BASE_WEIGHT + reputation.min(DRAW_REPUTATION_CAP);
+        if env.prng().gen_range::<u64>(0..max_weight as u64) >= weight as u64 {
+            continue;
+        }
+        moved.set(pick, moved.get(next).unwrap_or(next));
+        next += 1;
+
+        if is_juror(env, dispute_id, &juror)
+            || check_juror_eligibility(env, &dispute, &juror).is_err()
+            || stake_balance(&get_stake_vault(env, &juror)).free < court.min_stake
+        {
+            continue;
+        }
+        lock_stake(env, &juror, dispute_id, court.min_stake)?;
+        add_juror(env, &mut dispute, &juror);
+        drawn += 1;
+    }
+    if drawn < count {
+        return Err(Error::InvalidEligibility);
+    }
+    set_dispute_record(env, &dispute);
+
+    Ok(dispute)
+}
@@ -1,28 +1,129 @@
-use crate::events::event;
-use crate::storage::dispute::get_dispute;
-use crate::storage::vote::get_anonymous_voting_config;
+use crate::events::{self, event};
+use crate::methods::config::voting_ends_at;
+use crate::methods::emission::{add_to_reservoir, credit_coherent_weight, record_ruling_weight};
+use crate::methods::fee::{
+    arbitration_cost, deposit_creator_fee, dispute_token, pay_reward, settle_fees,
+};
+use crate::methods::phase::require_phase;
+use crate::methods::registry::unregistered_template;
+use crate::methods::reputation::{Verdict, record_reward, settle_juror_reputation};
+use crate::methods::settlement::MAX_SHARE_BPS;
+use crate::methods::tally::{majority_option, new_tally, open_tally, verify_tallies};
+use crate::methods::vault::release_stake;
+use crate::storage::dispute::{
+    APPEAL_PERIOD, CreatorActivity, REWARD_CLAIM_PERIOD, Ruling, current_phase, enter_phase,
+    get_creator_activity, get_dispute_record, get_dispute_vote, get_juror_at, get_reveal,
+    get_settlement, set_creator_activity,
+};
+use crate::storage::config::get_protocol_config;
+use crate::storage::eligibility::set_counterparties;
+use crate::storage::registry::{DisputeTemplate, get_caller_template};
+use crate::storage::vote::{VoteAnon, VoteChoice, VotingMode};
 use crate::storage::voter::{get_voter, update_voter};
 use crate::storage::{
-    dispute::{Dispute, set_dispute},
-    dispute_status::DisputeStatus,
+    dispute::{Dispute, DisputeRecord, get_dispute, set_dispute_record},
+    dispute_status::{DisputePhase, DisputeStatus},
     error::Error,
-    storage::DataKey,
-    vote::{VoteAnon, VoteData},
+    storage::{DataKey, extend_instance, retain_persistent},
 };
-use soroban_sdk::crypto::bls12_381::G1Affine;
-use soroban_sdk::{Address, BytesN, Env, String, U256, Vec, panic_with_error, vec};
+use soroban_sdk::{Address, Env, String, Vec, panic_with_error, vec};
+
+/// Rewards claimed in a single `claim_rewards` call, to stay within the
+/// transaction budget
+pub(crate) const MAX_CLAIMS_PER_CALL: u32 = 20;
+/// Jurors settled in a single `settle_jurors` call, to stay within the
+/// transaction budget
+pub(crate) const SETTLE_PAGE_SIZE: u32 = 10;
 
+/// Open a dispute with parameters chosen by the creator.
+///
+/// Callers registered with a template must open their disputes through
+/// [`create_template_dispute`]; unregistered callers are subject to the
+/// admin's caller policy.
+#[allow(clippy::too_many_arguments)]
 pub fn create_dispute_demo(
     env: &Env,
     project_id: u32,
     creator: Address,
     counterpart: Address,
     proof: String,
-    voting_ends_at: u64,
+    voting_duration: u64,
     called_contract: Address,
-) -> Result<Dispute, Error> {
+    voting_mode: VotingMode,
+    court_id: Option<u32>,
+) -> Result<DisputeRecord, Error> {
+    if get_caller_template(env, &called_contract).is_some() {
+        return Err(Error::NotAuthorized);
+    }
+    let template = unregistered_template(env, court_id, voting_duration, voting_mode)?;
+
+    open_dispute(
+        env,
+        project_id,
+        creator,
+        counterpart,
+        proof,
+        called_contract,
+        template,
+    )
+}
+
+/// Open a dispute for a registered caller with the parameters of its template.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_id` - The caller's reference of the disputed item
+/// * `creator` - The party opening the dispute
+/// * `counterpart` - The other party
+/// * `proof` - The creator's evidence
+/// * `called_contract` - The registered caller, which must authorize the dispute
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The new dispute
+pub fn create_template_dispute(
+    env: &Env,
+    project_id: u32,
+    creator: Address,
+    counterpart: Address,
+    proof: String,
+    called_contract: Address,
+) -> Result<DisputeRecord, Error> {
+    called_contract.require_auth();
+
+    let template = get_caller_template(env, &called_contract).ok_or(Error::CallerNotRegistered)?;
+
+    open_dispute(
+        env,
+        project_id,
+        creator,
+        counterpart,
+        proof,
+        called_contract,
+        template,
+    )
+}
+
+fn open_dispute(
+    env: &Env,
+    project_id: u32,
+    creator: Address,
+    counterpart: Address,
+    proof: String,
+    called_contract: Address,
+    template: DisputeTemplate,
+) -> Result<DisputeRecord, Error> {
     // Require authentication from the dispute creator
     creator.require_auth();
+    extend_instance(env);
+
+    let voting_ends_at = voting_ends_at(env, template.voting_duration)?;
+    let reveal_ends_at = voting_ends_at + get_protocol_config(env).reveal_duration;
+    limit_creator(env, &creator)?;
+    let token = dispute_token(env, template.court_id, template.token)?;
+    let arbitration_fee = match template.arbitration_fee {
+        Some(fee) => fee,
+        None => arbitration_cost(env, template.court_id)?,
+    };
 
     let current_id = env
         .storage()
@@ -34,20 +135,12 @@ pub fn create_dispute_demo(
         .instance()
         .set(&DataKey::DisputeId, &new_dispute_id);
 
-    let votes = vec![&env];
-    let vote_data = VoteData {
-        voting_ends_at,
-        votes,
-    };
-
-    let dispute = Dispute {
+    let mut dispute = DisputeRecord {
         project_id,
         dispute_id: new_dispute_id,
-        able_to_vote: Vec::new(env),
-        voters: Vec::new(env),
-        vote_commits: Vec::new(env),
-        votes: Vec::new(env),
         dispute_status: DisputeStatus::OPEN,
+        phase: DisputePhase::Evidence,
+        phase_started_at: env.ledger().timestamp(),
         initial_timestamp: env.ledger().timestamp(),
         finish_timestamp: None,
         creator: creator.clone(),
@@ -58,11 +151,48 @@ pub fn create_dispute_demo(
         waiting_for_judges: false,
         votes_for: 0,
         votes_against: 0,
-        vote_data,
+        voting_mode: template.voting_mode,
+        voting_ends_at,
+        reveal_ends_at,
+        tally: new_tally(env, template.ruling_options),
         called_contract,
+        juror_count: 0,
+        max_jurors: template.juror_count,
+        commit_count: 0,
+        revealed_count: 0,
+        vote_count: 0,
+        settled_count: 0,
+        court_id: template.court_id,
+        arbitration_fee: 0,
+        fee_deadline: 0,
+        counterpart_paid: false,
+        reward_pool: 0,
+        rewarded_weight: 0,
+        claimable_weight: 0,
+        emission_epoch: None,
+        slashed_pool: 0,
+        claimed_weight: 0,
+        reward_paid: 0,
+        slashed_paid: 0,
+        creation_bond: 0,
+        token,
     };
 
-    set_dispute(env, new_dispute_id, dispute.clone());
+    // the creator deposits the arbitration fee up front
+    deposit_creator_fee(env, &mut dispute, arbitration_fee)?;
+    // free disputes have no fee to wait for
+    if dispute.counterpart_paid {
+        enter_phase(
+            &mut dispute,
+            DisputePhase::Registration,
+            env.ledger().timestamp(),
+        );
+    }
+
+    // the parties can no longer judge each other's disputes
+    set_counterparties(env, &dispute.creator, &dispute.counterpart);
+
+    set_dispute_record(env, &dispute);
 
     // Emit event for dispute creation
     event::AnonymousDisputeSetup {
@@ -74,42 +204,59 @@ pub fn create_dispute_demo(
     Ok(dispute)
 }
 
+/// Count a new dispute of the creator against the creation limits.
+///
+/// # Returns
+/// * `Result<(), Error>` - `InsufficientTime` during the cooldown, or
+///   `TooManyOpenDisputes` when the creator has too many disputes awaiting a ruling
+fn limit_creator(env: &Env, creator: &Address) -> Result<(), Error> {
+    let config = get_protocol_config(env);
+    let now = env.ledger().timestamp();
+
+    let mut activity = match get_creator_activity(env, creator) {
+        Some(activity) if now < activity.last_created_at + config.dispute_cooldown => {
+            return Err(Error::InsufficientTime);
+        }
+        Some(activity) => activity,
+        None => CreatorActivity::default(),
+    };
+    if config.max_open_disputes > 0 && activity.open_disputes >= config.max_open_disputes {
+        return Err(Error::TooManyOpenDisputes);
+    }
+
+    activity.open_disputes += 1;
+    activity.last_created_at = now;
+    set_creator_activity(env, creator, &activity);
+
+    Ok(())
+}
+
+/// Open a dispute with parameters chosen by the creator, like
+/// [`create_dispute_demo`], returning its public view.
+#[allow(clippy::too_many_arguments)]
 pub fn create_dispute(
     env: &Env,
     project_id: u32,
     creator: Address,
     counterpart: Address,
     proof: String,
-    voting_ends_at: u64,
+    voting_duration: u64,
     called_contract: Address,
-    // amount: i128,
+    voting_mode: VotingMode,
+    court_id: Option<u32>,
 ) -> Result<Dispute, Error> {
-    let dispute = Dispute {
+    let dispute = create_dispute_demo(
+        env,
         project_id,
-        dispute_id: 1,
-        able_to_vote: Vec::new(env),
-        voters: Vec::new(env),
-        vote_commits: Vec::new(env),
-        votes: Vec::new(env),
-        dispute_status: DisputeStatus::OPEN,
-        initial_timestamp: env.ledger().timestamp(),
-        finish_timestamp: None,
-        creator: creator.clone(),
+        creator,
         counterpart,
-        winner: None,
-        creator_proves: proof.clone(),
-        counterpart_proves: None,
-        waiting_for_judges: false,
-        votes_for: 0,
-        votes_against: 0,
-        vote_data: VoteData {
-            voting_ends_at,
-            votes: Vec::new(env),
-        },
+        proof,
+        voting_duration,
         called_contract,
-    };
-
-    Ok(dispute)
+        voting_mode,
+        court_id,
+    )?;
+    get_dispute(env, dispute.dispute_id)
 }
 
 /// Execute a vote after the voting period ends.
@@ -130,6 +277,10 @@ pub fn create_dispute(
 /// # Returns
 /// * `types::ProposalStatus` - The final status of the proposal (Approved, Rejected, or Cancelled)
 ///
+/// The ruling stays appealable for [`crate::storage::dispute::APPEAL_PERIOD`] before it is final.
+/// Its cost does not depend on the number of votes: the jurors are settled
+/// afterwards by [`settle_jurors`].
+///
 /// # Panics
 /// * If the voting period hasn't ended
 /// * If the proposal doesn't exist
@@ -137,6 +288,7 @@ pub fn create_dispute(
 /// * If tallies/seeds are missing for anonymous votes
 /// * If commitment validation fails for anonymous votes
 /// * If the maintainer is not authorized
+/// * If the dispute uses commit-reveal voting and its votes can still be revealed
 pub fn execute(
     env: Env,
     maintainer: Address,
@@ -145,45 +297,43 @@ pub fn execute(
     seeds: Option<Vec<u128>>,
 ) -> DisputeStatus {
     maintainer.require_auth();
+    extend_instance(&env);
 
-    let mut dispute = match get_dispute(&env, dispute_id) {
+    let mut dispute = match get_dispute_record(&env, dispute_id) {
         Ok(dispute) => dispute,
         Err(_) => panic_with_error!(&env, &Error::DisputeNotFound),
     };
 
-    let curr_timestamp = env.ledger().timestamp();
-
-    // only allow to execute once
-    if dispute.dispute_status != DisputeStatus::OPEN {
-        panic_with_error!(&env, &Error::ProposalActive);
-    }
-    if curr_timestamp < dispute.vote_data.voting_ends_at {
-        panic_with_error!(&env, &Error::ProposalVotingTime);
+    // only allow to execute once, after the voting period; without the
+    // counterpart's fee the creator claims the default ruling instead
+    if let Err(error) = require_phase(&env, &mut dispute, &[DisputePhase::Tally]) {
+        panic_with_error!(&env, &error);
     }
 
-    // tally to results
-    let (tallies_, seeds_) = match (tallies, seeds) {
-        (Some(t), Some(s)) => (t, s),
-        _ => panic_with_error!(&env, &Error::TallySeedError),
+    let tallies_ = match dispute.voting_mode {
+        // public votes are tallied on-chain as they are cast
+        VotingMode::Public => dispute.tally.tallies.clone(),
+        VotingMode::Anonymous => {
+            // tally to results, checked against the commitments
+            if let Err(error) =
+                open_tally(&env, dispute.project_id, &mut dispute.tally, tallies, seeds)
+            {
+                panic_with_error!(&env, &error);
+            }
+            dispute.tally.tallies.clone()
+        }
+        // commit-reveal disputes are resolved by `reveal_votes`, or without
+        // a ruling once the votes were not revealed in time
+        VotingMode::CommitReveal => {
+            if env.ledger().timestamp() < dispute.reveal_ends_at {
+                panic_with_error!(&env, &Error::WrongVoteType);
+            }
+            vec![&env, 0u128, 0u128, 0u128]
+        }
     };
 
-    // Validate tallies and seeds have expected length (3: approve, reject, abstain)
-    if tallies_.len() != 3 || seeds_.len() != 3 {
-        panic_with_error!(&env, &Error::TallySeedError);
-    }
-
-    if !proof(
-        env.clone(),
-        //project_key.clone(),
-        dispute.clone(),
-        tallies_.clone(),
-        seeds_,
-    ) {
-        panic_with_error!(&env, &Error::InvalidProof)
-    }
-
-    // Set the dispute status based on tallies
-    dispute.dispute_status = anonymous_execute(&tallies_);
+    // The outcome based on tallies
+    let outcome = anonymous_execute(&tallies_);
 
     // Extract vote counts from tallies
     let voted_approve = tallies_.get(0).unwrap();
@@ -194,14 +344,150 @@ pub fn execute(
     dispute.votes_against = voted_reject as u32;
 
     // Set the winner based on the dispute status
-    dispute.winner = match dispute.dispute_status {
+    dispute.winner = match outcome {
         DisputeStatus::CREATOR => Some(dispute.creator.clone()),
         DisputeStatus::COUNTERPART => Some(dispute.counterpart.clone()),
         _ => None,
     };
 
-    set_dispute(&env, dispute_id, dispute.clone());
-    dispute.dispute_status
+    // The winning tally is the coherent weight. Public voters are rewarded
+    // when they voted for the winner; anonymous voters can all claim since
+    // their choice is never revealed, and share the winners' rewards
+    let rewarded_weight = match outcome {
+        DisputeStatus::CREATOR => voted_approve,
+        DisputeStatus::COUNTERPART => voted_reject,
+        _ => 0,
+    };
+    let claimable_weight = match dispute.voting_mode {
+        VotingMode::Anonymous => tallies_.iter().sum(),
+        _ => rewarded_weight,
+    };
+    let now = env.ledger().timestamp();
+    dispute.finish_timestamp = Some(now);
+    enter_phase(&mut dispute, DisputePhase::Appealable, now);
+    settle_fees(&env, &mut dispute, rewarded_weight, claimable_weight);
+    dispute.emission_epoch = record_ruling_weight(&env, dispute.rewarded_weight);
+
+    set_dispute_record(&env, &dispute);
+    outcome
+}
+
+/// Settle the next seated jurors of a ruled dispute, in seating order.
+///
+/// Settling applies the ruling to each juror: jurors who never voted lose the
+/// missed vote penalty and `missed_vote_slash_bps` of the stake they locked
+/// on the dispute, known votes for the loser lose `INCOHERENT_VOTE_PENALTY`
+/// and `incoherent_slash_bps` of the locked stake per unit of weight. The
+/// slashed stake joins the dispute's slashed pool, or the rewards reservoir
+/// when no juror is coherent, and every lock is released. Disputes settled by
+/// the parties only release the locks.
+///
+/// Anyone can settle, a page at a time so the cost does not grow with the
+/// jury. Rewards are claimed once every juror is settled.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of a ruled or settled dispute
+/// * `limit` - The number of jurors to settle, at most [`SETTLE_PAGE_SIZE`]
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The dispute with its settled jurors, or
+///   `AlreadyClaimed` if every juror was already settled
+pub fn settle_jurors(env: &Env, dispute_id: u32, limit: u32) -> Result<DisputeRecord, Error> {
+    extend_instance(env);
+
+    if limit == 0 || limit > SETTLE_PAGE_SIZE {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+    require_phase(
+        env,
+        &mut dispute,
+        &[DisputePhase::Appealable, DisputePhase::Final],
+    )?;
+    if dispute.settled_count >= dispute.juror_count {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    let config = get_protocol_config(env);
+    let ruled = dispute.dispute_status != DisputeStatus::SETTLED;
+    let end = (dispute.settled_count + limit).min(dispute.juror_count);
+    let mut slashed = 0;
+    for index in dispute.settled_count..end {
+        let juror = get_juror_at(env, dispute_id, index);
+        let slash_bps = if ruled {
+            match settle_juror_reputation(env, &dispute, &juror, config.missed_vote_penalty) {
+                Verdict::Absent => config.missed_vote_slash_bps as i128,
+                Verdict::Incoherent(weight) => config.incoherent_slash_bps as i128 * weight as i128,
+                Verdict::Cleared => 0,
+            }
+        } else {
+            0
+        };
+        slashed += release_stake(env, dispute_id, &juror, slash_bps);
+    }
+    dispute.settled_count = end;
+
+    if dispute.rewarded_weight > 0 {
+        dispute.slashed_pool += slashed;
+    } else if slashed > 0 {
+        add_to_reservoir(env, slashed);
+    }
+    set_dispute_record(env, &dispute);
+
+    Ok(dispute)
+}
+
+/// Compact ruling of a dispute, for contracts gating their logic on it.
+///
+/// Disputes are ruled in a single round until appeals are supported. The
+/// creator's share is the agreed split of a settlement, the whole payment
+/// when the creator wins, nothing when the counterpart wins, and half of it
+/// when there is no winner.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of the dispute
+///
+/// # Returns
+/// * `Result<Ruling, Error>` - The ruling, final once it can no longer be
+///   appealed, or an error if the dispute is not ruled yet
+pub fn get_ruling(env: &Env, dispute_id: u32) -> Result<Ruling, Error> {
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    let phase = require_phase(
+        env,
+        &mut dispute,
+        &[DisputePhase::Appealable, DisputePhase::Final],
+    )?;
+
+    let winning_option = match &dispute.winner {
+        Some(winner) if *winner == dispute.creator => VoteChoice::Creator,
+        Some(_) => VoteChoice::Counterpart,
+        None => VoteChoice::Abstain,
+    };
+    let creator_share_bps = match (get_settlement(env, dispute_id), winning_option) {
+        (Some(settlement), _) if dispute.dispute_status == DisputeStatus::SETTLED => {
+            settlement.creator_share_bps
+        }
+        (_, VoteChoice::Creator) => MAX_SHARE_BPS,
+        (_, VoteChoice::Counterpart) => 0,
+        (_, VoteChoice::Abstain) => MAX_SHARE_BPS / 2,
+    };
+    let finalized_at = match phase {
+        DisputePhase::Appealable => dispute.phase_started_at + APPEAL_PERIOD,
+        _ => dispute.phase_started_at,
+    };
+
+    Ok(Ruling {
+        dispute_id,
+        round: 1,
+        winning_option,
+        creator_share_bps,
+        finalized_at,
+        is_final: phase == DisputePhase::Final,
+    })
 }
 
 /// Verify vote commitment proof for anonymous voting.
@@ -217,6 +503,9 @@ pub fn execute(
 /// where g, h are BLS12-381 generator points and v is the vote choice,
 /// r is the seed. Voting weight is introduced during the tallying phase.
 ///
+/// The weighted commitments are summed per option as each vote is cast, so
+/// the verification cost does not depend on the number of votes.
+///
 /// # Arguments
 /// * `env` - The environment object
 /// * `project_key` - The project key identifier
@@ -229,85 +518,32 @@ pub fn execute(
 ///
 /// # Panics
 /// * If no anonymous voting configuration exists for the project
+/// * If the dispute does not use anonymous voting
 pub fn proof(
     env: Env,
     //project_key: Bytes,
-    dispute: Dispute,
+    dispute: DisputeRecord,
     tallies: Vec<u128>,
     seeds: Vec<u128>,
 ) -> bool {
-    // Proof validation only applies to active proposals (before execution)
-    if dispute.dispute_status != DisputeStatus::OPEN {
-        panic_with_error!(&env, &Error::ProposalActive);
-    }
-
-    // // we can only proof anonymous votes
-    // if proposal.vote_data.public_voting {
-    //     panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
-    // }
-
-    let bls12_381 = env.crypto().bls12_381();
-
-    let vote_config = get_anonymous_voting_config(&env, dispute.project_id);
-    // let vote_config: types::AnonymousVoteConfig = env
-    //     .storage()
-    //     .instance()
-    //     .get(&types::ProjectKey::AnonymousVoteConfig(project_key))
-    //     .unwrap_or_else(|| {
-    //         panic_with_error!(&env, &errors::ContractErrors::NoAnonymousVotingConfig);
-    //     });
-
-    let seed_generator_point = G1Affine::from_bytes(vote_config.seed_generator_point);
-    let vote_generator_point = G1Affine::from_bytes(vote_config.vote_generator_point);
-
-    // calculate commitments from vote tally and seed tally
-    let mut commitment_checks = Vec::new(&env);
-    for it in tallies.iter().zip(seeds.iter()) {
-        let (tally_, seed_) = it;
-        let seed_: U256 = U256::from_u128(&env, seed_);
-        let tally_: U256 = U256::from_u128(&env, tally_);
-        let seed_point_ = bls12_381.g1_mul(&seed_generator_point, &seed_.into());
-        let tally_commitment_votes_ = bls12_381.g1_mul(&vote_generator_point, &tally_.into());
-        let commitment_check_ = bls12_381.g1_add(&tally_commitment_votes_, &seed_point_);
-        commitment_checks.push_back(commitment_check_);
-    }
-
-    // tally commitments from recorded votes (vote + seed)
-    let mut g1_identity = [0u8; 96];
-    g1_identity[0] = 0x40;
-    let tally_commitment_init_ = G1Affine::from_bytes(BytesN::from_array(&env, &g1_identity));
-
-    let mut tally_commitments = [
-        tally_commitment_init_.clone(),
-        tally_commitment_init_.clone(),
-        tally_commitment_init_.clone(),
-    ];
-
-    for vote_ in dispute.vote_data.votes.iter() {
-        let VoteAnon::AnonymousVote(anonymous_vote) = &vote_;
-        let weight_: U256 = U256::from_u32(&env, anonymous_vote.weight);
-        for (commitment, tally_commitment) in anonymous_vote
-            .commitments
-            .iter()
-            .zip(tally_commitments.iter_mut())
-        {
-            let commitment_ = G1Affine::from_bytes(commitment);
-            // scale the commitment by the voter weight: weight * (g*v + h*r).
-            let weighted_commitment = bls12_381.g1_mul(&commitment_, &weight_.clone().into());
-            *tally_commitment = bls12_381.g1_add(tally_commitment, &weighted_commitment);
-        }
+    // Proof validation only applies to disputes not ruled yet
+    let (phase, _) = current_phase(&env, &dispute);
+    if phase >= DisputePhase::Appealable {
+        panic_with_error!(&env, &Error::DisputeAlreadyResolved);
     }
 
-    // compare commitments
-    for (commitment_check, tally_commitment) in
-        commitment_checks.iter().zip(tally_commitments.iter())
-    {
-        if commitment_check != *tally_commitment {
-            return false;
-        }
+    // we can only proof anonymous votes
+    if dispute.voting_mode != VotingMode::Anonymous {
+        panic_with_error!(&env, &Error::WrongVoteType);
     }
 
-    true
+    verify_tallies(
+        &env,
+        dispute.project_id,
+        &dispute.tally.tally_commitments,
+        &tallies,
+        &seeds,
+    )
 }
 
 /// Execute an anonymous voting proposal.
@@ -316,64 +552,33 @@ pub fn proof(
 /// based on the tallied vote counts. For anonymous voting, individual votes are
 /// not visible, only the aggregated tallies.
 ///
+/// Like any ballot, an option needs more than half of all votes (including
+/// abstains) to win, which ensures broad consensus before resolving the dispute.
+/// A majority of abstains or no clear majority leaves the dispute without a winner.
+///
 /// # Arguments
 /// * `tallies` - The tallied vote counts [approve, reject, abstain]
 ///
 /// # Returns
-/// * `types::ProposalStatus` - The final status (Approved if approve > reject, Rejected if reject > approve, Cancelled if equal)
+/// * `types::ProposalStatus` - The final status (Approved if approve > reject + abstain, Rejected if reject > approve + abstain, Cancelled otherwise)
 pub fn anonymous_execute(tallies: &Vec<u128>) -> DisputeStatus {
-    // Use get() method to access elements safely
-    let voted_approve = tallies
-        .get(0)
-        .expect("anonymous_execute missing creator tally entry");
-    let voted_reject = tallies
-        .get(1)
-        .expect("anonymous_execute missing counterpart tally entry");
-    let voted_abstain = tallies
-        .get(2)
-        .expect("anonymous_execute missing abstain tally entry");
-
-    tallies_to_result(voted_approve, voted_reject, voted_abstain)
-}
-
-/// Convert vote tallies to proposal status.
-///
-/// Helper function to determine the final status based on vote counts.
-/// Abstain votes are ignored in the decision. If approve and reject are equal,
-/// the proposal is cancelled.
-///
-/// # Arguments
-/// * `voted_approve` - Number of approve votes
-/// * `voted_reject` - Number of reject votes
-/// * `voted_abstain` - Number of abstain votes (not used in decision)
-///
-/// # Returns
-/// * `types::ProposalStatus` - The final status (Approved, Rejected, or Cancelled)
-fn tallies_to_result(
-    voted_approve: u128,
-    voted_reject: u128,
-    voted_abstain: u128,
-) -> DisputeStatus {
-    // Supermajority governance: requires more than half of all votes (including abstains)
-    // This ensures broad consensus before passing any proposal
-    // Approve needs: approve > (reject + abstain)
-    // Reject needs: reject > (approve + abstain)
-    // Otherwise: cancelled (tie or no clear supermajority)
-    if voted_approve > (voted_reject + voted_abstain) {
-        DisputeStatus::CREATOR
-    } else if voted_reject > (voted_approve + voted_abstain) {
-        DisputeStatus::COUNTERPART
-    } else {
-        DisputeStatus::ABSTAIN
+    match majority_option(tallies) {
+        Some(0) => DisputeStatus::CREATOR,
+        Some(1) => DisputeStatus::COUNTERPART,
+        _ => DisputeStatus::ABSTAIN,
     }
 }
 
 /// Claim reward for voting with the majority.
 ///
-/// Allows voters to claim their reward after a dispute is executed.
+/// Allows voters to claim their reward once the ruling of a dispute is final.
+/// For public and commit-reveal disputes the voter's choice must match the
+/// winner; anonymous voters are rewarded for participating since their
+/// choice is never revealed.
 /// Voters who voted with the winning side receive:
 /// - +10 balance
 /// - +1 reputation
+/// - their share of the loser's arbitration fee, by weight
 ///
 /// This function can only be called once per voter per dispute.
 ///
@@ -387,69 +592,140 @@ fn tallies_to_result(
 ///
 /// # Panics
 /// * If the dispute doesn't exist
-/// * If the dispute is not yet executed (still OPEN)
+/// * If the dispute is not final yet
+/// * If the jurors of the dispute are not all settled yet
 /// * If the voter didn't participate in this dispute
 /// * If the voter already claimed their reward
 /// * If the voter didn't vote with the majority
 pub fn claim_reward(env: Env, voter: Address, dispute_id: u32) -> Result<(), Error> {
     voter.require_auth();
 
+    credit_reward(&env, &voter, dispute_id);
+
+    Ok(())
+}
+
+/// Claim the rewards of several disputes at once.
+///
+/// The batch is all or nothing: any dispute the voter cannot claim fails the
+/// whole call, with the same errors as [`claim_reward`].
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `voter` - The address of the voter claiming the rewards
+/// * `dispute_ids` - At most [`MAX_CLAIMS_PER_CALL`] disputes
+///
+/// # Returns
+/// * `Result<i128, Error>` - The total amount credited to the voter's balance
+pub fn claim_rewards(env: Env, voter: Address, dispute_ids: Vec<u32>) -> Result<i128, Error> {
+    voter.require_auth();
+    extend_instance(&env);
+
+    if dispute_ids.len() > MAX_CLAIMS_PER_CALL {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut total = 0;
+    for dispute_id in dispute_ids.iter() {
+        total += credit_reward(&env, &voter, dispute_id);
+    }
+    Ok(total)
+}
+
+/// Credit the reward of one dispute to a voter, returning the amount.
+fn credit_reward(env: &Env, voter: &Address, dispute_id: u32) -> i128 {
     // Get dispute
-    let dispute = match get_dispute(&env, dispute_id) {
+    let mut dispute = match get_dispute_record(env, dispute_id) {
         Ok(dispute) => dispute,
-        Err(_) => panic_with_error!(&env, &Error::DisputeNotFound),
+        Err(_) => panic_with_error!(env, &Error::DisputeNotFound),
     };
 
-    // Check dispute is executed (not OPEN anymore)
-    if dispute.dispute_status == DisputeStatus::OPEN {
-        panic_with_error!(&env, &Error::ProposalActive);
+    // Rewards are paid once the ruling can no longer be appealed, and the
+    // slashed pool is complete once every juror is settled
+    if let Err(error) = require_phase(env, &mut dispute, &[DisputePhase::Final]) {
+        panic_with_error!(env, &error);
+    }
+    if dispute.settled_count < dispute.juror_count {
+        panic_with_error!(env, &Error::ProposalActive);
+    }
+    // Unclaimed rewards are swept once the claim period is over
+    if env.ledger().timestamp() >= dispute.phase_started_at + REWARD_CLAIM_PERIOD {
+        panic_with_error!(env, &Error::InsufficientTime);
     }
 
     // Check if already claimed
     let claim_key = DataKey::RewardClaimed(dispute_id, voter.clone());
-    if env.storage().instance().has(&claim_key) {
-        panic_with_error!(&env, &Error::AlreadyClaimed);
+    if env.storage().persistent().has(&claim_key) {
+        panic_with_error!(env, &Error::AlreadyClaimed);
     }
 
     // Get voter data
-    let voter_data = match get_voter(&env, voter.clone()) {
+    let voter_data = match get_voter(env, voter.clone()) {
         Ok(v) => v,
-        Err(_) => panic_with_error!(&env, &Error::UserNotFound),
+        Err(_) => panic_with_error!(env, &Error::UserNotFound),
     };
 
-    // Find voter's vote in the dispute
-    let mut voter_choice: Option<usize> = None; // 0=creator, 1=counterpart, 2=abstain
-
-    for vote in dispute.vote_data.votes.iter() {
-        let VoteAnon::AnonymousVote(anonymous_vote) = vote;
-        if anonymous_vote.address == voter {
-            // Determine which option they voted for by checking the tallies
-            // This is a simplified check - in production you'd decrypt their vote
-            // For now, we'll check if they're in the voters list
-            voter_choice = Some(0); // Placeholder - needs proper vote extraction
-            break;
-        }
-    }
+    // Find voter's choice and weight in the dispute
+    // None means the voter participated but their choice is encrypted
+    let (voter_choice, weight) = match dispute.voting_mode {
+        VotingMode::Public => match get_dispute_vote(env, dispute_id, voter) {
+            Some(VoteAnon::PublicVote(public_vote)) => {
+                (Some(public_vote.vote_choice), public_vote.weight)
+            }
+            _ => panic_with_error!(env, &Error::VoterNotFound),
+        },
+        VotingMode::CommitReveal => match get_reveal(env, dispute_id, voter) {
+            Some(true) => (Some(VoteChoice::Creator), 1),
+            Some(false) => (Some(VoteChoice::Counterpart), 1),
+            None => panic_with_error!(env, &Error::VoterNotFound),
+        },
+        // For anonymous votes, we can't determine individual vote choice
+        // So we reward ALL voters who participated (they proved they voted)
+        VotingMode::Anonymous => match get_dispute_vote(env, dispute_id, voter) {
+            Some(vote) => (None, vote.weight()),
+            None => panic_with_error!(env, &Error::VoterNotFound),
+        },
+    };
 
-    // Check if voter participated
-    if voter_choice.is_none() {
-        panic_with_error!(&env, &Error::VoterNotFound);
+    // If dispute ended without a winner, no rewards
+    let winning_choice = match &dispute.winner {
+        Some(winner) if *winner == dispute.creator => VoteChoice::Creator,
+        Some(_) => VoteChoice::Counterpart,
+        None => panic_with_error!(env, &Error::NoWinner),
+    };
+
+    if let Some(voter_choice) = voter_choice
+        && voter_choice != winning_choice
+    {
+        panic_with_error!(env, &Error::NotWithMajority);
     }
 
-    // For anonymous votes, we can't easily determine individual vote choice
-    // So we reward ALL voters who participated (they proved they voted)
-    // Alternatively, you could require voters to submit proof of their vote choice
+    // Award the reward
+    update_voter(env, voter_data, 10, 1);
 
-    // If dispute ended in ABSTAIN, no rewards
-    if dispute.dispute_status == DisputeStatus::ABSTAIN {
-        panic_with_error!(&env, &Error::NoWinner);
+    let (share, slashed_share) = match pay_reward(env, &mut dispute, voter, weight) {
+        Ok(shares) => shares,
+        Err(error) => panic_with_error!(env, &error),
+    };
+    set_dispute_record(env, &dispute);
+    record_reward(env, voter, 10 + share + slashed_share);
+    // anonymous voters are credited once they prove their vote was coherent
+    if voter_choice.is_some() {
+        credit_coherent_weight(env, &dispute, voter, weight);
     }
 
-    // Award the reward
-    update_voter(&env, voter_data, 10, 1);
+    // Mark as claimed, kept as long as the finished dispute
+    env.storage().persistent().set(&claim_key, &true);
+    retain_persistent(env, &claim_key);
 
-    // Mark as claimed
-    env.storage().instance().set(&claim_key, &true);
+    events::RewardClaimed {
+        dispute_id,
+        voter: voter.clone(),
+        token: dispute.token,
+        amount: 10 + share,
+        slashed_share,
+    }
+    .publish(env);
 
-    Ok(())
+    10 + share + slashed_share
 }
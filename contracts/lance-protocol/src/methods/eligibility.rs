@@ -0,0 +1,219 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::admin::{require_admin, require_moderator},
+    storage::{
+        dispute::{DisputeRecord, get_dispute_record},
+        eligibility::{
+            EligibilityRules, Suspension, get_eligibility_rules, get_suspension, is_suspended,
+            remove_suspension, set_eligibility_rules as write_eligibility_rules, set_suspension,
+            were_counterparties,
+        },
+        error::Error,
+        storage::extend_instance,
+        voter::{get_voter, update_voter},
+    },
+};
+
+/// Configure the conflict-of-interest and reputation rules for jurors.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `rules` - The new rules, applied to registrations and draws from now on
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the rules were updated
+pub fn set_eligibility_rules(
+    env: &Env,
+    admin: Address,
+    rules: EligibilityRules,
+) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    write_eligibility_rules(env, &rules);
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Suspend a voter until the given time, or ban them without an expiry.
+///
+/// A suspended voter can neither register, commit nor vote on any dispute.
+/// Suspending again replaces the previous suspension.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `moderator` - The contract admin or the governance
+/// * `voter` - The voter to suspend
+/// * `until` - The end of the suspension, or None for a permanent ban
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the voter was suspended
+pub fn suspend_voter(
+    env: &Env,
+    moderator: Address,
+    voter: Address,
+    until: Option<u64>,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+    extend_instance(env);
+
+    let now = env.ledger().timestamp();
+    if until.is_some_and(|until| until <= now) {
+        return Err(Error::InvalidDuration);
+    }
+
+    set_suspension(
+        env,
+        &voter,
+        &Suspension {
+            suspended_at: now,
+            until,
+        },
+    );
+
+    events::VoterSuspended { voter, until }.publish(env);
+
+    Ok(())
+}
+
+/// Lift the suspension or ban of a voter.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `moderator` - The contract admin or the governance
+/// * `voter` - The voter to reinstate
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the voter was reinstated
+pub fn reinstate_voter(env: &Env, moderator: Address, voter: Address) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+    extend_instance(env);
+
+    if get_suspension(env, &voter).is_none() {
+        return Err(Error::VoterNotFound);
+    }
+    remove_suspension(env, &voter);
+
+    events::VoterReinstated { voter }.publish(env);
+
+    Ok(())
+}
+
+/// Delegators a judge can hold, each checked on every eligibility check
+pub(crate) const MAX_DELEGATES: u32 = 50;
+
+/// Delegate a vote to a judge, recorded in the judge's `delegates`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `delegator` - The address delegating its vote
+/// * `judge` - The registered voter receiving the delegation
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the delegation was recorded,
+///   `JudgeAlreadyVoted` if it already was, or `VoteLimitExceeded` if the
+///   judge holds `MAX_DELEGATES` delegations
+pub fn delegate_vote(env: &Env, delegator: Address, judge: Address) -> Result<(), Error> {
+    delegator.require_auth();
+
+    let mut voter = get_voter(env, judge)?;
+    if voter.delegates.contains(&delegator) {
+        return Err(Error::JudgeAlreadyVoted);
+    }
+    if voter.delegates.len() >= MAX_DELEGATES {
+        return Err(Error::VoteLimitExceeded);
+    }
+    voter.delegates.push_back(delegator);
+    update_voter(env, voter, 0, 0);
+
+    Ok(())
+}
+
+/// Withdraw a vote delegated to a judge.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `delegator` - The address which delegated its vote
+/// * `judge` - The judge holding the delegation
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the delegation was removed, or
+///   `VoterNotFound` if there was none
+pub fn undelegate_vote(env: &Env, delegator: Address, judge: Address) -> Result<(), Error> {
+    delegator.require_auth();
+
+    let mut voter = get_voter(env, judge)?;
+    let index = voter
+        .delegates
+        .first_index_of(&delegator)
+        .ok_or(Error::VoterNotFound)?;
+    voter.delegates.remove(index);
+    update_voter(env, voter, 0, 0);
+
+    Ok(())
+}
+
+/// Whether the juror delegates its vote to the given party.
+fn delegates_to(env: &Env, juror: &Address, party: &Address) -> bool {
+    match get_voter(env, party.clone()) {
+        Ok(voter) => voter.delegates.contains(juror),
+        Err(_) => false,
+    }
+}
+
+/// Check a juror against the parties of a dispute, suspensions and the
+/// eligibility rules.
+///
+/// Each rule has its own error so callers can tell why a juror was rejected.
+pub(crate) fn check_juror_eligibility(
+    env: &Env,
+    dispute: &DisputeRecord,
+    juror: &Address,
+) -> Result<(), Error> {
+    if *juror == dispute.creator || *juror == dispute.counterpart {
+        return Err(Error::NotAuthorized);
+    }
+    if is_suspended(env, juror) {
+        return Err(Error::VoterSuspended);
+    }
+
+    let rules = get_eligibility_rules(env);
+    if rules.exclude_past_counterparties
+        && (were_counterparties(env, juror, &dispute.creator)
+            || were_counterparties(env, juror, &dispute.counterpart))
+    {
+        return Err(Error::ConflictOfInterest);
+    }
+    if rules.exclude_delegators
+        && (delegates_to(env, juror, &dispute.creator)
+            || delegates_to(env, juror, &dispute.counterpart))
+    {
+        return Err(Error::DelegatesToParty);
+    }
+    if rules.min_reputation > 0 {
+        let reputation = get_voter(env, juror.clone()).map_or(0, |voter| voter.reputation);
+        if reputation < rules.min_reputation {
+            return Err(Error::ReputationTooLow);
+        }
+    }
+
+    Ok(())
+}
+
+/// Check whether an address can sit on a dispute as a juror.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of the dispute
+/// * `juror` - The candidate juror
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if eligible, otherwise the reason of the rejection
+pub fn check_juror(env: &Env, dispute_id: u32, juror: Address) -> Result<(), Error> {
+    let dispute = get_dispute_record(env, dispute_id)?;
+
+    check_juror_eligibility(env, &dispute, &juror)
+}
@@ -0,0 +1,290 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::{
+        admin::require_moderator,
+        balance::{get_balance, set_balance},
+        reputation::record_reward,
+        token::{get_token, token_transfer},
+    },
+    storage::{
+        dispute::DisputeRecord,
+        emission::{
+            EmissionEpoch, EmissionSchedule, get_emission_epoch, get_emission_schedule,
+            get_emission_weight, get_emissions, set_emission_epoch,
+            set_emission_schedule as write_emission_schedule, set_emission_weight, set_emissions,
+        },
+        error::Error,
+        storage::extend_instance,
+    },
+};
+
+/// Time jurors have after the last ruling of an epoch to claim its emission,
+/// past the appeal period and the retention of the finished disputes
+pub(crate) const EMISSION_CLAIM_PERIOD: u64 = 90 * 24 * 3600;
+
+/// Epoch of the emission schedule at the current time, if there is one.
+pub(crate) fn current_epoch(env: &Env) -> Option<u32> {
+    let schedule = get_emission_schedule(env)?;
+    let elapsed = env.ledger().timestamp() - schedule.started_at;
+
+    Some(schedule.first_epoch + (elapsed / schedule.epoch_duration) as u32)
+}
+
+/// Configure the emission of protocol tokens to coherent jurors.
+///
+/// The current epoch closes with the rate it started with and the new rate
+/// applies from the next epoch, which starts now. A rate of 0 pauses the
+/// emission.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `moderator` - The contract admin or the governance
+/// * `rate` - Tokens emitted per epoch
+/// * `epoch_duration` - Length of an epoch in seconds
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the schedule was updated
+pub fn set_emission_schedule(
+    env: &Env,
+    moderator: Address,
+    rate: i128,
+    epoch_duration: u64,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    if rate < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if epoch_duration == 0 {
+        return Err(Error::InvalidDuration);
+    }
+
+    let first_epoch = current_epoch(env).map_or(0, |epoch| epoch + 1);
+    write_emission_schedule(
+        env,
+        &EmissionSchedule {
+            rate,
+            epoch_duration,
+            started_at: env.ledger().timestamp(),
+            first_epoch,
+        },
+    );
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Add protocol tokens to the reward reservoir.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `funder` - The contract admin or the governance, paying the tokens
+/// * `amount` - Tokens added to the reservoir
+///
+/// # Returns
+/// * `Result<i128, Error>` - The reservoir balance
+pub fn fund_emissions(env: &Env, funder: Address, amount: i128) -> Result<i128, Error> {
+    require_moderator(env, &funder)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    token_transfer(
+        env,
+        &get_token(env)?,
+        &funder,
+        &env.current_contract_address(),
+        &amount,
+    )?;
+    add_to_reservoir(env, amount);
+    extend_instance(env);
+
+    events::EmissionsFunded { funder, amount }.publish(env);
+
+    Ok(get_emissions(env).reservoir)
+}
+
+/// Add protocol tokens the contract already holds to the reward reservoir.
+pub(crate) fn add_to_reservoir(env: &Env, amount: i128) {
+    let mut emissions = get_emissions(env);
+    emissions.reservoir += amount;
+    set_emissions(env, &emissions);
+}
+
+/// Record the coherent weight of a dispute ruled in the current epoch,
+/// returning the epoch. Nothing is recorded without an emission schedule or
+/// without coherent weight.
+pub(crate) fn record_ruling_weight(env: &Env, weight: u128) -> Option<u32> {
+    let schedule = get_emission_schedule(env)?;
+    let epoch = current_epoch(env)?;
+    if weight == 0 {
+        return None;
+    }
+
+    let mut record = get_emission_epoch(env, epoch).unwrap_or(EmissionEpoch {
+        rate: schedule.rate,
+        ..Default::default()
+    });
+    record.total_weight += weight;
+    record.last_ruled_at = env.ledger().timestamp();
+    set_emission_epoch(env, epoch, &record);
+
+    Some(epoch)
+}
+
+/// Credit a coherent juror with their weight towards the emission of the
+/// dispute's ruling epoch.
+///
+/// Jurors are credited once their vote is known to be for the winner: public
+/// and revealed votes when claiming their reward, anonymous votes once proven
+/// with [`crate::methods::vote::prove_coherent_vote`]. The credits never
+/// exceed the coherent weight recorded at the ruling.
+pub(crate) fn credit_coherent_weight(
+    env: &Env,
+    record: &DisputeRecord,
+    juror: &Address,
+    weight: u32,
+) {
+    let Some(epoch) = record.emission_epoch else {
+        return;
+    };
+
+    if weight == 0 {
+        return;
+    }
+    let earned = get_emission_weight(env, epoch, juror).unwrap_or(0);
+    set_emission_weight(env, epoch, juror, earned + weight as u128);
+}
+
+/// Whether the claim period of a closed epoch is over.
+fn claim_period_over(env: &Env, record: &EmissionEpoch) -> bool {
+    env.ledger().timestamp() >= record.last_ruled_at + EMISSION_CLAIM_PERIOD
+}
+
+/// Claim a juror's share of the emission of a closed epoch.
+///
+/// The first claim of an epoch takes its emission from the reservoir, capped
+/// by what is left in it. The emission is shared by the coherent weight of
+/// the disputes ruled during the epoch, credited to each juror when they
+/// are known to be coherent, and paid to the juror's balance in the protocol
+/// token. Claims close `EMISSION_CLAIM_PERIOD` after the last ruling of the
+/// epoch, when what is left can be returned with [`reclaim_emission`].
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - The juror claiming the emission
+/// * `epoch` - A closed epoch
+///
+/// # Returns
+/// * `Result<i128, Error>` - The amount credited, or `InsufficientTime` if the
+///   epoch is still running or its claim period is over
+pub fn claim_emission(env: &Env, juror: Address, epoch: u32) -> Result<i128, Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    if current_epoch(env).is_none_or(|current| epoch >= current) {
+        return Err(Error::InsufficientTime);
+    }
+    let weight = match get_emission_weight(env, epoch, &juror) {
+        None => return Err(Error::NotEligible),
+        Some(0) => return Err(Error::AlreadyClaimed),
+        Some(weight) => weight,
+    };
+
+    // the weight only exists once the epoch has a record
+    let mut record = get_emission_epoch(env, epoch).unwrap();
+    if claim_period_over(env, &record) {
+        return Err(Error::InsufficientTime);
+    }
+    let mut emissions = get_emissions(env);
+    let emission = match record.emission {
+        Some(emission) => emission,
+        None => {
+            let emission = record.rate.min(emissions.reservoir);
+            emissions.reservoir -= emission;
+            emissions.allocated += emission;
+            record.emission = Some(emission);
+            emission
+        }
+    };
+
+    // rounding remainders stay allocated to the epoch
+    let share = (emission as u128 * weight / record.total_weight) as i128;
+    record.distributed += share;
+    emissions.allocated -= share;
+    emissions.distributed += share;
+    set_emission_epoch(env, epoch, &record);
+    set_emissions(env, &emissions);
+    set_emission_weight(env, epoch, &juror, 0);
+
+    if share > 0 {
+        let token = get_token(env)?;
+        set_balance(
+            env,
+            &juror,
+            &token,
+            get_balance(env, &juror, &token) + share,
+        );
+        record_reward(env, &juror, share);
+    }
+
+    events::EmissionClaimed {
+        epoch,
+        juror,
+        amount: share,
+    }
+    .publish(env);
+
+    Ok(share)
+}
+
+/// Return the emission of an epoch left unclaimed to the reservoir.
+///
+/// Anyone can reclaim once the claim period of the epoch is over: the
+/// emission of jurors who never claimed, or who were never proven coherent,
+/// and the rounding remainders go back to the reservoir for later epochs.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `epoch` - A closed epoch
+///
+/// # Returns
+/// * `Result<i128, Error>` - The amount returned, `InsufficientTime` if the
+///   claim period is not over, or `AlreadyClaimed` if nothing is left
+pub fn reclaim_emission(env: &Env, epoch: u32) -> Result<i128, Error> {
+    extend_instance(env);
+
+    if current_epoch(env).is_none_or(|current| epoch >= current) {
+        return Err(Error::InsufficientTime);
+    }
+    let mut record = get_emission_epoch(env, epoch).ok_or(Error::NotEligible)?;
+    if !claim_period_over(env, &record) {
+        return Err(Error::InsufficientTime);
+    }
+
+    // an epoch nobody claimed never took its emission from the reservoir
+    let unclaimed = record
+        .emission
+        .map_or(0, |emission| emission - record.distributed);
+    if unclaimed == 0 {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    record.emission = Some(record.distributed);
+    set_emission_epoch(env, epoch, &record);
+    let mut emissions = get_emissions(env);
+    emissions.allocated -= unclaimed;
+    emissions.reservoir += unclaimed;
+    set_emissions(env, &emissions);
+
+    events::EmissionReclaimed {
+        epoch,
+        amount: unclaimed,
+    }
+    .publish(env);
+
+    Ok(unclaimed)
+}
@@ -0,0 +1,459 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::{
+        admin::require_admin,
+        balance::{get_balance, set_balance},
+        phase::require_phase,
+        emission::add_to_reservoir,
+        token::{get_token, payment_token, token_transfer},
+    },
+    storage::{
+        config::get_protocol_config,
+        court::get_court,
+        dispute::{
+            DisputeRecord, REWARD_CLAIM_PERIOD, enter_phase, get_creator_activity,
+            get_dispute_record, set_creator_activity, set_dispute_record,
+        },
+        dispute_status::DisputePhase,
+        error::Error,
+        storage::{DataKey, extend_instance},
+    },
+};
+
+/// Fee each party of a dispute deposits, in units of the dispute's token.
+///
+/// Disputes in a court pay the court's fee. Other disputes are free until the
+/// admin configures a fee.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `court_id` - The court of the dispute, if any
+///
+/// # Returns
+/// * `Result<i128, Error>` - The arbitration fee
+pub fn arbitration_cost(env: &Env, court_id: Option<u32>) -> Result<i128, Error> {
+    if let Some(court_id) = court_id {
+        return Ok(get_court(env, court_id)?.fee);
+    }
+
+    Ok(env
+        .storage()
+        .instance()
+        .get(&DataKey::ArbitrationFee)
+        .unwrap_or(0))
+}
+
+/// Token the fees and rewards of a new dispute are paid in.
+///
+/// Disputes in a court are paid in the court's token, other disputes in the
+/// requested token if allowed, or in the protocol token.
+///
+/// # Returns
+/// * `Result<Address, Error>` - The token, or `NotAuthorized` if the requested
+///   token is not allowed or differs from the court's token
+pub(crate) fn dispute_token(
+    env: &Env,
+    court_id: Option<u32>,
+    token: Option<Address>,
+) -> Result<Address, Error> {
+    match court_id {
+        Some(court_id) => {
+            let court_token = get_court(env, court_id)?.token;
+            if token.is_some_and(|token| token != court_token) {
+                return Err(Error::NotAuthorized);
+            }
+            Ok(court_token)
+        }
+        None => payment_token(env, token),
+    }
+}
+
+/// Configure the arbitration fee charged to both parties of new disputes
+/// outside of courts.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `fee` - The new fee, applied to disputes created from now on
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the fee was updated
+pub fn set_arbitration_fee(env: &Env, admin: Address, fee: i128) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if fee < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    env.storage().instance().set(&DataKey::ArbitrationFee, &fee);
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Deposit the creator's fee and bond and initialize the fee fields of a new
+/// dispute.
+pub(crate) fn deposit_creator_fee(
+    env: &Env,
+    record: &mut DisputeRecord,
+    fee: i128,
+) -> Result<(), Error> {
+    let config = get_protocol_config(env);
+    let deposit = fee + config.creation_bond;
+
+    if deposit > 0 {
+        token_transfer(
+            env,
+            &record.token,
+            &record.creator,
+            &env.current_contract_address(),
+            &deposit,
+        )?;
+    }
+
+    record.arbitration_fee = fee;
+    record.creation_bond = config.creation_bond;
+    record.fee_deadline = record
+        .voting_ends_at
+        .min(env.ledger().timestamp() + config.evidence_duration);
+    // there is nothing to match on free disputes
+    record.counterpart_paid = fee == 0;
+
+    Ok(())
+}
+
+/// Match the creator's arbitration fee as the counterpart.
+///
+/// Jurors can only register and vote once both parties have paid.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `counterpart` - The counterpart of the dispute
+/// * `dispute_id` - The ID of the dispute
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The updated dispute
+pub fn pay_arbitration_fee(
+    env: &Env,
+    counterpart: Address,
+    dispute_id: u32,
+) -> Result<DisputeRecord, Error> {
+    counterpart.require_auth();
+    extend_instance(env);
+
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    if counterpart != dispute.counterpart {
+        return Err(Error::NotAuthorized);
+    }
+    require_phase(env, &mut dispute, &[DisputePhase::Evidence])?;
+    if env.ledger().timestamp() >= dispute.fee_deadline {
+        return Err(Error::FeeDeadlinePassed);
+    }
+
+    token_transfer(
+        env,
+        &dispute.token,
+        &counterpart,
+        &env.current_contract_address(),
+        &dispute.arbitration_fee,
+    )?;
+    dispute.counterpart_paid = true;
+    enter_phase(
+        &mut dispute,
+        DisputePhase::Registration,
+        env.ledger().timestamp(),
+    );
+    set_dispute_record(env, &dispute);
+
+    events::ArbitrationFeePaid {
+        dispute_id,
+        payer: counterpart,
+        amount: dispute.arbitration_fee,
+    }
+    .publish(env);
+
+    Ok(dispute)
+}
+
+/// Rule for the creator when the counterpart did not match the fee in time.
+///
+/// The creator's fee is refunded to their balance and no jurors are involved.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `creator` - The creator of the dispute
+/// * `dispute_id` - The ID of the dispute
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The resolved dispute
+pub fn claim_default_ruling(
+    env: &Env,
+    creator: Address,
+    dispute_id: u32,
+) -> Result<DisputeRecord, Error> {
+    creator.require_auth();
+    extend_instance(env);
+
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    if creator != dispute.creator {
+        return Err(Error::NotAuthorized);
+    }
+    require_phase(env, &mut dispute, &[DisputePhase::Evidence])?;
+    if env.ledger().timestamp() < dispute.fee_deadline {
+        return Err(Error::InsufficientTime);
+    }
+
+    // there is no vote to appeal
+    let now = env.ledger().timestamp();
+    dispute.winner = Some(creator);
+    dispute.finish_timestamp = Some(now);
+    enter_phase(&mut dispute, DisputePhase::Final, now);
+    settle_fees(env, &mut dispute, 0, 0);
+    set_dispute_record(env, &dispute);
+
+    Ok(dispute)
+}
+
+/// Settle the parties' fees once the dispute has a result.
+///
+/// The winner's fee is refunded and the loser's fee becomes the reward pool
+/// of the jurors who voted for the winner, shared by weight when they claim
+/// their reward. Anonymous voters can all claim, so the pool is shared by
+/// the `claimable_weight` of every voter rather than the coherent
+/// `rewarded_weight`. Without a winner, or without any coherent juror to pay,
+/// the fees are refunded. Refunds are credited to the parties' balances.
+///
+/// The creator's bond goes to the counterpart when the ruling is against the
+/// creator and is refunded otherwise. The dispute no longer counts towards
+/// the creator's open disputes.
+///
+/// The caller is responsible for persisting the updated record.
+pub(crate) fn settle_fees(
+    env: &Env,
+    record: &mut DisputeRecord,
+    rewarded_weight: u128,
+    claimable_weight: u128,
+) {
+    let fee = record.arbitration_fee;
+    let token = record.token.clone();
+    let credit = |party: &Address, amount: i128| {
+        if amount > 0 {
+            set_balance(env, party, &token, get_balance(env, party, &token) + amount);
+        }
+    };
+    let refund = |party: &Address| credit(party, fee);
+
+    if record.winner.as_ref() == Some(&record.counterpart) {
+        credit(&record.counterpart, record.creation_bond);
+    } else {
+        credit(&record.creator, record.creation_bond);
+    }
+    if let Some(mut activity) = get_creator_activity(env, &record.creator) {
+        activity.open_disputes = activity.open_disputes.saturating_sub(1);
+        set_creator_activity(env, &record.creator, &activity);
+    }
+
+    match record.winner.clone() {
+        Some(winner) => {
+            let loser = if winner == record.creator {
+                record.counterpart.clone()
+            } else {
+                record.creator.clone()
+            };
+            let loser_paid = loser == record.creator || record.counterpart_paid;
+
+            refund(&winner);
+            record.rewarded_weight = rewarded_weight;
+            record.claimable_weight = claimable_weight;
+            if loser_paid && rewarded_weight > 0 {
+                record.reward_pool = fee;
+            } else if loser_paid {
+                refund(&loser);
+            }
+        }
+        None => {
+            refund(&record.creator);
+            if record.counterpart_paid {
+                refund(&record.counterpart);
+            }
+        }
+    }
+
+    events::FeesSettled {
+        dispute_id: record.dispute_id,
+        winner: record.winner.clone(),
+        reward_pool: record.reward_pool,
+    }
+    .publish(env);
+}
+
+/// Share of a pool owed to a juror of the given weight, rounded down.
+fn pool_share(pool: i128, weight: u32, claimable_weight: u128) -> i128 {
+    if claimable_weight == 0 {
+        return 0;
+    }
+    // weights are bounded by the locked stake, so the product fits
+    (pool as u128 * weight as u128 / claimable_weight) as i128
+}
+
+/// Credit a coherent juror with their share of the reward pool, in the
+/// dispute's token, and of the slashed pool, in the protocol token.
+///
+/// Shares are rounded down. Once the jurors claimed the whole claimable
+/// weight, what the rounding left in the pools goes to the dust sink; what
+/// jurors leave unclaimed is swept there by [`sweep_unclaimed_rewards`].
+///
+/// The caller is responsible for persisting the updated record.
+///
+/// # Returns
+/// * `(i128, i128)` - The shares of the reward pool and of the slashed pool
+pub(crate) fn pay_reward(
+    env: &Env,
+    record: &mut DisputeRecord,
+    juror: &Address,
+    weight: u32,
+) -> Result<(i128, i128), Error> {
+    let share = pool_share(record.reward_pool, weight, record.claimable_weight);
+    let slashed_share = pool_share(record.slashed_pool, weight, record.claimable_weight);
+    let protocol_token = get_token(env)?;
+    let credit = |token: &Address, amount: i128| {
+        if amount > 0 {
+            set_balance(env, juror, token, get_balance(env, juror, token) + amount);
+        }
+    };
+    credit(&record.token, share);
+    credit(&protocol_token, slashed_share);
+
+    record.claimed_weight += weight as u128;
+    record.reward_paid += share;
+    record.slashed_paid += slashed_share;
+    if record.claimed_weight >= record.claimable_weight {
+        close_pools(env, record, &protocol_token);
+    }
+
+    Ok((share, slashed_share))
+}
+
+/// Send what is left in the pools of a dispute to the dust sink, returning
+/// the amounts left in the reward pool and in the slashed pool.
+fn close_pools(env: &Env, record: &mut DisputeRecord, protocol_token: &Address) -> (i128, i128) {
+    let reward_left = record.reward_pool - record.reward_paid;
+    let slashed_left = record.slashed_pool - record.slashed_paid;
+    sink_dust(env, &record.token, reward_left);
+    sink_dust(env, protocol_token, slashed_left);
+    record.reward_paid = record.reward_pool;
+    record.slashed_paid = record.slashed_pool;
+
+    (reward_left, slashed_left)
+}
+
+/// Sweep the rewards jurors left unclaimed once the claim period is over.
+///
+/// Anyone can sweep a dispute `REWARD_CLAIM_PERIOD` after its ruling became
+/// final. The unclaimed shares and the rounding remainders of the reward
+/// pool and of the slashed pool go to the dust sink, as when every juror
+/// claimed.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of a dispute with a final ruling
+///
+/// # Returns
+/// * `Result<(i128, i128), Error>` - The amounts swept from the reward pool
+///   and from the slashed pool, `InsufficientTime` if the claim period is not
+///   over, or `AlreadyClaimed` if nothing is left
+pub fn sweep_unclaimed_rewards(env: &Env, dispute_id: u32) -> Result<(i128, i128), Error> {
+    extend_instance(env);
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    require_phase(env, &mut dispute, &[DisputePhase::Final])?;
+    if dispute.settled_count < dispute.juror_count {
+        return Err(Error::ProposalActive);
+    }
+    if env.ledger().timestamp() < dispute.phase_started_at + REWARD_CLAIM_PERIOD {
+        return Err(Error::InsufficientTime);
+    }
+    if dispute.reward_paid == dispute.reward_pool && dispute.slashed_paid == dispute.slashed_pool {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    let (reward, slashed) = close_pools(env, &mut dispute, &get_token(env)?);
+    set_dispute_record(env, &dispute);
+
+    events::RewardsSwept {
+        dispute_id,
+        reward,
+        slashed,
+    }
+    .publish(env);
+
+    Ok((reward, slashed))
+}
+
+/// Collect the rounding remainder of a pool.
+///
+/// Dust in the protocol token feeds the rewards reservoir, dust in other
+/// tokens is kept for the admin to sweep.
+fn sink_dust(env: &Env, token: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    if get_token(env).is_ok_and(|protocol_token| protocol_token == *token) {
+        add_to_reservoir(env, amount);
+    } else {
+        let key = DataKey::RewardDust(token.clone());
+        let dust: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(dust + amount));
+    }
+}
+
+/// Rounding remainders of the reward pools collected in a token other than
+/// the protocol token.
+pub fn get_reward_dust(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RewardDust(token))
+        .unwrap_or(0)
+}
+
+/// Send the rounding remainders collected in a token to a recipient.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `token` - The token of the remainders
+/// * `recipient` - The address receiving them
+///
+/// # Returns
+/// * `Result<i128, Error>` - The amount sent, or `BalanceIsZero` if there is
+///   nothing to sweep
+pub fn sweep_reward_dust(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    recipient: Address,
+) -> Result<i128, Error> {
+    require_admin(env, &admin)?;
+
+    let amount = get_reward_dust(env, token.clone());
+    if amount == 0 {
+        return Err(Error::BalanceIsZero);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::RewardDust(token.clone()));
+    extend_instance(env);
+    token_transfer(
+        env,
+        &token,
+        &env.current_contract_address(),
+        &recipient,
+        &amount,
+    )?;
+
+    Ok(amount)
+}
@@ -4,7 +4,7 @@ use super::{
     admin::{has_admin, set_admin},
     token::set_token,
 };
-use crate::storage::error::Error;
+use crate::storage::{error::Error, storage::extend_instance};
 
 pub fn initialize(
     env: &Env,
@@ -17,6 +17,7 @@ pub fn initialize(
 
     set_admin(&env, &admin);
     set_token(&env, &token);
+    extend_instance(env);
 
     Ok(())
 }
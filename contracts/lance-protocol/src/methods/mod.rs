@@ -1,6 +1,19 @@
 pub mod admin;
 pub mod balance;
+pub mod ballot;
+pub mod config;
+pub mod court;
 pub mod dispute;
+pub mod eligibility;
+pub mod emission;
+pub mod fee;
 pub mod initialize;
+pub mod phase;
+pub mod registry;
+pub mod reputation;
+pub mod settlement;
+pub mod tally;
 pub mod token;
+pub mod ttl;
+pub mod vault;
 pub mod vote;
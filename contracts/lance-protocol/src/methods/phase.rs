@@ -0,0 +1,48 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    dispute::{DisputeRecord, sync_phase},
+    dispute_status::DisputePhase,
+    error::Error,
+};
+
+/// Guard of every dispute entrypoint: bring the dispute up to its current
+/// phase and check the action is allowed in it.
+///
+/// The error tells why the dispute is not in one of the `allowed` phases:
+/// * `ArbitrationFeeNotPaid` - the counterpart has not matched the fee yet
+/// * `ArbitrationFeeAlreadyPaid` - the dispute moved past the fee payment
+/// * `ProposalVotingTime` - voting is still running, or already over
+/// * `ProposalActive` - the voting ended but the dispute is not ruled yet
+/// * `InsufficientTime` - the ruling is still appealable
+/// * `DisputeAlreadyResolved` - the dispute is ruled, settled or final
+///
+/// # Returns
+/// * `Result<DisputePhase, Error>` - The current phase
+pub(crate) fn require_phase(
+    env: &Env,
+    record: &mut DisputeRecord,
+    allowed: &[DisputePhase],
+) -> Result<DisputePhase, Error> {
+    let phase = sync_phase(env, record);
+    if allowed.contains(&phase) {
+        return Ok(phase);
+    }
+
+    let too_early = allowed.iter().all(|allowed| phase < *allowed);
+    let fee_only = allowed
+        .iter()
+        .all(|allowed| *allowed == DisputePhase::Evidence);
+    Err(match phase {
+        DisputePhase::Evidence => Error::ArbitrationFeeNotPaid,
+        DisputePhase::Registration | DisputePhase::Voting | DisputePhase::Tally if fee_only => {
+            Error::ArbitrationFeeAlreadyPaid
+        }
+        DisputePhase::Tally if too_early => Error::ProposalActive,
+        DisputePhase::Registration | DisputePhase::Voting | DisputePhase::Tally => {
+            Error::ProposalVotingTime
+        }
+        DisputePhase::Appealable if too_early => Error::InsufficientTime,
+        DisputePhase::Appealable | DisputePhase::Final => Error::DisputeAlreadyResolved,
+    })
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::{admin::require_admin, config::voting_ends_at, fee::dispute_token, vote::MAX_JURORS},
+    storage::{
+        dispute::DISPUTE_OPTIONS,
+        error::Error,
+        registry::{
+            CallerPolicy, DisputeTemplate, get_caller_policy, remove_caller_template,
+            set_caller_policy as write_caller_policy, set_caller_template,
+        },
+        storage::extend_instance,
+        vote::VotingMode,
+    },
+};
+
+/// Register an integrating contract with the template of its disputes.
+///
+/// Registering again replaces the template, disputes already opened keep
+/// their parameters.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `caller` - The integrating contract, passed as `called_contract`
+/// * `template` - The parameters of the caller's disputes
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the caller was registered
+pub fn register_caller(
+    env: &Env,
+    admin: Address,
+    caller: Address,
+    template: DisputeTemplate,
+) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    dispute_token(env, template.court_id, template.token.clone())?;
+    if template.juror_count == 0 || template.juror_count > MAX_JURORS {
+        return Err(Error::VoteLimitExceeded);
+    }
+    voting_ends_at(env, template.voting_duration)?;
+    if template.arbitration_fee.is_some_and(|fee| fee < 0) {
+        return Err(Error::InvalidAmount);
+    }
+    // disputes rule for either party or abstain
+    if template.ruling_options != DISPUTE_OPTIONS {
+        return Err(Error::InvalidBallotOptions);
+    }
+
+    set_caller_template(env, &caller, &template);
+    extend_instance(env);
+
+    events::CallerRegistered {
+        caller,
+        court_id: template.court_id,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Remove an integrating contract from the registry.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `caller` - A registered caller
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the caller was removed
+pub fn unregister_caller(env: &Env, admin: Address, caller: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if !remove_caller_template(env, &caller) {
+        return Err(Error::CallerNotRegistered);
+    }
+    extend_instance(env);
+
+    events::CallerUnregistered { caller }.publish(env);
+
+    Ok(())
+}
+
+/// Configure how disputes of unregistered callers are handled.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `policy` - The new policy, applied to disputes created from now on
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the policy was updated
+pub fn set_caller_policy(env: &Env, admin: Address, policy: CallerPolicy) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if let CallerPolicy::Fee(fee) = policy
+        && fee < 0
+    {
+        return Err(Error::InvalidAmount);
+    }
+
+    write_caller_policy(env, &policy);
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Template of a dispute opened by an unregistered caller with its own
+/// parameters.
+///
+/// # Returns
+/// * `Result<DisputeTemplate, Error>` - The dispute parameters, or
+///   `CallerNotRegistered` if only registered callers can open disputes
+pub(crate) fn unregistered_template(
+    env: &Env,
+    court_id: Option<u32>,
+    voting_duration: u64,
+    voting_mode: VotingMode,
+) -> Result<DisputeTemplate, Error> {
+    let arbitration_fee = match get_caller_policy(env) {
+        CallerPolicy::Open => None,
+        CallerPolicy::Fee(fee) => Some(fee),
+        CallerPolicy::Closed => return Err(Error::CallerNotRegistered),
+    };
+
+    Ok(DisputeTemplate {
+        court_id,
+        juror_count: MAX_JURORS,
+        voting_duration,
+        voting_mode,
+        arbitration_fee,
+        ruling_options: DISPUTE_OPTIONS,
+        token: None,
+    })
+}
@@ -0,0 +1,192 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    events,
+    storage::{
+        dispute::{DisputeRecord, get_commit, get_dispute_vote, get_reveal, has_dispute_vote},
+        reputation::{JurorStats, LeaderboardEntry, get_juror_stats, get_ranking, set_juror_stats},
+        vote::{VoteAnon, VoteChoice, VotingMode},
+        voter::{get_voter, touch_voter, update_voter},
+    },
+};
+
+/// Reputation lost for voting against the winner of a dispute
+pub(crate) const INCOHERENT_VOTE_PENALTY: u32 = 1;
+/// Largest page of the leaderboard, each juror taking two entries of the
+/// footprint
+pub(crate) const LEADERBOARD_PAGE_SIZE: u32 = 25;
+
+/// Update the stats of a juror.
+fn update_stats(env: &Env, juror: &Address, update: impl FnOnce(&mut JurorStats)) {
+    let mut stats = get_juror_stats(env, juror);
+    update(&mut stats);
+    set_juror_stats(env, juror, &stats);
+}
+
+/// Record a vote or commit, which also restarts the juror's reputation decay.
+pub(crate) fn record_vote(env: &Env, juror: &Address) {
+    update_stats(env, juror, |stats| {
+        stats.disputes += 1;
+        stats.last_active = env.ledger().timestamp();
+    });
+    touch_voter(env, juror);
+}
+
+/// Record a reward credited to a juror.
+pub(crate) fn record_reward(env: &Env, juror: &Address, amount: i128) {
+    update_stats(env, juror, |stats| stats.rewards_earned += amount);
+}
+
+/// Lower the reputation of a registered voter.
+///
+/// Addresses which never registered as voters have no reputation to lose.
+fn penalize(env: &Env, dispute_id: u32, juror: Address, penalty: u32) {
+    let Ok(voter) = get_voter(env, juror.clone()) else {
+        return;
+    };
+    let reputation = voter.reputation.saturating_sub(penalty);
+    update_voter(env, voter, 0, -(penalty as i32));
+
+    events::JurorPenalized {
+        dispute_id,
+        juror,
+        penalty,
+        reputation,
+    }
+    .publish(env);
+}
+
+/// Whether a seated juror cast a vote, or for commit-reveal disputes a commit
+/// which every reveal has to open.
+fn has_voted(env: &Env, record: &DisputeRecord, juror: &Address) -> bool {
+    match record.voting_mode {
+        VotingMode::CommitReveal => get_commit(env, record.dispute_id, juror).is_some(),
+        VotingMode::Public | VotingMode::Anonymous => {
+            has_dispute_vote(env, record.dispute_id, juror)
+        }
+    }
+}
+
+/// Record the coherence of a juror's known choice, penalizing votes for the loser.
+fn judge_vote(env: &Env, dispute_id: u32, juror: Address, coherent: bool) {
+    update_stats(env, &juror, |stats| {
+        if coherent {
+            stats.coherent_votes += 1;
+        } else {
+            stats.incoherent_votes += 1;
+        }
+    });
+    if !coherent {
+        penalize(env, dispute_id, juror, INCOHERENT_VOTE_PENALTY);
+    }
+}
+
+/// Outcome of a seated juror's participation in a ruled dispute.
+pub(crate) enum Verdict {
+    Absent,          // Never voted, or never committed
+    Incoherent(u32), // Voted for the loser with this weight
+    Cleared,         // Voted for the winner, abstained, or voted anonymously
+}
+
+/// Apply the ruling of a dispute to the reputation and stats of a seated juror.
+///
+/// Jurors who never voted lose the configured `missed_vote_penalty`. Public
+/// and revealed votes for the losing party lose `INCOHERENT_VOTE_PENALTY`;
+/// abstaining is not penalized and anonymous choices are never known.
+pub(crate) fn settle_juror_reputation(
+    env: &Env,
+    record: &DisputeRecord,
+    juror: &Address,
+    missed_vote_penalty: u32,
+) -> Verdict {
+    let dispute_id = record.dispute_id;
+
+    if !has_voted(env, record, juror) {
+        update_stats(env, juror, |stats| stats.missed_votes += 1);
+        if missed_vote_penalty > 0 {
+            penalize(env, dispute_id, juror.clone(), missed_vote_penalty);
+        }
+        return Verdict::Absent;
+    }
+
+    match judged_vote(env, record, juror) {
+        Some((weight, coherent)) => {
+            judge_vote(env, dispute_id, juror.clone(), coherent);
+            if coherent {
+                Verdict::Cleared
+            } else {
+                Verdict::Incoherent(weight)
+            }
+        }
+        None => Verdict::Cleared,
+    }
+}
+
+/// Weight and coherence of a juror's known vote for a party of a dispute with
+/// a winner.
+///
+/// Public and revealed votes are known; abstaining and anonymous votes are
+/// never judged.
+fn judged_vote(env: &Env, record: &DisputeRecord, juror: &Address) -> Option<(u32, bool)> {
+    let dispute_id = record.dispute_id;
+    let losing_choice = match &record.winner {
+        Some(winner) if *winner == record.creator => VoteChoice::Counterpart,
+        Some(_) => VoteChoice::Creator,
+        None => return None,
+    };
+
+    match record.voting_mode {
+        VotingMode::Public => match get_dispute_vote(env, dispute_id, juror) {
+            Some(VoteAnon::PublicVote(vote)) if vote.vote_choice != VoteChoice::Abstain => {
+                Some((vote.weight, vote.vote_choice != losing_choice))
+            }
+            _ => None,
+        },
+        // a revealed `true` is a vote for the creator
+        VotingMode::CommitReveal => get_reveal(env, dispute_id, juror)
+            .map(|vote| (1, vote != (losing_choice == VoteChoice::Creator))),
+        VotingMode::Anonymous => None,
+    }
+}
+
+/// Page through the best jurors with their reputation and stats.
+///
+/// Jurors are listed from the leaderboard index, which every update of a
+/// voter keeps sorted by reputation, and only holds the `LEADERBOARD_SIZE`
+/// best jurors with some reputation. Reputation decays without any write, so
+/// the index ranks jurors by their reputation as of their last update while
+/// the entries show it decayed to the time of the query.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `offset` - The rank to start from
+/// * `limit` - The page size, at most `LEADERBOARD_PAGE_SIZE`
+///
+/// # Returns
+/// * `Vec<LeaderboardEntry>` - The page, empty past the last ranked juror
+pub fn get_leaderboard(env: &Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+    let ranking = get_ranking(env);
+    let end = ranking
+        .len()
+        .min(offset.saturating_add(limit.min(LEADERBOARD_PAGE_SIZE)));
+
+    let mut page: Vec<LeaderboardEntry> = Vec::new(env);
+    for index in offset..end {
+        let juror = ranking.get_unchecked(index).juror;
+        let reputation = get_voter(env, juror.clone()).map_or(0, |voter| voter.reputation);
+        let stats = get_juror_stats(env, &juror);
+        let judged = stats.coherent_votes + stats.incoherent_votes;
+        let coherence_bps = if judged == 0 {
+            0
+        } else {
+            (stats.coherent_votes as u64 * 10_000 / judged as u64) as u32
+        };
+        page.push_back(LeaderboardEntry {
+            juror,
+            reputation,
+            coherence_bps,
+            stats,
+        });
+    }
+    page
+}
@@ -0,0 +1,156 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol, vec};
+
+use crate::{
+    events,
+    methods::{fee::settle_fees, phase::require_phase},
+    storage::{
+        dispute::{
+            DisputeRecord, Settlement, enter_phase, get_dispute_record, get_settlement,
+            set_dispute_record, set_settlement,
+        },
+        dispute_status::{DisputePhase, DisputeStatus},
+        error::Error,
+        storage::{DataKey, extend_instance, retain_persistent},
+    },
+};
+
+/// Basis points of the whole disputed payment
+pub(crate) const MAX_SHARE_BPS: u32 = 10_000;
+
+/// Function of the calling contract notified of a settlement, with the
+/// dispute ID and the creator's share in basis points
+pub(crate) const SETTLEMENT_CALLBACK: &str = "dispute_settled";
+
+/// Read a dispute still being voted on and check the caller is one of its parties.
+fn get_settling_dispute(
+    env: &Env,
+    party: &Address,
+    dispute_id: u32,
+) -> Result<DisputeRecord, Error> {
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    if *party != dispute.creator && *party != dispute.counterpart {
+        return Err(Error::NotAuthorized);
+    }
+    require_phase(
+        env,
+        &mut dispute,
+        &[
+            DisputePhase::Evidence,
+            DisputePhase::Registration,
+            DisputePhase::Voting,
+        ],
+    )?;
+    Ok(dispute)
+}
+
+/// Propose to settle a dispute with the given split of the disputed payment.
+///
+/// A new proposal from either party replaces the pending one.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `party` - The creator or the counterpart of the dispute
+/// * `dispute_id` - The ID of the dispute
+/// * `creator_share_bps` - The creator's share of the disputed payment, the
+///   rest going to the counterpart
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The dispute, still open
+pub fn propose_settlement(
+    env: &Env,
+    party: Address,
+    dispute_id: u32,
+    creator_share_bps: u32,
+) -> Result<DisputeRecord, Error> {
+    party.require_auth();
+    extend_instance(env);
+
+    let dispute = get_settling_dispute(env, &party, dispute_id)?;
+    if creator_share_bps > MAX_SHARE_BPS {
+        return Err(Error::InvalidAmount);
+    }
+
+    set_settlement(
+        env,
+        &dispute,
+        &Settlement {
+            proposer: party.clone(),
+            creator_share_bps,
+        },
+    );
+
+    events::SettlementProposed {
+        dispute_id,
+        proposer: party,
+        creator_share_bps,
+    }
+    .publish(env);
+
+    Ok(dispute)
+}
+
+/// Accept the other party's settlement and close the dispute.
+///
+/// Both arbitration fees paid so far are refunded to the parties' balances
+/// and jurors are neither rewarded nor penalized. The calling contract,
+/// which holds the disputed payment, is notified through its
+/// `dispute_settled(dispute_id, creator_share_bps)` function so it can
+/// release it per the agreement. A calling contract without that function
+/// can read the agreement with `get_settlement` instead.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `party` - The party which did not propose the settlement
+/// * `dispute_id` - The ID of the dispute
+/// * `creator_share_bps` - The accepted split, which must match the proposal
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The settled dispute
+pub fn accept_settlement(
+    env: &Env,
+    party: Address,
+    dispute_id: u32,
+    creator_share_bps: u32,
+) -> Result<DisputeRecord, Error> {
+    party.require_auth();
+    extend_instance(env);
+
+    let mut dispute = get_settling_dispute(env, &party, dispute_id)?;
+
+    // the split is repeated so a last-minute change of proposal cannot be accepted
+    match get_settlement(env, dispute_id) {
+        Some(settlement) if settlement.proposer == party => return Err(Error::NotAuthorized),
+        Some(settlement) if settlement.creator_share_bps == creator_share_bps => {}
+        _ => return Err(Error::SettlementNotFound),
+    }
+
+    let now = env.ledger().timestamp();
+    dispute.dispute_status = DisputeStatus::SETTLED;
+    dispute.finish_timestamp = Some(now);
+    enter_phase(&mut dispute, DisputePhase::Final, now);
+    settle_fees(env, &mut dispute, 0, 0);
+    set_dispute_record(env, &dispute);
+    retain_persistent(env, &DataKey::Settlement(dispute_id));
+
+    events::DisputeSettled {
+        dispute_id,
+        creator_share_bps,
+    }
+    .publish(env);
+
+    // the settlement stands whether or not the calling contract handles it
+    if dispute.called_contract != env.current_contract_address() {
+        let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &dispute.called_contract,
+            &Symbol::new(env, SETTLEMENT_CALLBACK),
+            vec![
+                env,
+                dispute_id.into_val(env),
+                creator_share_bps.into_val(env),
+            ],
+        );
+    }
+
+    Ok(dispute)
+}
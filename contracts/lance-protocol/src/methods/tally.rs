@@ -0,0 +1,213 @@
+use soroban_sdk::{BytesN, Env, U256, Vec, crypto::bls12_381::G1Affine};
+
+use crate::storage::{ballot::Tally, error::Error, vote::get_anonymous_voting_config};
+
+/// Start the tally of a vote over the given number of options.
+pub fn new_tally(env: &Env, options: u32) -> Tally {
+    let mut tallies = Vec::new(env);
+    for _ in 0..options {
+        tallies.push_back(0u128);
+    }
+
+    Tally {
+        tallies,
+        tally_commitments: empty_tally_commitments(env, options),
+        total_weight: 0,
+    }
+}
+
+/// Count a public vote for an option.
+///
+/// # Returns
+/// * `Result<(), Error>` - `InvalidBallotOptions` if the option does not exist
+pub(crate) fn count_public_vote(tally: &mut Tally, option: u32, weight: u32) -> Result<(), Error> {
+    let option_tally = tally
+        .tallies
+        .get(option)
+        .ok_or(Error::InvalidBallotOptions)?;
+
+    tally.tallies.set(option, option_tally + weight as u128);
+    tally.total_weight += weight as u128;
+    Ok(())
+}
+
+/// Count an anonymous vote, which carries one commitment per option.
+///
+/// # Returns
+/// * `Result<(), Error>` - `BadCommitment` if there is not one commitment per option
+///
+/// # Panics
+/// * If a commitment is not a point of G1
+pub(crate) fn count_anonymous_vote(
+    env: &Env,
+    tally: &mut Tally,
+    commitments: &Vec<BytesN<96>>,
+    weight: u32,
+) -> Result<(), Error> {
+    if commitments.len() != tally.tally_commitments.len() {
+        return Err(Error::BadCommitment);
+    }
+    for commitment in commitments {
+        G1Affine::from_bytes(commitment);
+    }
+
+    tally.tally_commitments =
+        add_weighted_commitments(env, &tally.tally_commitments, commitments, weight);
+    tally.total_weight += weight as u128;
+    Ok(())
+}
+
+/// Replace the tallies of anonymous votes with their decoded values, once
+/// checked against the accumulated commitments.
+///
+/// # Returns
+/// * `Result<(), Error>` - `TallySeedError` if the tallies or seeds are missing
+///   or not one per option, `InvalidProof` if they do not match the commitments
+pub(crate) fn open_tally(
+    env: &Env,
+    project_id: u32,
+    tally: &mut Tally,
+    tallies: Option<Vec<u128>>,
+    seeds: Option<Vec<u128>>,
+) -> Result<(), Error> {
+    let (tallies, seeds) = match (tallies, seeds) {
+        (Some(tallies), Some(seeds)) => (tallies, seeds),
+        _ => return Err(Error::TallySeedError),
+    };
+    let options = tally.tally_commitments.len();
+    if tallies.len() != options || seeds.len() != options {
+        return Err(Error::TallySeedError);
+    }
+    if !verify_tallies(env, project_id, &tally.tally_commitments, &tallies, &seeds) {
+        return Err(Error::InvalidProof);
+    }
+
+    tally.tallies = tallies;
+    Ok(())
+}
+
+/// Build the initial tally commitments for a new vote.
+///
+/// Each option starts at the G1 identity point so the weighted commitments
+/// can be accumulated as votes are cast.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `options` - The number of options of the vote
+///
+/// # Returns
+/// * `Vec<BytesN<96>>` - One identity point per option
+pub fn empty_tally_commitments(env: &Env, options: u32) -> Vec<BytesN<96>> {
+    let mut g1_identity = [0u8; 96];
+    g1_identity[0] = 0x40;
+    let identity = BytesN::from_array(env, &g1_identity);
+
+    let mut tally_commitments = Vec::new(env);
+    for _ in 0..options {
+        tally_commitments.push_back(identity.clone());
+    }
+    tally_commitments
+}
+
+/// Fold the weighted commitments of a vote into the per-option running tallies.
+///
+/// tally_i += weight * (g*v_i + h*r_i)
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tally_commitments` - The running tallies, one per option
+/// * `commitments` - The commitments of the vote, one per option
+/// * `weight` - The weight of the vote
+///
+/// # Returns
+/// * `Vec<BytesN<96>>` - The updated running tallies
+pub fn add_weighted_commitments(
+    env: &Env,
+    tally_commitments: &Vec<BytesN<96>>,
+    commitments: &Vec<BytesN<96>>,
+    weight: u32,
+) -> Vec<BytesN<96>> {
+    let bls12_381 = env.crypto().bls12_381();
+    let weight_: U256 = U256::from_u32(env, weight);
+
+    let mut updated = Vec::new(env);
+    for (commitment, tally_commitment) in commitments.iter().zip(tally_commitments.iter()) {
+        let weighted_commitment =
+            bls12_381.g1_mul(&G1Affine::from_bytes(commitment), &weight_.clone().into());
+        let tally_commitment = bls12_381.g1_add(
+            &G1Affine::from_bytes(tally_commitment),
+            &weighted_commitment,
+        );
+        updated.push_back(tally_commitment.to_bytes());
+    }
+    updated
+}
+
+/// Check decoded tallies and seeds against the accumulated tally commitments.
+///
+/// Recomputes g*tally_i + h*seed_i for every option with the project's
+/// generator points and compares it with the running tally. The cost only
+/// depends on the number of options, not on the number of votes.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_id` - The project holding the anonymous voting configuration
+/// * `tally_commitments` - The running tallies, one per option
+/// * `tallies` - Decoded tally values (scaled by weights), one per option
+/// * `seeds` - Decoded seed values (scaled by weights), one per option
+///
+/// # Returns
+/// * `bool` - True if all tally commitments match
+///
+/// # Panics
+/// * If no anonymous voting configuration exists for the project
+pub fn verify_tallies(
+    env: &Env,
+    project_id: u32,
+    tally_commitments: &Vec<BytesN<96>>,
+    tallies: &Vec<u128>,
+    seeds: &Vec<u128>,
+) -> bool {
+    let bls12_381 = env.crypto().bls12_381();
+
+    let vote_config = get_anonymous_voting_config(env, project_id);
+    let seed_generator_point = G1Affine::from_bytes(vote_config.seed_generator_point);
+    let vote_generator_point = G1Affine::from_bytes(vote_config.vote_generator_point);
+
+    for ((tally_, seed_), tally_commitment) in tallies
+        .iter()
+        .zip(seeds.iter())
+        .zip(tally_commitments.iter())
+    {
+        let seed_: U256 = U256::from_u128(env, seed_);
+        let tally_: U256 = U256::from_u128(env, tally_);
+        let seed_point_ = bls12_381.g1_mul(&seed_generator_point, &seed_.into());
+        let tally_commitment_votes_ = bls12_381.g1_mul(&vote_generator_point, &tally_.into());
+        let commitment_check_ = bls12_381.g1_add(&tally_commitment_votes_, &seed_point_);
+
+        if commitment_check_ != G1Affine::from_bytes(tally_commitment) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Find the option holding a majority of all the votes.
+///
+/// An option wins when its tally is strictly greater than the sum of all the
+/// other tallies, otherwise there is no clear majority.
+///
+/// # Arguments
+/// * `tallies` - The tallied votes, one per option
+///
+/// # Returns
+/// * `Option<u32>` - The index of the winning option, if any
+pub fn majority_option(tallies: &Vec<u128>) -> Option<u32> {
+    let total: u128 = tallies.iter().sum();
+
+    tallies
+        .iter()
+        .position(|tally| tally > total - tally)
+        .map(|index| index as u32)
+}
@@ -1,6 +1,7 @@
 use soroban_sdk::{token::{self}, Address, Env};
 
-use crate::storage::{error::Error, storage::DataKey};
+use crate::methods::admin::require_admin;
+use crate::storage::{error::Error, storage::{DataKey, extend_instance}};
 
 pub(crate) fn set_token(env: &Env, token: &Address) {
     let key = DataKey::Token;
@@ -17,9 +18,64 @@ pub(crate) fn get_token(env: &Env) -> Result<Address, Error> {
         .ok_or(Error::ContractNotInitialized)
 }
 
-pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) -> Result<(), Error> {
-    let token_id = get_token(env)?;
-    let token = token::Client::new(env, &token_id);
+/// Whether disputes can be paid in a token.
+///
+/// The protocol token, used for court stakes, is always allowed.
+pub(crate) fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    get_token(env).is_ok_and(|protocol_token| protocol_token == *token)
+        || env
+            .storage()
+            .instance()
+            .has(&DataKey::AllowedToken(token.clone()))
+}
+
+/// Allow or disallow a token for the fees and rewards of disputes.
+///
+/// Disputes already paid in a disallowed token are settled in it as usual.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `admin` - The contract admin
+/// * `token` - The token contract
+/// * `allowed` - Whether new courts, templates and disputes can use the token
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the allowlist was updated
+pub fn allow_token(env: &Env, admin: Address, token: Address, allowed: bool) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    let key = DataKey::AllowedToken(token);
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+    extend_instance(env);
+
+    Ok(())
+}
+
+/// Resolve the payment token of a court or template, checking the allowlist.
+///
+/// # Returns
+/// * `Result<Address, Error>` - The token, the protocol token when none is
+///   given, or `NotAuthorized` if the token is not allowed
+pub(crate) fn payment_token(env: &Env, token: Option<Address>) -> Result<Address, Error> {
+    match token {
+        Some(token) if is_token_allowed(env, &token) => Ok(token),
+        Some(_) => Err(Error::NotAuthorized),
+        None => get_token(env),
+    }
+}
+
+pub fn token_transfer(
+    env: &Env,
+    token_id: &Address,
+    from: &Address,
+    to: &Address,
+    amount: &i128,
+) -> Result<(), Error> {
+    let token = token::Client::new(env, token_id);
     token.transfer(from, to, amount);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,71 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    ballot::{extend_ballot, get_ballot},
+    dispute::{extend_dispute, get_dispute_record},
+    error::Error,
+    storage::{DataKey, extend_instance, extend_persistent},
+};
+
+/// Extend the lifetime of a user's records.
+///
+/// Anyone can pay the rent to keep a voter profile and reward balance alive,
+/// so no authorization is required.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `user` - The address whose voter profile and balance should be extended
+/// * `token` - The token of the balance
+pub fn bump(env: &Env, user: Address, token: Address) {
+    extend_instance(env);
+
+    extend_persistent(env, &DataKey::Voters(user.clone()));
+    extend_persistent(env, &DataKey::Balances(user, token));
+}
+
+/// Extend the lifetime of a dispute and a page of its per-juror entries.
+///
+/// Per-juror entries are written to live until the end of the dispute's
+/// appeal period plus the retention period, so they only need a bump when the
+/// dispute is ruled late. The entries are extended for the same time from
+/// now, and the dispute record along with every page.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of the dispute to extend
+/// * `offset` - The first seat to extend
+/// * `limit` - The number of seats, at most `DISPUTE_BUMP_PAGE_SIZE`
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the dispute was extended
+pub fn bump_dispute(env: &Env, dispute_id: u32, offset: u32, limit: u32) -> Result<(), Error> {
+    extend_instance(env);
+
+    let dispute = get_dispute_record(env, dispute_id)?;
+    extend_dispute(env, &dispute, offset, limit);
+
+    Ok(())
+}
+
+/// Extend the lifetime of a ballot and a page of its per-voter entries.
+///
+/// Per-voter entries are written to live until voting ends plus the
+/// retention period. The entries are extended for the same time from now,
+/// and the ballot along with every page.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `ballot_id` - The ID of the ballot to extend
+/// * `offset` - The index of the first voter and vote to extend
+/// * `limit` - The page size, at most `BALLOT_BUMP_PAGE_SIZE`
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the ballot was extended
+pub fn bump_ballot(env: &Env, ballot_id: u32, offset: u32, limit: u32) -> Result<(), Error> {
+    extend_instance(env);
+
+    let ballot = get_ballot(env, ballot_id)?;
+    extend_ballot(env, &ballot, offset, limit);
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::token::{get_token, token_transfer},
+    storage::{
+        config::get_protocol_config,
+        error::Error,
+        storage::extend_instance,
+        vault::{StakeBalance, StakeVault, get_stake_vault, set_stake_vault},
+        voter::get_voter,
+    },
+};
+
+/// Unsettled disputes a juror can sit on at once
+pub(crate) const MAX_ACTIVE_DISPUTES: u32 = 50;
+/// Basis points of a whole stake
+pub(crate) const MAX_SLASH_BPS: u32 = 10_000;
+
+/// Split a vault into its locked, free and unbonding stake.
+pub(crate) fn stake_balance(vault: &StakeVault) -> StakeBalance {
+    let locked = vault.active_disputes.values().iter().sum();
+
+    StakeBalance {
+        locked,
+        free: vault.staked - locked,
+        unbonding: vault.unbonding,
+        unbonding_ends_at: vault.unbonding_ends_at,
+    }
+}
+
+/// Lock part of the stake of a juror seated on a dispute until they are
+/// settled after its ruling.
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the juror was seated, `InsufficientStake` if
+///   the free stake does not cover the amount, or `VoteLimitExceeded` if the
+///   juror sits on `MAX_ACTIVE_DISPUTES` unsettled disputes
+pub(crate) fn lock_stake(
+    env: &Env,
+    juror: &Address,
+    dispute_id: u32,
+    amount: i128,
+) -> Result<(), Error> {
+    let mut vault = get_stake_vault(env, juror);
+
+    if vault.active_disputes.len() >= MAX_ACTIVE_DISPUTES {
+        return Err(Error::VoteLimitExceeded);
+    }
+    if stake_balance(&vault).free < amount {
+        return Err(Error::InsufficientStake);
+    }
+    vault.active_disputes.set(dispute_id, amount);
+    set_stake_vault(env, juror, &vault);
+
+    Ok(())
+}
+
+/// Largest weight a juror can vote with on a dispute: the stake they locked
+/// when seated, or a single vote when the dispute locks no stake.
+///
+/// The weight drives the share of the fees and slashed stakes a coherent
+/// juror is paid, so it never exceeds what the juror puts at risk.
+pub(crate) fn max_vote_weight(env: &Env, juror: &Address, dispute_id: u32) -> u32 {
+    let locked = get_stake_vault(env, juror)
+        .active_disputes
+        .get(dispute_id)
+        .unwrap_or(0);
+
+    locked.clamp(1, u32::MAX as i128) as u32
+}
+
+/// Release the stake a juror locked on a dispute, slashing `slash_bps` of it
+/// first, up to the whole lock.
+///
+/// # Returns
+/// * `i128` - The slashed stake, for the caller to redistribute
+pub(crate) fn release_stake(env: &Env, dispute_id: u32, juror: &Address, slash_bps: i128) -> i128 {
+    let mut vault = get_stake_vault(env, juror);
+    let Some(locked) = vault.active_disputes.get(dispute_id) else {
+        return 0;
+    };
+
+    let amount = (locked * slash_bps / MAX_SLASH_BPS as i128).min(locked);
+    vault.active_disputes.remove(dispute_id);
+    vault.staked -= amount;
+    set_stake_vault(env, juror, &vault);
+
+    if amount > 0 {
+        events::JurorSlashed {
+            dispute_id,
+            juror: juror.clone(),
+            amount,
+        }
+        .publish(env);
+    }
+    amount
+}
+
+/// Deposit protocol tokens in the juror's stake vault.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - A registered voter
+/// * `amount` - The amount of protocol token to stake
+///
+/// # Returns
+/// * `Result<StakeBalance, Error>` - The juror's stake
+pub fn stake(env: &Env, juror: Address, amount: i128) -> Result<StakeBalance, Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    get_voter(env, juror.clone())?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    token_transfer(
+        env,
+        &get_token(env)?,
+        &juror,
+        &env.current_contract_address(),
+        &amount,
+    )?;
+    let mut vault = get_stake_vault(env, &juror);
+    vault.staked += amount;
+    set_stake_vault(env, &juror, &vault);
+
+    events::Staked {
+        juror,
+        amount,
+        staked: vault.staked,
+    }
+    .publish(env);
+
+    Ok(stake_balance(&vault))
+}
+
+/// Start unbonding part of the juror's free stake.
+///
+/// The unbonding period restarts for the whole unbonding stake.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - The staked juror
+/// * `amount` - The amount to unbond
+///
+/// # Returns
+/// * `Result<StakeBalance, Error>` - The juror's stake, or `InsufficientStake`
+///   if the amount exceeds the free stake
+pub fn request_unstake(env: &Env, juror: Address, amount: i128) -> Result<StakeBalance, Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let mut vault = get_stake_vault(env, &juror);
+    if amount > stake_balance(&vault).free {
+        return Err(Error::InsufficientStake);
+    }
+
+    vault.staked -= amount;
+    vault.unbonding += amount;
+    vault.unbonding_ends_at = env.ledger().timestamp() + get_protocol_config(env).unbonding_period;
+    set_stake_vault(env, &juror, &vault);
+
+    events::UnstakeRequested {
+        juror,
+        amount,
+        unbonding_ends_at: vault.unbonding_ends_at,
+    }
+    .publish(env);
+
+    Ok(stake_balance(&vault))
+}
+
+/// Withdraw the juror's stake once its unbonding period is over.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - The unbonding juror
+///
+/// # Returns
+/// * `Result<i128, Error>` - The amount withdrawn, or `InsufficientTime` if
+///   the stake is still unbonding
+pub fn withdraw_stake(env: &Env, juror: Address) -> Result<i128, Error> {
+    juror.require_auth();
+    extend_instance(env);
+
+    let mut vault = get_stake_vault(env, &juror);
+    let amount = vault.unbonding;
+    if amount == 0 {
+        return Err(Error::BalanceIsZero);
+    }
+    if env.ledger().timestamp() < vault.unbonding_ends_at {
+        return Err(Error::InsufficientTime);
+    }
+
+    vault.unbonding = 0;
+    set_stake_vault(env, &juror, &vault);
+    token_transfer(
+        env,
+        &get_token(env)?,
+        &env.current_contract_address(),
+        &juror,
+        &amount,
+    )?;
+
+    events::StakeWithdrawn { juror, amount }.publish(env);
+
+    Ok(amount)
+}
+
+/// Locked, free and unbonding stake of a juror.
+pub fn get_stake(env: &Env, juror: Address) -> StakeBalance {
+    stake_balance(&get_stake_vault(env, &juror))
+}
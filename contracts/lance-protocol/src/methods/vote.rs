@@ -1,13 +1,28 @@
 use crate::{
     events,
     methods::{
-        balance::{get_balance, set_balance},
+        eligibility::check_juror_eligibility,
+        emission::{credit_coherent_weight, record_ruling_weight},
+        fee::settle_fees,
+        phase::require_phase,
+        reputation::record_vote,
+        tally::{count_anonymous_vote, count_public_vote, verify_tallies},
+        vault::{lock_stake, max_vote_weight},
     },
     storage::{
-        dispute::{Dispute, get_dispute, set_dispute},
-        dispute_status::DisputeStatus,
+        config::get_protocol_config,
+        dispute::{
+            DisputeRecord, add_commit, add_dispute_vote, add_juror, enter_phase,
+            get_anonymous_justification, get_commit, get_committer_at, get_dispute_record,
+            get_dispute_vote, get_reveal, has_dispute_vote, has_justification_commit, is_juror,
+            set_anonymous_justification, set_dispute_record, set_justification,
+            set_justification_commit, set_reveal,
+        },
+        dispute_status::DisputePhase,
+        eligibility::is_suspended,
         error::Error,
-        vote::{Vote, VoteAnon, get_anonymous_voting_config},
+        storage::extend_instance,
+        vote::{VoteAnon, VotingMode, get_anonymous_voting_config},
         voter::get_voter,
     },
 };
@@ -19,32 +34,64 @@ use soroban_sdk::{
 // const VOTE_BASE_POWER: u32 = 1;
 // const MIN_VOTES_TO_FINISH_DISPUTE: u32 = 5;
 
-const MAX_VOTES_PER_PROPOSAL: u32 = 1000; // DoS protection
+pub(crate) const MAX_VOTES_PER_PROPOSAL: u32 = 1000; // DoS protection
+/// Jurors a dispute can seat, the largest jury the budget tests run every
+/// dispute entrypoint with
+pub(crate) const MAX_JURORS: u32 = 100;
+/// Commits revealed in a single `reveal_votes` call, to stay within the
+/// transaction budget
+pub(crate) const MAX_REVEALS_PER_CALL: u32 = 20;
+
+/// Move a dispute to the voting phase on its first vote or commit.
+fn start_voting(env: &Env, dispute: &mut DisputeRecord) {
+    if dispute.phase == DisputePhase::Registration {
+        enter_phase(dispute, DisputePhase::Voting, env.ledger().timestamp());
+    }
+}
 
-pub fn register_to_vote(env: &Env, voter: Address, dispute_id: u32) -> Result<Dispute, Error> {
+pub fn register_to_vote(
+    env: &Env,
+    voter: Address,
+    dispute_id: u32,
+) -> Result<DisputeRecord, Error> {
     voter.require_auth();
 
     let _new_judge_caller = get_voter(env, voter.clone())?;
 
-    let mut dispute = get_dispute(env, dispute_id)?;
+    let mut dispute = get_dispute_record(env, dispute_id)?;
 
-    if dispute.dispute_status != DisputeStatus::OPEN {
-        return Err(Error::InvalidDisputeStatus);
-    }
+    // Jurors only join once both parties paid the arbitration fee, and can
+    // still join while the others vote
+    require_phase(
+        env,
+        &mut dispute,
+        &[DisputePhase::Registration, DisputePhase::Voting],
+    )?;
 
-    if voter == dispute.creator || voter == dispute.counterpart {
-        return Err(Error::NotAuthorized);
+    // Parties, conflicted, suspended or low reputation jurors are rejected
+    check_juror_eligibility(env, &dispute, &voter)?;
+
+    // Court disputes only seat the jurors drawn from the court's pool
+    if dispute.court_id.is_some() {
+        return Err(Error::NotEligible);
     }
 
     // Check if voter is not already registered
-    for able_voter in dispute.able_to_vote.iter() {
-        if able_voter == voter {
-            return Err(Error::JudgeAlreadyVoted);
-        }
+    if is_juror(env, dispute_id, &voter) {
+        return Err(Error::JudgeAlreadyVoted);
+    }
+    if dispute.juror_count >= dispute.max_jurors {
+        return Err(Error::VoteLimitExceeded);
     }
 
-    dispute.able_to_vote.push_back(voter);
-    set_dispute(env, dispute_id, dispute.clone());
+    lock_stake(
+        env,
+        &voter,
+        dispute_id,
+        get_protocol_config(env).juror_stake,
+    )?;
+    add_juror(env, &mut dispute, &voter);
+    set_dispute_record(env, &dispute);
 
     Ok(dispute)
 }
@@ -54,94 +101,151 @@ pub fn commit_vote(
     voter: Address,
     dispute_id: u32,
     commit_hash: BytesN<32>,
-) -> Result<Dispute, Error> {
+) -> Result<DisputeRecord, Error> {
     voter.require_auth();
 
-    let mut dispute = get_dispute(env, dispute_id)?;
+    let mut dispute = get_dispute_record(env, dispute_id)?;
 
-    // Check if dispute is resolved
-    if dispute.dispute_status == DisputeStatus::FINISHED {
-        return Err(Error::DisputeAlreadyResolved);
-    }
+    require_phase(
+        env,
+        &mut dispute,
+        &[DisputePhase::Registration, DisputePhase::Voting],
+    )?;
 
-    // Check if dispute is open
-    if dispute.dispute_status != DisputeStatus::OPEN {
-        return Err(Error::DisputeNotOpen);
+    // Only commit-reveal disputes accept commits
+    if dispute.voting_mode != VotingMode::CommitReveal {
+        return Err(Error::WrongVoteType);
     }
 
     // Check if judge is allowed to vote
-    let mut allowed = false;
-    for able_voter in dispute.able_to_vote.iter() {
-        if able_voter == voter {
-            allowed = true;
-            break;
-        }
-    }
-    if !allowed {
+    if !is_juror(env, dispute_id, &voter) {
         return Err(Error::JudgeNotAllowedToVote);
     }
 
+    // Jurors suspended after registering cannot commit
+    if is_suspended(env, &voter) {
+        return Err(Error::VoterSuspended);
+    }
+
     // Check if judge has not already committed
-    for committed_voter in dispute.voters.iter() {
-        if committed_voter == voter {
-            return Err(Error::JudgeAlreadyVoted);
-        }
+    if get_commit(env, dispute_id, &voter).is_some() {
+        return Err(Error::JudgeAlreadyVoted);
     }
 
     // Store the commit hash provided by the judge
     // Judge computes this off-chain as: SHA256(vote_string || secret)
-    dispute.voters.push_back(voter);
-    dispute.vote_commits.push_back(commit_hash);
+    add_commit(env, &mut dispute, &voter, &commit_hash);
+    record_vote(env, &voter);
+    start_voting(env, &mut dispute);
 
-    set_dispute(env, dispute_id, dispute.clone());
+    set_dispute_record(env, &dispute);
 
     Ok(dispute)
 }
 
+/// Reveal the next committed votes of a commit-reveal dispute, in commit order.
+///
+/// The creator reveals the votes a page at a time, each vote with the secret
+/// of its commit. The page which reveals the last commit rules the dispute;
+/// the jurors are settled afterwards by `settle_jurors`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `creator` - The dispute creator
+/// * `dispute_id` - The ID of the dispute
+/// * `votes` - The next votes, at most `MAX_REVEALS_PER_CALL`, `true` for the creator
+/// * `secrets` - The secret of each vote's commit
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The dispute with the revealed votes, or
+///   `InvalidReveal` if a vote does not match its commit or the page goes
+///   past the last commit
 pub fn reveal_votes(
     env: &Env,
     creator: Address,
     dispute_id: u32,
-    votes: soroban_sdk::Vec<bool>,
-    secrets: soroban_sdk::Vec<Bytes>,
-) -> Result<Dispute, Error> {
+    votes: Vec<bool>,
+    secrets: Vec<Bytes>,
+) -> Result<DisputeRecord, Error> {
+    reveal_justified_votes(env, creator, dispute_id, votes, secrets, Vec::new(env))
+}
+
+/// Reveal the next committed votes of a commit-reveal dispute with the
+/// justifications their jurors attached.
+///
+/// A juror attaches a justification by committing to
+/// `SHA256(vote_string || secret || justification)`, so it is fixed before
+/// the ruling and revealed with the vote. Otherwise works like
+/// [`reveal_votes`].
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `creator` - The dispute creator
+/// * `dispute_id` - The ID of the dispute
+/// * `votes` - The next votes, at most `MAX_REVEALS_PER_CALL`, `true` for the creator
+/// * `secrets` - The secret of each vote's commit
+/// * `justifications` - The justification hash of each vote, if any, or empty
+///   if no vote of the page is justified
+///
+/// # Returns
+/// * `Result<DisputeRecord, Error>` - The dispute with the revealed votes, or
+///   `InvalidReveal` if a vote does not match its commit or the page goes
+///   past the last commit
+pub fn reveal_justified_votes(
+    env: &Env,
+    creator: Address,
+    dispute_id: u32,
+    votes: Vec<bool>,
+    secrets: Vec<Bytes>,
+    justifications: Vec<Option<BytesN<32>>>,
+) -> Result<DisputeRecord, Error> {
     creator.require_auth();
 
-    let mut dispute = get_dispute(env, dispute_id)?;
+    let mut dispute = get_dispute_record(env, dispute_id)?;
 
     // Only dispute creator can reveal votes
     if creator != dispute.creator {
         return Err(Error::NotAuthorized);
     }
 
-    // Check if dispute is resolved
-    if dispute.dispute_status == DisputeStatus::FINISHED {
-        return Err(Error::DisputeAlreadyResolved);
-    }
+    // Votes are revealed once the voting period is over
+    require_phase(env, &mut dispute, &[DisputePhase::Tally])?;
 
-    // Check if dispute is open
-    if dispute.dispute_status != DisputeStatus::OPEN {
-        return Err(Error::DisputeNotOpen);
+    if dispute.voting_mode != VotingMode::CommitReveal {
+        return Err(Error::WrongVoteType);
     }
 
-    let commit_count = dispute.voters.len();
+    // Unrevealed votes leave the dispute to be closed without a ruling
+    if env.ledger().timestamp() >= dispute.reveal_ends_at {
+        return Err(Error::ProposalVotingTime);
+    }
 
     // Validate inputs
-    if votes.len() != commit_count || secrets.len() != commit_count {
+    let count = votes.len();
+    if count > MAX_REVEALS_PER_CALL {
+        return Err(Error::BatchTooLarge);
+    }
+    if secrets.len() != count
+        || !(justifications.is_empty() || justifications.len() == count)
+        || dispute.revealed_count + count > dispute.commit_count
+    {
         return Err(Error::InvalidReveal);
     }
 
-    // Verify all commit hashes and collect valid votes
-    for i in 0..commit_count {
-        let vote = votes.get(i).unwrap();
-        let secret = secrets.get(i).unwrap();
-        let stored_commit = dispute.vote_commits.get(i).unwrap();
+    // Verify the commit hashes of the page
+    for (i, (vote, secret)) in votes.iter().zip(secrets.iter()).enumerate() {
+        let voter_addr = get_committer_at(env, dispute_id, dispute.revealed_count + i as u32);
+        let stored_commit = get_commit(env, dispute_id, &voter_addr).unwrap();
 
-        // Compute hash(vote_string || secret)
+        // Compute hash(vote_string || secret [|| justification])
         let vote_str = if vote { "true" } else { "false" };
         let mut data = Bytes::new(env);
         data.append(&Bytes::from_slice(env, vote_str.as_bytes()));
         data.append(&secret);
+        let justification = justifications.get(i as u32).flatten();
+        if let Some(justification) = &justification {
+            data.append(&justification.clone().into());
+        }
 
         let computed_hash: BytesN<32> = env.crypto().sha256(&data).into();
 
@@ -151,11 +255,16 @@ pub fn reveal_votes(
         }
 
         // Store the vote
-        let voter_addr = dispute.voters.get(i).unwrap();
-        dispute.votes.push_back(Vote {
-            account: voter_addr,
-            vote,
-        });
+        set_reveal(env, &dispute, &voter_addr, vote);
+        if let Some(justification) = justification {
+            set_justification(env, &dispute, &voter_addr, &justification);
+            events::VoteJustified {
+                dispute_id,
+                juror: voter_addr,
+                justification,
+            }
+            .publish(env);
+        }
 
         // Update vote counts
         if vote {
@@ -164,45 +273,33 @@ pub fn reveal_votes(
             dispute.votes_against += 1;
         }
     }
+    dispute.revealed_count += count;
 
     // All votes revealed - resolve the dispute
-    dispute.dispute_status = DisputeStatus::FINISHED;
-    dispute.finish_timestamp = Some(env.ledger().timestamp());
-
-    // Determine winner and distribute rewards
-    const REWARD_PER_CORRECT_VOTE: i128 = 1_000_000; // 0.1 tokens (7 decimals)
-
-    if dispute.votes_for > dispute.votes_against {
-        dispute.winner = Some(dispute.creator.clone());
-
-        // Reward voters who voted for the winner
-        for i in 0..dispute.voters.len() {
-            let vote_val = dispute.votes.get(i).unwrap().vote;
-            let voter_addr = dispute.voters.get(i).unwrap();
-
-            if vote_val {
-                // Voted for winner - add reward
-                let balance = get_balance(env, &voter_addr);
-                set_balance(env, &voter_addr, balance + REWARD_PER_CORRECT_VOTE);
-            }
-        }
-    } else {
-        dispute.winner = Some(dispute.counterpart.clone());
-
-        // Reward voters who voted for the winner
-        for i in 0..dispute.voters.len() {
-            let vote_val = dispute.votes.get(i).unwrap().vote;
-            let voter_addr = dispute.voters.get(i).unwrap();
-
-            if !vote_val {
-                // Voted for winner - add reward
-                let balance = get_balance(env, &voter_addr);
-                set_balance(env, &voter_addr, balance + REWARD_PER_CORRECT_VOTE);
-            }
-        }
+    if dispute.revealed_count == dispute.commit_count {
+        let now = env.ledger().timestamp();
+        dispute.finish_timestamp = Some(now);
+        enter_phase(&mut dispute, DisputePhase::Appealable, now);
+
+        // Determine the winner, coherent jurors claim their reward once the
+        // ruling is final
+        let rewarded_weight = if dispute.votes_for > dispute.votes_against {
+            dispute.winner = Some(dispute.creator.clone());
+            dispute.votes_for
+        } else {
+            dispute.winner = Some(dispute.counterpart.clone());
+            dispute.votes_against
+        };
+        settle_fees(
+            env,
+            &mut dispute,
+            rewarded_weight as u128,
+            rewarded_weight as u128,
+        );
+        dispute.emission_epoch = record_ruling_weight(env, dispute.rewarded_weight);
     }
 
-    set_dispute(env, dispute_id, dispute.clone());
+    set_dispute_record(env, &dispute);
 
     Ok(dispute)
 }
@@ -259,7 +356,8 @@ pub fn build_commitments_from_votes(
 /// Cast a vote on a proposal.
 ///
 /// Allows a member to vote on a proposal.
-/// The vote can be either public or anonymous depending on the proposal configuration.
+/// The vote can be either public or anonymous depending on the dispute's voting mode;
+/// commit-reveal disputes use `commit_vote` instead.
 /// For public votes, the choice and weight are visible. For anonymous votes, only
 /// the weight is visible, and the choice is encrypted.
 ///
@@ -275,8 +373,9 @@ pub fn build_commitments_from_votes(
 /// * If the voting period has ended
 /// * If the proposal is not active anymore
 /// * If the proposal doesn't exist
-/// * If the voter's weight exceeds their maximum allowed weight
-/// * If the voter is not a member of the project
+/// * If the voter's weight exceeds the stake they locked on the dispute
+/// * If the voter is not seated on the dispute
+/// * If the vote type does not match the dispute's voting mode
 pub fn vote(
     env: Env,
     voter: Address,
@@ -285,6 +384,7 @@ pub fn vote(
 ) {
     //Tansu::require_not_paused(env.clone());
     voter.require_auth();
+    extend_instance(&env);
 
     //let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
     //let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
@@ -294,71 +394,60 @@ pub fn vote(
     //     _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
     // };
 
-    let mut dispute = match get_dispute(&env, dispute_id) {
+    let mut dispute = match get_dispute_record(&env, dispute_id) {
         Ok(dispute) => dispute,
         Err(_) => panic_with_error!(&env, &Error::DisputeNotFound),
     };
 
-    // Check that voting period has not ended
-    let curr_timestamp = env.ledger().timestamp();
-    if curr_timestamp >= dispute.vote_data.voting_ends_at {
-        panic_with_error!(&env, &Error::ProposalVotingTime);
+    // Votes only count once both parties paid the arbitration fee, and
+    // until the voting period ends
+    if let Err(error) = require_phase(
+        &env,
+        &mut dispute,
+        &[DisputePhase::Registration, DisputePhase::Voting],
+    ) {
+        panic_with_error!(&env, &error);
     }
 
     // Check vote limits for DoS protection
-    if dispute.vote_data.votes.len() >= MAX_VOTES_PER_PROPOSAL {
+    if dispute.vote_count >= MAX_JURORS {
         panic_with_error!(&env, &Error::VoteLimitExceeded);
     }
 
-    // only allow to vote once per voter
-    let has_already_voted = dispute.vote_data.votes.iter().any(|vote_| match vote_ {
-        VoteAnon::AnonymousVote(vote_choice) => vote_choice.address == voter,
-    });
-
-    if has_already_voted {
-        panic_with_error!(&env, &Error::AlreadyVoted);
+    if let Err(error) = check_juror_eligibility(&env, &dispute, &voter) {
+        panic_with_error!(&env, &error);
     }
 
-    // // proposals are either public or anonymous so only a single type of vote
-    // // can be registered for a given proposal
-    // let is_public_vote = matches!(vote, types::Vote::PublicVote(_));
-    // if is_public_vote != proposal.vote_data.public_voting {
-    //     panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
-    // }
+    // Voters must be seated with their stake locked, which weighs their vote;
+    // court disputes only take their drawn jurors
+    if !is_juror(&env, dispute_id, &voter) {
+        panic_with_error!(&env, &Error::JudgeNotAllowedToVote);
+    }
 
-    // For anonymous votes, validate commitment structure
-    let VoteAnon::AnonymousVote(vote_choice) = &vote;
-    if vote_choice.commitments.len() != 3 {
-        panic_with_error!(&env, &Error::BadCommitment)
+    // only allow to vote once per voter
+    if has_dispute_vote(&env, dispute_id, &voter) {
+        panic_with_error!(&env, &Error::AlreadyVoted);
     }
-    for commitment in &vote_choice.commitments {
-        G1Affine::from_bytes(commitment);
+
+    // disputes are either public or anonymous so only a single type of vote
+    // can be registered for a given dispute
+    let is_expected_type = matches!(
+        (&dispute.voting_mode, &vote),
+        (VotingMode::Public, VoteAnon::PublicVote(_))
+            | (VotingMode::Anonymous, VoteAnon::AnonymousVote(_))
+    );
+    if !is_expected_type {
+        panic_with_error!(&env, &Error::WrongVoteType);
     }
 
     // can only vote for yourself so address must match
-    let VoteAnon::AnonymousVote(vote_choice) = &vote;
-    if vote_choice.address != voter {
+    if *vote.address() != voter {
         panic_with_error!(&env, &Error::WrongVoter);
     }
 
-    // Voter can use up to their max allowed voting weight
-    let vote_weight = match &vote {
-        VoteAnon::AnonymousVote(vote_choice) => &vote_choice.weight,
-    };
-
-    // TODO: Restore max weight
-    let voter_max_weight = 100;
-    /*let voter_max_weight = <Tansu as MembershipTrait>::get_max_weight(
-        env.clone(),
-        project_key.clone(),
-        vote_address.clone(),
-    );*/
-
-    if voter_max_weight == 0 {
-        panic_with_error!(&env, &Error::UnknownMember);
-    }
-
-    if vote_weight > &voter_max_weight {
+    // Voter can use up to the weight of the stake they locked
+    let vote_weight = vote.weight();
+    if vote_weight > max_vote_weight(&env, &voter, dispute_id) {
         panic_with_error!(&env, &Error::VoterWeight);
     }
 
@@ -368,10 +457,42 @@ pub fn vote(
         Ok(..) => (),
         _ => panic_with_error!(&env, &errors::ContractErrors::CollateralError),
     }*/
+    let counted = match &vote {
+        // Public votes are tallied directly
+        VoteAnon::PublicVote(public_vote) => count_public_vote(
+            &mut dispute.tally,
+            public_vote.vote_choice as u32,
+            vote_weight,
+        ),
+        // Anonymous votes carry one commitment per option
+        VoteAnon::AnonymousVote(anonymous_vote) => count_anonymous_vote(
+            &env,
+            &mut dispute.tally,
+            &anonymous_vote.commitments,
+            vote_weight,
+        ),
+    };
+    if let Err(error) = counted {
+        panic_with_error!(&env, &error);
+    }
+
+    // Anonymous jurors commit to their justification, opened once the ruling
+    // is final
+    if let VoteAnon::AnonymousVote(anonymous_vote) = &vote
+        && let Some(commit) = &anonymous_vote.justification_commit
+    {
+        if has_justification_commit(&env, dispute_id, commit) {
+            panic_with_error!(&env, &Error::AlreadyVoted);
+        }
+        set_justification_commit(&env, &dispute, commit, None);
+    }
+
     // Record the vote
-    dispute.vote_data.votes.push_back(vote.clone());
+    add_dispute_vote(&env, &mut dispute, &voter, &vote);
+    record_vote(&env, &voter);
+    start_voting(&env, &mut dispute);
 
-    set_dispute(&env, dispute_id, dispute.clone());
+    set_dispute_record(&env, &dispute);
 
     events::VoteCast {
         //project_key,
@@ -380,3 +501,123 @@ pub fn vote(
     }
     .publish(&env);
 }
+
+/// Publish the justification of an anonymous vote, keyed by its nullifier.
+///
+/// The juror committed to `SHA256(nullifier || justification)` with their
+/// vote and opens the commitment once the ruling is final, so the
+/// justification cannot sway the ruling and is not stored under the juror's
+/// address. Anyone holding the nullifier can publish it.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dispute_id` - The ID of an anonymous dispute
+/// * `nullifier` - The secret of the juror's justification commitment
+/// * `justification` - Hash of the justification, stored off-chain
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the justification was published, or
+///   `VoterNotFound` if no vote committed to it
+pub fn justify_anonymous_vote(
+    env: &Env,
+    dispute_id: u32,
+    nullifier: BytesN<32>,
+    justification: BytesN<32>,
+) -> Result<(), Error> {
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    require_phase(env, &mut dispute, &[DisputePhase::Final])?;
+    if dispute.voting_mode != VotingMode::Anonymous {
+        return Err(Error::WrongVoteType);
+    }
+
+    let mut data = Bytes::from(nullifier.clone());
+    data.append(&justification.clone().into());
+    let commit: BytesN<32> = env.crypto().sha256(&data).into();
+    if !has_justification_commit(env, dispute_id, &commit) {
+        return Err(Error::VoterNotFound);
+    }
+    if get_anonymous_justification(env, dispute_id, &nullifier).is_some() {
+        return Err(Error::AlreadyVoted);
+    }
+
+    set_justification_commit(env, &dispute, &commit, Some(nullifier.clone()));
+    set_anonymous_justification(env, &dispute, &nullifier, &justification);
+
+    events::AnonymousVoteJustified {
+        dispute_id,
+        nullifier,
+        justification,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Prove an anonymous vote was cast for the winning option.
+///
+/// The juror opens their commitment for the winning option with its seed,
+/// showing it holds a vote. Their weight then counts towards the emission of
+/// the ruling epoch, as public and revealed votes do when claiming their
+/// reward. The other options stay hidden.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `juror` - The anonymous voter
+/// * `dispute_id` - The ID of an anonymous dispute with a final ruling
+/// * `seed` - The seed of the juror's commitment for the winning option
+///
+/// # Returns
+/// * `Result<(), Error>` - Ok if the vote was proven, `NoWinner` if the
+///   dispute has no winner, `AlreadyVoted` if it was proven before, or
+///   `InvalidProof` if the commitment does not open to a vote for the winner
+pub fn prove_coherent_vote(
+    env: &Env,
+    juror: Address,
+    dispute_id: u32,
+    seed: u128,
+) -> Result<(), Error> {
+    juror.require_auth();
+    extend_instance(env);
+    let mut dispute = get_dispute_record(env, dispute_id)?;
+
+    require_phase(env, &mut dispute, &[DisputePhase::Final])?;
+    if dispute.voting_mode != VotingMode::Anonymous {
+        return Err(Error::WrongVoteType);
+    }
+    let for_creator = match &dispute.winner {
+        Some(winner) => *winner == dispute.creator,
+        None => return Err(Error::NoWinner),
+    };
+    let Some(VoteAnon::AnonymousVote(vote)) = get_dispute_vote(env, dispute_id, &juror) else {
+        return Err(Error::VoterNotFound);
+    };
+    if get_reveal(env, dispute_id, &juror).is_some() {
+        return Err(Error::AlreadyVoted);
+    }
+
+    // the creator's option comes first, the counterpart's second
+    let option = if for_creator { 0 } else { 1 };
+    let commitment = vote.commitments.get(option).ok_or(Error::BadCommitment)?;
+    if !verify_tallies(
+        env,
+        dispute.project_id,
+        &Vec::from_array(env, [commitment]),
+        &Vec::from_array(env, [1]),
+        &Vec::from_array(env, [seed]),
+    ) {
+        return Err(Error::InvalidProof);
+    }
+
+    set_reveal(env, &dispute, &juror, for_creator);
+    credit_coherent_weight(env, &dispute, &juror, vote.weight);
+
+    events::CoherentVoteProven {
+        dispute_id,
+        juror,
+        weight: vote.weight,
+    }
+    .publish(env);
+
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Val, Vec, contracttype};
+
+use crate::storage::{
+    error::Error,
+    storage::{
+        DataKey, FINISHED_DISPUTE_RETENTION, LEDGER_SECONDS, extend_persistent, retain_persistent,
+        retain_persistent_for,
+    },
+    vote::{AnonymousVote, VotingMode},
+};
+
+/// Largest page of the votes of a ballot, each taking two entries of the
+/// footprint
+pub(crate) const BALLOT_PAGE_SIZE: u32 = 40;
+/// Largest page of voters extended by `bump_ballot`, each taking up to four
+/// entries of the footprint
+pub(crate) const BALLOT_BUMP_PAGE_SIZE: u32 = 20;
+
+/// Running tally of a vote over a fixed list of options.
+///
+/// This is the core ballots and disputes share: both count their public and
+/// anonymous votes into it and decide on it with [`crate::methods::tally`].
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tally {
+    pub tallies: Vec<u128>, // Running for public votes, decoded at the end for anonymous ones
+    pub tally_commitments: Vec<BytesN<96>>, // Weighted anonymous commitments, one per option
+    pub total_weight: u128, // Weight of all the votes counted
+}
+
+/// Who is allowed to vote on a ballot.
+///
+/// Eligibility is settled before voting starts: the creator lists more voters
+/// with `add_ballot_voters`, and the moderators draw the jurors in pages with
+/// `draw_ballot_voters`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Eligibility {
+    /// Only the listed addresses can vote, a first page of them
+    Voters(Vec<Address>),
+    /// The given number of jurors are drawn at random from the registered
+    /// voters with the juror stake
+    JurorDraw(u32),
+}
+
+/// Parameters of a new ballot.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BallotConfig {
+    pub title: String,
+    pub options: Vec<String>,
+    pub eligibility: Eligibility,
+    pub voting_mode: VotingMode,
+    pub voting_starts_at: u64,
+    pub voting_ends_at: u64,
+    pub quorum: u128, // Minimum total weight for the result to count
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BallotStatus {
+    Open,
+    /// An option received more than half of all votes
+    Decided,
+    /// No option received more than half of all votes
+    Tied,
+    /// Not enough weight was cast before the deadline
+    NoQuorum,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicBallotVote {
+    pub address: Address,
+    pub weight: u32,
+    pub option: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum BallotVote {
+    PublicVote(PublicBallotVote),
+    AnonymousVote(AnonymousVote),
+}
+
+impl BallotVote {
+    pub fn address(&self) -> &Address {
+        match self {
+            BallotVote::PublicVote(vote) => &vote.address,
+            BallotVote::AnonymousVote(vote) => &vote.address,
+        }
+    }
+
+    pub fn weight(&self) -> u32 {
+        match self {
+            BallotVote::PublicVote(vote) => vote.weight,
+            BallotVote::AnonymousVote(vote) => vote.weight,
+        }
+    }
+}
+
+/// A generic vote any dApp can open, stored under `DataKey::Ballots`.
+///
+/// Eligible voters and cast votes are stored under their own per-voter keys.
+///
+/// Disputes are not stored as ballots. Both count their votes into a
+/// [`Tally`] decided by the same majority rule; the parties, seats, stakes,
+/// fees and appeals of a dispute stay in its own record, and moving disputes
+/// onto ballots is out of scope.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ballot {
+    pub ballot_id: u32,
+    pub project_id: u32, // Holds the anonymous voting configuration
+    pub creator: Address,
+    pub title: String,
+    pub options: Vec<String>,
+    pub voting_mode: VotingMode,
+    pub voting_starts_at: u64,
+    pub voting_ends_at: u64,
+    pub quorum: u128,
+    pub status: BallotStatus,
+    pub winning_option: Option<u32>,
+    pub tally: Tally,
+    pub voter_count: u32, // Eligible voters
+    pub vote_count: u32,
+    pub jurors: u32, // Jurors to draw, 0 if the voters are listed
+}
+
+impl Ballot {
+    /// Whether the ballot can still receive votes
+    pub fn is_active(&self) -> bool {
+        self.status == BallotStatus::Open
+    }
+}
+
+/// Read a ballot, extending its lifetime while it is open.
+pub(crate) fn get_ballot(env: &Env, ballot_id: u32) -> Result<Ballot, Error> {
+    let key = DataKey::Ballots(ballot_id);
+
+    let ballot: Ballot = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::BallotNotFound)?;
+    if ballot.is_active() {
+        extend_persistent(env, &key);
+    }
+    Ok(ballot)
+}
+
+/// Write a ballot.
+///
+/// Finalized ballots follow the same retention policy as finished disputes.
+pub(crate) fn set_ballot(env: &Env, ballot: &Ballot) {
+    let key = DataKey::Ballots(ballot.ballot_id);
+
+    env.storage().persistent().set(&key, ballot);
+    if ballot.is_active() {
+        extend_persistent(env, &key);
+    } else {
+        retain_persistent(env, &key);
+    }
+}
+
+/// Ledgers the entries of a ballot must live for: until voting ends, then
+/// for the retention period, within the largest lifetime of the network.
+fn ballot_entry_ttl(env: &Env, ballot: &Ballot) -> u32 {
+    let remaining = ballot
+        .voting_ends_at
+        .saturating_sub(env.ledger().timestamp())
+        / LEDGER_SECONDS;
+
+    (remaining as u32)
+        .saturating_add(FINISHED_DISPUTE_RETENTION)
+        .min(env.storage().max_ttl())
+}
+
+/// Extend a ballot and a page of its per-voter entries for the rest of the
+/// ballot's lifetime.
+///
+/// The page holds the eligible voter and the vote at each index from
+/// `offset`, at most `BALLOT_BUMP_PAGE_SIZE` of them.
+pub(crate) fn extend_ballot(env: &Env, ballot: &Ballot, offset: u32, limit: u32) {
+    let ballot_id = ballot.ballot_id;
+    let ttl = ballot_entry_ttl(env, ballot);
+    let extend = |key: &DataKey| retain_persistent_for(env, key, ttl);
+    let end = offset.saturating_add(limit.min(BALLOT_BUMP_PAGE_SIZE));
+
+    extend(&DataKey::Ballots(ballot_id));
+    for index in offset..end.min(ballot.voter_count) {
+        let voter = get_eligible_voter_at(env, ballot_id, index);
+        extend(&DataKey::BallotVoterAt(ballot_id, index));
+        extend(&DataKey::BallotVoter(ballot_id, voter));
+    }
+    for index in offset..end.min(ballot.vote_count) {
+        let voter = get_ballot_voter_at(env, ballot_id, index);
+        extend(&DataKey::BallotVoteAt(ballot_id, index));
+        extend(&DataKey::BallotVote(ballot_id, voter));
+    }
+}
+
+/// Store a per-voter entry of a ballot, living for the rest of the ballot's
+/// lifetime.
+fn set_ballot_entry<V: IntoVal<Env, Val>>(env: &Env, ballot: &Ballot, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    retain_persistent_for(env, key, ballot_entry_ttl(env, ballot));
+}
+
+/********** Eligible voters **********/
+
+pub(crate) fn is_eligible(env: &Env, ballot_id: u32, voter: &Address) -> bool {
+    let key = DataKey::BallotVoter(ballot_id, voter.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn get_eligible_voter_at(env: &Env, ballot_id: u32, index: u32) -> Address {
+    let key = DataKey::BallotVoterAt(ballot_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Allow a voter on the ballot and bump the voter counter.
+///
+/// The caller is responsible for persisting the updated ballot.
+pub(crate) fn add_eligible_voter(env: &Env, ballot: &mut Ballot, voter: &Address) {
+    let index = ballot.voter_count;
+
+    set_ballot_entry(
+        env,
+        ballot,
+        &DataKey::BallotVoter(ballot.ballot_id, voter.clone()),
+        &index,
+    );
+    set_ballot_entry(
+        env,
+        ballot,
+        &DataKey::BallotVoterAt(ballot.ballot_id, index),
+        voter,
+    );
+
+    ballot.voter_count += 1;
+}
+
+/********** Votes **********/
+
+pub(crate) fn get_ballot_vote(env: &Env, ballot_id: u32, voter: &Address) -> Option<BallotVote> {
+    let key = DataKey::BallotVote(ballot_id, voter.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+/// Collect a page of at most `BALLOT_PAGE_SIZE` votes cast on a ballot, in
+/// order.
+pub(crate) fn get_ballot_votes(
+    env: &Env,
+    ballot: &Ballot,
+    offset: u32,
+    limit: u32,
+) -> Vec<BallotVote> {
+    let end = ballot
+        .vote_count
+        .min(offset.saturating_add(limit.min(BALLOT_PAGE_SIZE)));
+
+    let mut votes = Vec::new(env);
+    for index in offset..end {
+        let voter = get_ballot_voter_at(env, ballot.ballot_id, index);
+        votes.push_back(get_ballot_vote(env, ballot.ballot_id, &voter).unwrap());
+    }
+    votes
+}
+
+pub(crate) fn has_ballot_vote(env: &Env, ballot_id: u32, voter: &Address) -> bool {
+    let key = DataKey::BallotVote(ballot_id, voter.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn get_ballot_voter_at(env: &Env, ballot_id: u32, index: u32) -> Address {
+    let key = DataKey::BallotVoteAt(ballot_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Store a vote and bump the vote counter.
+///
+/// The caller is responsible for persisting the updated ballot.
+pub(crate) fn add_ballot_vote(env: &Env, ballot: &mut Ballot, voter: &Address, vote: &BallotVote) {
+    let index = ballot.vote_count;
+
+    set_ballot_entry(
+        env,
+        ballot,
+        &DataKey::BallotVote(ballot.ballot_id, voter.clone()),
+        vote,
+    );
+    set_ballot_entry(
+        env,
+        ballot,
+        &DataKey::BallotVoteAt(ballot.ballot_id, index),
+        voter,
+    );
+
+    ballot.vote_count += 1;
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{Env, contracttype};
+
+use crate::storage::storage::DataKey;
+
+const ONE_DAY: u64 = 24 * 3600;
+
+/// Timing of new disputes, configured by the admin.
+///
+/// Integrators pick the voting duration of their disputes within the bounds,
+/// the other durations apply to every dispute. The creation limits keep a
+/// single creator from flooding the juror pool and are off by default.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    /// Shortest voting period a dispute can be created with
+    pub min_voting_duration: u64,
+    /// Longest voting period a dispute can be created with
+    pub max_voting_duration: u64,
+    /// Time the counterpart has to match the arbitration fee, capped by the
+    /// end of the voting period
+    pub evidence_duration: u64,
+    /// Time the creator has to reveal the votes of a commit-reveal dispute
+    /// once the voting period ends
+    pub reveal_duration: u64,
+    /// Disputes a creator can have awaiting a ruling at once, 0 for no limit
+    pub max_open_disputes: u32,
+    /// Time a creator waits before opening another dispute
+    pub dispute_cooldown: u64,
+    /// Bond deposited by the creator, forfeited to the counterpart if the
+    /// ruling goes against the creator
+    pub creation_bond: i128,
+    /// Time unstaked vault stake waits before it can be withdrawn
+    pub unbonding_period: u64,
+    /// Vault stake locked by a seat on a dispute outside courts, which lock
+    /// their minimum stake instead
+    pub juror_stake: i128,
+    /// Reputation lost by a seated juror who never voted or revealed
+    pub missed_vote_penalty: u32,
+    /// Share of the locked stake, in basis points, slashed from a seated
+    /// juror who never voted and shared among the coherent jurors
+    pub missed_vote_slash_bps: u32,
+    /// Share of the locked stake, in basis points per unit of vote
+    /// weight, slashed from a juror who voted for the loser and shared among
+    /// the coherent jurors
+    pub incoherent_slash_bps: u32,
+}
+
+impl Default for ProtocolConfig {
+    fn default() -> Self {
+        ProtocolConfig {
+            min_voting_duration: ONE_DAY,
+            max_voting_duration: 30 * ONE_DAY,
+            evidence_duration: 3 * ONE_DAY,
+            reveal_duration: 2 * ONE_DAY,
+            max_open_disputes: 0,
+            dispute_cooldown: 0,
+            creation_bond: 0,
+            unbonding_period: 7 * ONE_DAY,
+            juror_stake: 0,
+            missed_vote_penalty: 2,
+            missed_vote_slash_bps: 0,
+            incoherent_slash_bps: 0,
+        }
+    }
+}
+
+pub(crate) fn get_protocol_config(env: &Env) -> ProtocolConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProtocolConfig)
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_protocol_config(env: &Env, config: &ProtocolConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProtocolConfig, config);
+}
@@ -0,0 +1,158 @@
+use soroban_sdk::{Address, Env, IntoVal, String, Val, contracttype};
+
+use crate::storage::{
+    error::Error,
+    storage::{DataKey, extend_persistent},
+};
+
+/// A category of disputes with its own juror pool, stored under `DataKey::Courts`.
+///
+/// Jurors who stake in a court also sit in all of its parent courts, so the
+/// pool of a court holds the jurors of its whole subtree. Jurors are backed by
+/// their vault stake, in the protocol token, which must cover the court's
+/// minimum stake, at least the parent's. Fees and rewards are in the court's
+/// token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Court {
+    pub court_id: u32,
+    pub name: String,
+    pub parent: Option<u32>,
+    pub fee: i128,        // Arbitration fee of the court's disputes
+    pub min_stake: i128,  // Minimum stake to join the court
+    pub juror_count: u32, // Jurors in the pool, including those of child courts
+    pub token: Address,   // Token of the court's fees and rewards
+}
+
+/// Position of a juror in a court pool.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourtSeat {
+    pub index: u32,
+    pub memberships: u32, // Courts of the subtree the juror staked in
+}
+
+/// Read a court, extending its lifetime.
+pub(crate) fn get_court(env: &Env, court_id: u32) -> Result<Court, Error> {
+    let key = DataKey::Courts(court_id);
+
+    let court = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::CourtNotFound)?;
+    extend_persistent(env, &key);
+    Ok(court)
+}
+
+pub(crate) fn set_court(env: &Env, court: &Court) {
+    set_court_entry(env, &DataKey::Courts(court.court_id), court);
+}
+
+/// Store a court entry with the regular bump.
+fn set_court_entry<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+/********** Members **********/
+
+/// Whether the juror joined the court itself, not one of its child courts.
+pub(crate) fn is_court_member(env: &Env, court_id: u32, juror: &Address) -> bool {
+    let key = DataKey::CourtMember(court_id, juror.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn set_court_member(env: &Env, court_id: u32, juror: &Address, member: bool) {
+    let key = DataKey::CourtMember(court_id, juror.clone());
+
+    if member {
+        set_court_entry(env, &key, &());
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/********** Pools **********/
+
+pub(crate) fn is_court_juror(env: &Env, court_id: u32, juror: &Address) -> bool {
+    let key = DataKey::CourtJuror(court_id, juror.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn get_court_juror_at(env: &Env, court_id: u32, index: u32) -> Address {
+    let key = DataKey::CourtJurorAt(court_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Seat a juror in a court pool, or count one more membership if already seated.
+///
+/// The caller is responsible for persisting the updated court.
+pub(crate) fn add_court_juror(env: &Env, court: &mut Court, juror: &Address) {
+    let key = DataKey::CourtJuror(court.court_id, juror.clone());
+
+    let seat = match env.storage().persistent().get::<_, CourtSeat>(&key) {
+        Some(seat) => CourtSeat {
+            memberships: seat.memberships + 1,
+            ..seat
+        },
+        None => {
+            let index = court.juror_count;
+            set_court_entry(env, &DataKey::CourtJurorAt(court.court_id, index), juror);
+            court.juror_count += 1;
+            CourtSeat {
+                index,
+                memberships: 1,
+            }
+        }
+    };
+    set_court_entry(env, &key, &seat);
+}
+
+/// Count one membership less, removing the juror from the pool after the last.
+///
+/// The last juror of the pool takes the freed index. The caller is
+/// responsible for persisting the updated court.
+pub(crate) fn remove_court_juror(env: &Env, court: &mut Court, juror: &Address) {
+    let key = DataKey::CourtJuror(court.court_id, juror.clone());
+    let Some(seat) = env.storage().persistent().get::<_, CourtSeat>(&key) else {
+        return;
+    };
+
+    if seat.memberships > 1 {
+        let seat = CourtSeat {
+            memberships: seat.memberships - 1,
+            ..seat
+        };
+        set_court_entry(env, &key, &seat);
+        return;
+    }
+
+    let last_index = court.juror_count - 1;
+    if seat.index != last_index {
+        let last = get_court_juror_at(env, court.court_id, last_index);
+        let last_key = DataKey::CourtJuror(court.court_id, last.clone());
+        let last_seat: CourtSeat = env.storage().persistent().get(&last_key).unwrap();
+        set_court_entry(
+            env,
+            &last_key,
+            &CourtSeat {
+                index: seat.index,
+                ..last_seat
+            },
+        );
+        set_court_entry(
+            env,
+            &DataKey::CourtJurorAt(court.court_id, seat.index),
+            &last,
+        );
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourtJurorAt(court.court_id, last_index));
+    env.storage().persistent().remove(&key);
+    court.juror_count -= 1;
+}
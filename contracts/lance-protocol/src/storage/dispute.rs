@@ -1,7 +1,37 @@
-use super::{dispute_status::DisputeStatus, vote::Vote};
-use crate::storage::{error::Error, storage::DataKey, vote::VoteData};
-use soroban_sdk::{Address, BytesN, Env, String, Vec, contracttype};
+use super::{
+    ballot::Tally,
+    dispute_status::{DisputePhase, DisputeStatus},
+    vote::Vote,
+};
+use crate::storage::{
+    error::Error,
+    storage::{
+        DataKey, FINISHED_DISPUTE_RETENTION, LEDGER_SECONDS, extend_persistent, retain_persistent,
+        retain_persistent_for,
+    },
+    vote::{VoteAnon, VoteChoice, VoteData, VotingMode},
+};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Val, Vec, contracttype};
 
+/// A dispute is voted on over three fixed options [creator, counterpart, abstain].
+///
+/// Public and anonymous votes are counted into the same [`Tally`] as generic
+/// ballots and decided by the same majority rule, see [`crate::methods::tally`].
+pub const DISPUTE_OPTIONS: u32 = 3;
+/// Largest page of the per-juror views of a dispute
+pub(crate) const DISPUTE_PAGE_SIZE: u32 = 50;
+/// Jurors and votes listed by the full view of a dispute, each taking up to
+/// four entries of the footprint
+pub(crate) const DISPUTE_VIEW_SIZE: u32 = 20;
+/// Largest page of seats extended by `bump_dispute`, each taking up to seven
+/// entries of the footprint
+pub(crate) const DISPUTE_BUMP_PAGE_SIZE: u32 = 12;
+
+/// Assembled view of a dispute, as returned to callers.
+///
+/// Only the fields of [`DisputeRecord`] are stored together; jurors, commits,
+/// votes and reveals are stored under their own per-juror keys and the first
+/// `DISPUTE_VIEW_SIZE` of each are collected here by [`get_dispute`].
 #[derive(Clone)]
 #[contracttype]
 pub struct Dispute {
@@ -12,6 +42,8 @@ pub struct Dispute {
     pub vote_commits: Vec<BytesN<32>>, // Commit hashes
     pub votes: Vec<Vote>,              // Revealed votes
     pub dispute_status: DisputeStatus,
+    pub phase: DisputePhase,
+    pub phase_started_at: u64,
     pub initial_timestamp: u64,
     pub finish_timestamp: Option<u64>,
     pub creator: Address,
@@ -24,20 +56,637 @@ pub struct Dispute {
     pub votes_against: u32,
     pub vote_data: VoteData,
     pub called_contract: Address,
-    //TODO add payment: i128, ???
+    pub court_id: Option<u32>,
+    pub max_jurors: u32,
+    pub arbitration_fee: i128,
+    pub fee_deadline: u64,
+    pub counterpart_paid: bool,
+    pub reward_pool: i128,
+    pub slashed_pool: i128,
+    pub reveal_ends_at: u64,
+    pub creation_bond: i128,
+    pub token: Address,
 }
 
-pub(crate) fn get_dispute(env: &Env, dispute_id: u32) -> Result<Dispute, Error> {
+/// Vote of a juror as returned by `get_dispute_votes`.
+#[derive(Clone)]
+#[contracttype]
+pub enum JurorVote {
+    Commit(CommittedVote), // Commit-reveal disputes
+    Cast(VoteAnon),        // Public and anonymous disputes
+}
+
+/// Commit of a juror on a commit-reveal dispute, with the vote once revealed.
+#[derive(Clone)]
+#[contracttype]
+pub struct CommittedVote {
+    pub juror: Address,
+    pub commit_hash: BytesN<32>,
+    pub reveal: Option<bool>,
+}
+
+/// Fixed-size part of a dispute stored under `DataKey::Disputes`.
+#[derive(Clone)]
+#[contracttype]
+pub struct DisputeRecord {
+    pub project_id: u32,
+    pub dispute_id: u32,
+    pub dispute_status: DisputeStatus, // Follows the phase, see `DisputePhase::status`
+    pub phase: DisputePhase,
+    pub phase_started_at: u64,
+    pub initial_timestamp: u64,
+    pub finish_timestamp: Option<u64>,
+    pub creator: Address,
+    pub counterpart: Address,
+    pub winner: Option<Address>,
+    pub creator_proves: String,
+    pub counterpart_proves: Option<String>,
+    pub waiting_for_judges: bool,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub voting_mode: VotingMode,
+    pub voting_ends_at: u64,
+    pub reveal_ends_at: u64, // Commit-reveal votes must be revealed before this
+    pub tally: Tally,        // Public and anonymous votes [creator, counterpart, abstain]
+    pub called_contract: Address,
+    pub juror_count: u32,      // Judges registered to vote
+    pub max_jurors: u32,       // Judges the dispute can seat
+    pub commit_count: u32,     // Judges who have committed
+    pub revealed_count: u32,   // Commits revealed, in commit order
+    pub vote_count: u32,       // Public or anonymous votes cast
+    pub settled_count: u32,    // Jurors settled after the ruling, in seating order
+    pub court_id: Option<u32>, // Jurors are drawn from this court's pool
+    pub arbitration_fee: i128, // Fee deposited by each party
+    pub fee_deadline: u64,     // The counterpart must match the fee before this
+    pub counterpart_paid: bool,
+    pub reward_pool: i128,      // Loser's fee, shared by the coherent jurors
+    pub rewarded_weight: u128,  // Total weight of the coherent jurors
+    pub claimable_weight: u128, // Weight of the jurors who can claim, every anonymous voter
+    pub emission_epoch: Option<u32>, // Epoch the coherent weight counts towards
+    pub slashed_pool: i128,     // Stake slashed from incoherent jurors, in the protocol token
+    pub claimed_weight: u128,   // Weight of the jurors who claimed their reward
+    pub reward_paid: i128,      // Claimed from the reward pool
+    pub slashed_paid: i128,     // Claimed from the slashed pool
+    pub creation_bond: i128,    // Creator's bond, returned unless the ruling goes against them
+    pub token: Address,         // Token of the fees, bond and rewards
+}
+
+/// Disputes opened by a creator, stored under `DataKey::CreatorActivity` to
+/// enforce the creation limits of the protocol config.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct CreatorActivity {
+    pub open_disputes: u32,   // Disputes awaiting a ruling
+    pub last_created_at: u64, // Creation time of the latest dispute
+}
+
+/// Outcome the parties agree on to close a dispute without a ruling, stored
+/// under `DataKey::Settlement` while pending and kept as the agreement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Settlement {
+    pub proposer: Address,
+    pub creator_share_bps: u32, // Share of the disputed payment going to the creator
+}
+
+/// Compact outcome of a ruled dispute, returned by `get_ruling`.
+///
+/// Integrators gate their own logic on it without decoding the whole
+/// [`Dispute`]. Its layout is part of the interface: fields are never
+/// reordered, renamed or removed across upgrades.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Ruling {
+    pub dispute_id: u32,
+    pub round: u32,                 // Voting round the ruling comes from
+    pub winning_option: VoteChoice, // Abstain when there is no winner
+    pub creator_share_bps: u32,     // Share of the disputed payment going to the creator
+    pub finalized_at: u64,          // When the ruling is, or was, no longer appealable
+    pub is_final: bool,
+}
+
+/// Read a dispute record, extending its lifetime while the dispute is active.
+pub(crate) fn get_dispute_record(env: &Env, dispute_id: u32) -> Result<DisputeRecord, Error> {
     let key = DataKey::Disputes(dispute_id);
 
-    env.storage()
-        .instance()
+    let record: DisputeRecord = env
+        .storage()
+        .persistent()
         .get(&key)
-        .ok_or(Error::DisputeNotFound)
+        .ok_or(Error::DisputeNotFound)?;
+    if record.dispute_status.is_active() {
+        extend_persistent(env, &key);
+    }
+    Ok(record)
 }
 
-pub(crate) fn set_dispute(env: &Env, dispute_id: u32, dispute: Dispute) {
-    let key = DataKey::Disputes(dispute_id);
+/// Write a dispute record.
+///
+/// Active disputes are extended by the regular bump, finished disputes are
+/// guaranteed to live for the retention period and are not extended again.
+pub(crate) fn set_dispute_record(env: &Env, record: &DisputeRecord) {
+    let key = DataKey::Disputes(record.dispute_id);
+
+    env.storage().persistent().set(&key, record);
+    if record.dispute_status.is_active() {
+        extend_persistent(env, &key);
+    } else {
+        retain_persistent(env, &key);
+    }
+}
+
+/// Ledgers the entries of a dispute must live for: until its appeal period
+/// ends if it runs its full course, then for the retention period, within the
+/// largest lifetime of the network.
+fn dispute_entry_ttl(env: &Env, record: &DisputeRecord) -> u32 {
+    let ends_at = record.voting_ends_at.max(record.reveal_ends_at) + APPEAL_PERIOD;
+    let remaining = ends_at.saturating_sub(env.ledger().timestamp()) / LEDGER_SECONDS;
+
+    (remaining as u32)
+        .saturating_add(FINISHED_DISPUTE_RETENTION)
+        .min(env.storage().max_ttl())
+}
+
+/// Extend a dispute record and a page of its per-juror entries for the rest
+/// of the dispute's lifetime.
+///
+/// The page holds the seats from `offset`, at most `DISPUTE_BUMP_PAGE_SIZE`
+/// of them: the juror, commit and vote at each of their indexes.
+pub(crate) fn extend_dispute(env: &Env, record: &DisputeRecord, offset: u32, limit: u32) {
+    let dispute_id = record.dispute_id;
+    let ttl = dispute_entry_ttl(env, record);
+    let extend = |key: &DataKey| retain_persistent_for(env, key, ttl);
+    let end = offset.saturating_add(limit.min(DISPUTE_BUMP_PAGE_SIZE));
+
+    extend(&DataKey::Disputes(dispute_id));
+    extend(&DataKey::Settlement(dispute_id));
+    for index in offset..end.min(record.juror_count) {
+        let juror = get_juror_at(env, dispute_id, index);
+        extend(&DataKey::DisputeJurorAt(dispute_id, index));
+        extend(&DataKey::DisputeJuror(dispute_id, juror));
+    }
+    for index in offset..end.min(record.commit_count) {
+        let juror = get_committer_at(env, dispute_id, index);
+        extend(&DataKey::VoteCommitAt(dispute_id, index));
+        extend(&DataKey::VoteCommit(dispute_id, juror.clone()));
+        extend(&DataKey::VoteReveal(dispute_id, juror.clone()));
+        extend(&DataKey::Justification(dispute_id, juror));
+    }
+    for index in offset..end.min(record.vote_count) {
+        let voter = get_dispute_voter_at(env, dispute_id, index);
+        extend(&DataKey::DisputeVoteAt(dispute_id, index));
+        extend(&DataKey::DisputeVote(dispute_id, voter.clone()));
+        extend(&DataKey::RewardClaimed(dispute_id, voter.clone()));
+        if record.voting_mode == VotingMode::Anonymous {
+            extend(&DataKey::VoteReveal(dispute_id, voter.clone()));
+        }
+        if let Some(VoteAnon::AnonymousVote(vote)) = get_dispute_vote(env, dispute_id, &voter)
+            && let Some(commit) = vote.justification_commit
+        {
+            extend(&DataKey::JustificationCommit(dispute_id, commit.clone()));
+            if let Some(nullifier) = get_justification_nullifier(env, dispute_id, &commit) {
+                extend(&DataKey::AnonymousJustification(dispute_id, nullifier));
+            }
+        }
+    }
+}
+
+/// Store a per-juror entry of a dispute, living for the rest of the dispute's
+/// lifetime.
+fn set_dispute_entry<V: IntoVal<Env, Val>>(
+    env: &Env,
+    record: &DisputeRecord,
+    key: &DataKey,
+    value: &V,
+) {
+    env.storage().persistent().set(key, value);
+    retain_persistent_for(env, key, dispute_entry_ttl(env, record));
+}
+
+/// Assemble the full view of a dispute from its record and per-juror entries.
+///
+/// The view shows the dispute in its current phase, even if the end of the
+/// voting or appeal period was not stored yet. It lists the first
+/// `DISPUTE_VIEW_SIZE` jurors, commits and votes; the others are read through
+/// [`get_dispute_jurors`] and [`get_dispute_votes`].
+pub(crate) fn get_dispute(env: &Env, dispute_id: u32) -> Result<Dispute, Error> {
+    let mut record = get_dispute_record(env, dispute_id)?;
+    sync_phase(env, &mut record);
+
+    let mut able_to_vote = Vec::new(env);
+    for index in 0..record.juror_count.min(DISPUTE_VIEW_SIZE) {
+        able_to_vote.push_back(get_juror_at(env, dispute_id, index));
+    }
+
+    let mut voters = Vec::new(env);
+    let mut vote_commits = Vec::new(env);
+    let mut votes = Vec::new(env);
+    for index in 0..record.commit_count.min(DISPUTE_VIEW_SIZE) {
+        let voter = get_committer_at(env, dispute_id, index);
+        vote_commits.push_back(get_commit(env, dispute_id, &voter).unwrap());
+        if let Some(vote) = get_reveal(env, dispute_id, &voter) {
+            votes.push_back(Vote {
+                account: voter.clone(),
+                vote,
+            });
+        }
+        voters.push_back(voter);
+    }
+
+    let mut cast_votes = Vec::new(env);
+    for index in 0..record.vote_count.min(DISPUTE_VIEW_SIZE) {
+        let voter = get_dispute_voter_at(env, dispute_id, index);
+        cast_votes.push_back(get_dispute_vote(env, dispute_id, &voter).unwrap());
+    }
+
+    Ok(Dispute {
+        project_id: record.project_id,
+        dispute_id: record.dispute_id,
+        able_to_vote,
+        voters,
+        vote_commits,
+        votes,
+        dispute_status: record.dispute_status,
+        phase: record.phase,
+        phase_started_at: record.phase_started_at,
+        initial_timestamp: record.initial_timestamp,
+        finish_timestamp: record.finish_timestamp,
+        creator: record.creator,
+        counterpart: record.counterpart,
+        winner: record.winner,
+        creator_proves: record.creator_proves,
+        counterpart_proves: record.counterpart_proves,
+        waiting_for_judges: record.waiting_for_judges,
+        votes_for: record.votes_for,
+        votes_against: record.votes_against,
+        vote_data: VoteData {
+            voting_ends_at: record.voting_ends_at,
+            voting_mode: record.voting_mode,
+            votes: cast_votes,
+            tally_commitments: record.tally.tally_commitments,
+        },
+        called_contract: record.called_contract,
+        court_id: record.court_id,
+        max_jurors: record.max_jurors,
+        arbitration_fee: record.arbitration_fee,
+        fee_deadline: record.fee_deadline,
+        counterpart_paid: record.counterpart_paid,
+        reward_pool: record.reward_pool,
+        slashed_pool: record.slashed_pool,
+        reveal_ends_at: record.reveal_ends_at,
+        creation_bond: record.creation_bond,
+        token: record.token,
+    })
+}
+
+/// Page through the jurors seated on a dispute, in seating order.
+///
+/// # Returns
+/// * `Result<Vec<Address>, Error>` - At most `DISPUTE_PAGE_SIZE` jurors, empty
+///   past the last one
+pub(crate) fn get_dispute_jurors(
+    env: &Env,
+    dispute_id: u32,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<Address>, Error> {
+    let record = get_dispute_record(env, dispute_id)?;
+    let end = page_end(record.juror_count, offset, limit);
+
+    let mut jurors = Vec::new(env);
+    for index in offset..end {
+        jurors.push_back(get_juror_at(env, dispute_id, index));
+    }
+    Ok(jurors)
+}
+
+/// Page through the votes of a dispute, in the order they were committed or
+/// cast.
+///
+/// # Returns
+/// * `Result<Vec<JurorVote>, Error>` - At most `DISPUTE_PAGE_SIZE` votes,
+///   empty past the last one
+pub(crate) fn get_dispute_votes(
+    env: &Env,
+    dispute_id: u32,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<JurorVote>, Error> {
+    let record = get_dispute_record(env, dispute_id)?;
+
+    let mut votes = Vec::new(env);
+    if record.voting_mode == VotingMode::CommitReveal {
+        for index in offset..page_end(record.commit_count, offset, limit) {
+            let juror = get_committer_at(env, dispute_id, index);
+            votes.push_back(JurorVote::Commit(CommittedVote {
+                commit_hash: get_commit(env, dispute_id, &juror).unwrap(),
+                reveal: get_reveal(env, dispute_id, &juror),
+                juror,
+            }));
+        }
+    } else {
+        for index in offset..page_end(record.vote_count, offset, limit) {
+            let voter = get_dispute_voter_at(env, dispute_id, index);
+            votes.push_back(JurorVote::Cast(
+                get_dispute_vote(env, dispute_id, &voter).unwrap(),
+            ));
+        }
+    }
+    Ok(votes)
+}
+
+/// End of a page of at most `DISPUTE_PAGE_SIZE` entries out of `count`.
+fn page_end(count: u32, offset: u32, limit: u32) -> u32 {
+    count.min(offset.saturating_add(limit.min(DISPUTE_PAGE_SIZE)))
+}
+
+/********** Phases **********/
+
+/// Time a ruling stays appealable before it becomes final
+pub const APPEAL_PERIOD: u64 = 2 * 24 * 3600;
+
+/// Time jurors have to claim their reward once the ruling is final, within
+/// the retention of the finished dispute
+pub const REWARD_CLAIM_PERIOD: u64 = 30 * 24 * 3600;
+
+/// Phase of a dispute at the current time, with the time it started.
+///
+/// Actions move a dispute through most phases; the end of the voting period
+/// and of the appeal period are applied here, when the dispute is next read.
+pub(crate) fn current_phase(env: &Env, record: &DisputeRecord) -> (DisputePhase, u64) {
+    let now = env.ledger().timestamp();
+    let appeal_ends_at = record.phase_started_at.saturating_add(APPEAL_PERIOD);
+
+    match record.phase {
+        DisputePhase::Registration | DisputePhase::Voting if now >= record.voting_ends_at => {
+            (DisputePhase::Tally, record.voting_ends_at)
+        }
+        DisputePhase::Appealable if now >= appeal_ends_at => (DisputePhase::Final, appeal_ends_at),
+        phase => (phase, record.phase_started_at),
+    }
+}
+
+/// Move a dispute to a phase, updating its status accordingly.
+///
+/// The caller is responsible for persisting the updated record.
+pub(crate) fn enter_phase(record: &mut DisputeRecord, phase: DisputePhase, at: u64) {
+    record.phase = phase;
+    record.phase_started_at = at;
+    // a settlement stays visible once final
+    if record.dispute_status != DisputeStatus::SETTLED {
+        record.dispute_status = phase.status();
+    }
+}
+
+/// Bring a dispute up to its current phase.
+pub(crate) fn sync_phase(env: &Env, record: &mut DisputeRecord) -> DisputePhase {
+    let (phase, started_at) = current_phase(env, record);
+    if phase != record.phase {
+        enter_phase(record, phase, started_at);
+    }
+    phase
+}
+
+/********** Settlements **********/
+
+pub(crate) fn get_settlement(env: &Env, dispute_id: u32) -> Option<Settlement> {
+    let key = DataKey::Settlement(dispute_id);
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_settlement(env: &Env, record: &DisputeRecord, settlement: &Settlement) {
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::Settlement(record.dispute_id),
+        settlement,
+    );
+}
+
+/********** Jurors **********/
+
+pub(crate) fn is_juror(env: &Env, dispute_id: u32, juror: &Address) -> bool {
+    let key = DataKey::DisputeJuror(dispute_id, juror.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn get_juror_at(env: &Env, dispute_id: u32, index: u32) -> Address {
+    let key = DataKey::DisputeJurorAt(dispute_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Register a juror on the dispute and bump the juror counter.
+///
+/// The caller is responsible for persisting the updated record.
+pub(crate) fn add_juror(env: &Env, record: &mut DisputeRecord, juror: &Address) {
+    let index = record.juror_count;
+
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::DisputeJuror(record.dispute_id, juror.clone()),
+        &index,
+    );
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::DisputeJurorAt(record.dispute_id, index),
+        juror,
+    );
+
+    record.juror_count += 1;
+}
+
+/********** Creators **********/
+
+pub(crate) fn get_creator_activity(env: &Env, creator: &Address) -> Option<CreatorActivity> {
+    let key = DataKey::CreatorActivity(creator.clone());
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_creator_activity(env: &Env, creator: &Address, activity: &CreatorActivity) {
+    let key = DataKey::CreatorActivity(creator.clone());
+
+    env.storage().persistent().set(&key, activity);
+    extend_persistent(env, &key);
+}
+
+/********** Commits **********/
+
+pub(crate) fn get_commit(env: &Env, dispute_id: u32, juror: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::VoteCommit(dispute_id, juror.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn get_committer_at(env: &Env, dispute_id: u32, index: u32) -> Address {
+    let key = DataKey::VoteCommitAt(dispute_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Store a juror's commit hash and bump the commit counter.
+///
+/// The caller is responsible for persisting the updated record.
+pub(crate) fn add_commit(
+    env: &Env,
+    record: &mut DisputeRecord,
+    juror: &Address,
+    commit_hash: &BytesN<32>,
+) {
+    let index = record.commit_count;
+
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::VoteCommit(record.dispute_id, juror.clone()),
+        commit_hash,
+    );
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::VoteCommitAt(record.dispute_id, index),
+        juror,
+    );
+
+    record.commit_count += 1;
+}
+
+/********** Reveals **********/
+
+pub(crate) fn get_reveal(env: &Env, dispute_id: u32, juror: &Address) -> Option<bool> {
+    let key = DataKey::VoteReveal(dispute_id, juror.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_reveal(env: &Env, record: &DisputeRecord, juror: &Address, vote: bool) {
+    let key = DataKey::VoteReveal(record.dispute_id, juror.clone());
+
+    set_dispute_entry(env, record, &key, &vote)
+}
+
+/********** Justifications **********/
+
+pub(crate) fn get_justification(env: &Env, dispute_id: u32, juror: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::Justification(dispute_id, juror.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_justification(
+    env: &Env,
+    record: &DisputeRecord,
+    juror: &Address,
+    justification: &BytesN<32>,
+) {
+    let key = DataKey::Justification(record.dispute_id, juror.clone());
+
+    set_dispute_entry(env, record, &key, justification)
+}
+
+/// Whether an anonymous vote of the dispute committed to this justification.
+pub(crate) fn has_justification_commit(env: &Env, dispute_id: u32, commit: &BytesN<32>) -> bool {
+    let key = DataKey::JustificationCommit(dispute_id, commit.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+/// Nullifier which opened a justification commitment, if it was opened.
+pub(crate) fn get_justification_nullifier(
+    env: &Env,
+    dispute_id: u32,
+    commit: &BytesN<32>,
+) -> Option<BytesN<32>> {
+    let key = DataKey::JustificationCommit(dispute_id, commit.clone());
+
+    env.storage()
+        .persistent()
+        .get::<_, Option<BytesN<32>>>(&key)
+        .flatten()
+}
+
+/// Record a justification commitment, or the nullifier which opened it.
+pub(crate) fn set_justification_commit(
+    env: &Env,
+    record: &DisputeRecord,
+    commit: &BytesN<32>,
+    nullifier: Option<BytesN<32>>,
+) {
+    let key = DataKey::JustificationCommit(record.dispute_id, commit.clone());
+
+    set_dispute_entry(env, record, &key, &nullifier)
+}
+
+pub(crate) fn get_anonymous_justification(
+    env: &Env,
+    dispute_id: u32,
+    nullifier: &BytesN<32>,
+) -> Option<BytesN<32>> {
+    let key = DataKey::AnonymousJustification(dispute_id, nullifier.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_anonymous_justification(
+    env: &Env,
+    record: &DisputeRecord,
+    nullifier: &BytesN<32>,
+    justification: &BytesN<32>,
+) {
+    let key = DataKey::AnonymousJustification(record.dispute_id, nullifier.clone());
+
+    set_dispute_entry(env, record, &key, justification)
+}
+
+/********** Public and anonymous votes **********/
+
+pub(crate) fn get_dispute_vote(env: &Env, dispute_id: u32, voter: &Address) -> Option<VoteAnon> {
+    let key = DataKey::DisputeVote(dispute_id, voter.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn has_dispute_vote(env: &Env, dispute_id: u32, voter: &Address) -> bool {
+    let key = DataKey::DisputeVote(dispute_id, voter.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn get_dispute_voter_at(env: &Env, dispute_id: u32, index: u32) -> Address {
+    let key = DataKey::DisputeVoteAt(dispute_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Store a public or anonymous vote and bump the vote counter.
+///
+/// The caller is responsible for persisting the updated record.
+pub(crate) fn add_dispute_vote(
+    env: &Env,
+    record: &mut DisputeRecord,
+    voter: &Address,
+    vote: &VoteAnon,
+) {
+    let index = record.vote_count;
+
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::DisputeVote(record.dispute_id, voter.clone()),
+        vote,
+    );
+    set_dispute_entry(
+        env,
+        record,
+        &DataKey::DisputeVoteAt(record.dispute_id, index),
+        voter,
+    );
 
-    env.storage().instance().set(&key, &dispute)
+    record.vote_count += 1;
 }
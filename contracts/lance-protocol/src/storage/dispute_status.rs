@@ -1,5 +1,9 @@
 use soroban_sdk::contracttype;
 
+/// Coarse status of a dispute, derived from its [`DisputePhase`].
+///
+/// `CREATOR`, `COUNTERPART` and `ABSTAIN` are the outcomes returned by
+/// `execute`; the ruling itself is recorded as the dispute's winner.
 #[derive(Clone, PartialEq, Debug)]
 #[contracttype]
 pub enum DisputeStatus {
@@ -10,4 +14,38 @@ pub enum DisputeStatus {
     ABSTAIN,
     EXECUTED,
     FINISHED,
+    SETTLED,
+}
+
+impl DisputeStatus {
+    /// Whether the dispute can still receive jurors, commits or votes
+    pub fn is_active(&self) -> bool {
+        matches!(self, DisputeStatus::OPEN | DisputeStatus::VOTING)
+    }
+}
+
+/// Lifecycle of a dispute, in order.
+///
+/// See [`crate::methods::phase`] for the transitions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[contracttype]
+pub enum DisputePhase {
+    Evidence,     // Waiting for the counterpart to match the arbitration fee
+    Registration, // Jurors register or are drawn
+    Voting,       // Votes or commits are being cast
+    Tally,        // Voting ended, waiting for `execute` or `reveal_votes`
+    Appealable,   // Ruled, the ruling can still be appealed
+    Final,        // Ruled, settled or decided by default
+}
+
+impl DisputePhase {
+    /// Status shown for a dispute in this phase
+    pub fn status(&self) -> DisputeStatus {
+        match self {
+            DisputePhase::Evidence | DisputePhase::Registration => DisputeStatus::OPEN,
+            DisputePhase::Voting | DisputePhase::Tally => DisputeStatus::VOTING,
+            DisputePhase::Appealable => DisputeStatus::EXECUTED,
+            DisputePhase::Final => DisputeStatus::FINISHED,
+        }
+    }
 }
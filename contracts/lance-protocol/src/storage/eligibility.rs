@@ -0,0 +1,104 @@
+use soroban_sdk::{Address, Env, contracttype};
+
+use crate::storage::storage::{DataKey, extend_persistent};
+
+/// Rules a juror must pass to sit on a dispute, configured by the admin.
+///
+/// The parties themselves can never be jurors of their own dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EligibilityRules {
+    /// Reject jurors who were a party of a dispute against either party
+    pub exclude_past_counterparties: bool,
+    /// Reject jurors who delegate their vote to either party
+    pub exclude_delegators: bool,
+    /// Minimum reputation of a juror
+    pub min_reputation: u32,
+}
+
+impl Default for EligibilityRules {
+    fn default() -> Self {
+        EligibilityRules {
+            exclude_past_counterparties: true,
+            exclude_delegators: true,
+            min_reputation: 0,
+        }
+    }
+}
+
+pub(crate) fn get_eligibility_rules(env: &Env) -> EligibilityRules {
+    env.storage()
+        .instance()
+        .get(&DataKey::EligibilityRules)
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_eligibility_rules(env: &Env, rules: &EligibilityRules) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EligibilityRules, rules);
+}
+
+/********** Counterparties **********/
+
+fn counterparties_key(a: &Address, b: &Address) -> DataKey {
+    // the same entry whichever side opened the dispute
+    if a < b {
+        DataKey::Counterparties(a.clone(), b.clone())
+    } else {
+        DataKey::Counterparties(b.clone(), a.clone())
+    }
+}
+
+pub(crate) fn were_counterparties(env: &Env, a: &Address, b: &Address) -> bool {
+    let key = counterparties_key(a, b);
+
+    extend_persistent(env, &key);
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn set_counterparties(env: &Env, a: &Address, b: &Address) {
+    let key = counterparties_key(a, b);
+
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+}
+
+/********** Suspensions **********/
+
+/// A voter kept off disputes, stored under `DataKey::Suspension`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Suspension {
+    pub suspended_at: u64,
+    pub until: Option<u64>, // None for a permanent ban
+}
+
+pub(crate) fn get_suspension(env: &Env, voter: &Address) -> Option<Suspension> {
+    let key = DataKey::Suspension(voter.clone());
+
+    env.storage().persistent().get(&key)
+}
+
+/// Whether the voter is banned or suspended at the current ledger time.
+pub(crate) fn is_suspended(env: &Env, voter: &Address) -> bool {
+    match get_suspension(env, voter) {
+        Some(suspension) => suspension
+            .until
+            .is_none_or(|until| env.ledger().timestamp() < until),
+        None => false,
+    }
+}
+
+pub(crate) fn set_suspension(env: &Env, voter: &Address, suspension: &Suspension) {
+    let key = DataKey::Suspension(voter.clone());
+
+    env.storage().persistent().set(&key, suspension);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_suspension(env: &Env, voter: &Address) {
+    let key = DataKey::Suspension(voter.clone());
+
+    env.storage().persistent().remove(&key);
+}
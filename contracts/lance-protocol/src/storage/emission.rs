@@ -0,0 +1,87 @@
+use soroban_sdk::{Address, Env, contracttype};
+
+use crate::storage::storage::{DataKey, extend_persistent};
+
+/// Emission of protocol tokens to coherent jurors, stored under
+/// `DataKey::EmissionSchedule`.
+///
+/// Epochs are numbered from the first schedule. A new schedule closes the
+/// current epoch and starts the next one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionSchedule {
+    pub rate: i128,          // Tokens emitted per epoch, as long as the reservoir lasts
+    pub epoch_duration: u64, // Length of an epoch in seconds
+    pub started_at: u64,     // Start of `first_epoch`
+    pub first_epoch: u32,    // Epoch the schedule started in
+}
+
+/// Reward reservoir accounting, stored under `DataKey::Emissions`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Emissions {
+    pub reservoir: i128,   // Funded and not yet assigned to an epoch
+    pub allocated: i128,   // Assigned to closed epochs and not yet claimed
+    pub distributed: i128, // Credited to jurors
+}
+
+/// Participation in an epoch, stored under `DataKey::EmissionEpoch`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmissionEpoch {
+    pub rate: i128,             // Rate of the schedule when the epoch started
+    pub total_weight: u128,     // Coherent weight of the disputes ruled in the epoch
+    pub emission: Option<i128>, // Taken from the reservoir once the epoch closed
+    pub distributed: i128,      // Claimed by jurors
+    pub last_ruled_at: u64,     // Time of the last ruling, starting the claim period
+}
+
+pub(crate) fn get_emission_schedule(env: &Env) -> Option<EmissionSchedule> {
+    env.storage().instance().get(&DataKey::EmissionSchedule)
+}
+
+pub(crate) fn set_emission_schedule(env: &Env, schedule: &EmissionSchedule) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EmissionSchedule, schedule);
+}
+
+pub(crate) fn get_emissions(env: &Env) -> Emissions {
+    env.storage()
+        .instance()
+        .get(&DataKey::Emissions)
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_emissions(env: &Env, emissions: &Emissions) {
+    env.storage().instance().set(&DataKey::Emissions, emissions);
+}
+
+pub(crate) fn get_emission_epoch(env: &Env, epoch: u32) -> Option<EmissionEpoch> {
+    let key = DataKey::EmissionEpoch(epoch);
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_emission_epoch(env: &Env, epoch: u32, record: &EmissionEpoch) {
+    let key = DataKey::EmissionEpoch(epoch);
+
+    env.storage().persistent().set(&key, record);
+    extend_persistent(env, &key);
+}
+
+/// Weight a juror earned in an epoch, zero once claimed.
+pub(crate) fn get_emission_weight(env: &Env, epoch: u32, juror: &Address) -> Option<u128> {
+    let key = DataKey::EmissionWeight(epoch, juror.clone());
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_emission_weight(env: &Env, epoch: u32, juror: &Address, weight: u128) {
+    let key = DataKey::EmissionWeight(epoch, juror.clone());
+
+    env.storage().persistent().set(&key, &weight);
+    extend_persistent(env, &key);
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::contracterror;
 
+// A contract error enum can have at most 50 variants. Discriminants are part
+// of the interface, so removed variants leave gaps instead of being reused.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -8,29 +10,16 @@ pub enum Error {
     ContractNotInitialized = 1,
     NotAuthorized = 2,
     UserNotFound = 3,
-    ServiceNotFound = 4,
     DisputeNotFound = 5,
     InsufficientTime = 6,
     InvalidDuration = 7,
     BalanceIsZero = 8,
-    InvalidStatus = 9,
-    InvalidService = 10,
-    InvalidServiceStatus = 11,
-    DisputeAlreadyCreated = 12,
-    InvalidDisputeStatus = 13,
     JudgeNotFound = 14,
     InvalidAmount = 15,
-    NoPositionInBlend = 16,
-    InsufficientFundsInBlend = 17,
-    NoTokensToLend = 18,
     DisputeAlreadyResolved = 19,
-    DisputeNotOpen = 20,
     JudgeNotAllowedToVote = 21,
     JudgeAlreadyVoted = 22,
     InvalidReveal = 23,
-    JudgeNotCommitted = 24,
-    UnauthorizedSigner = 25,
-    InvalidKey = 26,
     TallySeedError = 27,
     NoAnonymousVotingConfig = 28,
     AdminNotFound = 29,
@@ -46,4 +35,25 @@ pub enum Error {
     AlreadyClaimed = 39,
     VoterNotFound = 40,
     NoWinner = 41,
+    WrongVoteType = 42,
+    NotWithMajority = 43,
+    BallotNotFound = 44,
+    InvalidBallotOptions = 45,
+    InvalidEligibility = 46,
+    NotEligible = 47,
+    BallotNotOpen = 48,
+    ArbitrationFeeNotPaid = 49,
+    ArbitrationFeeAlreadyPaid = 50,
+    FeeDeadlinePassed = 51,
+    CourtNotFound = 52,
+    InsufficientStake = 53,
+    ConflictOfInterest = 54,
+    DelegatesToParty = 55,
+    ReputationTooLow = 56,
+    VoterSuspended = 57,
+    SettlementNotFound = 58,
+    BatchTooLarge = 59,
+    InsufficientBalance = 60,
+    CallerNotRegistered = 61,
+    TooManyOpenDisputes = 62,
 }
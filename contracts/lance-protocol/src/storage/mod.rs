@@ -1,8 +1,16 @@
+pub mod ballot;
+pub mod config;
+pub mod court;
 pub mod dispute;
 pub mod dispute_status;
+pub mod eligibility;
+pub mod emission;
 pub mod error;
 pub mod project;
+pub mod registry;
+pub mod reputation;
 pub mod storage;
+pub mod vault;
 pub mod vote;
 pub mod voter;
 
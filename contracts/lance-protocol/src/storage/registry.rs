@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env, contracttype};
+
+use crate::storage::{
+    storage::{DataKey, extend_persistent},
+    vote::VotingMode,
+};
+
+/// Parameters shared by the disputes of a registered caller, stored under
+/// `DataKey::CallerTemplate`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeTemplate {
+    pub court_id: Option<u32>, // Jurors are drawn from this court's pool
+    pub juror_count: u32,      // Jurors a dispute can seat
+    pub voting_duration: u64,
+    pub voting_mode: VotingMode,
+    pub arbitration_fee: Option<i128>, // Replaces the court or default fee
+    pub ruling_options: u32,           // Options jurors vote over, `DISPUTE_OPTIONS`
+    pub token: Option<Address>,        // Token of the fees and rewards, the court's in a court
+}
+
+/// How disputes of callers without a template are handled.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum CallerPolicy {
+    /// Anyone can open disputes with the regular fees
+    #[default]
+    Open,
+    /// Both parties pay this arbitration fee instead of the regular one
+    Fee(i128),
+    /// Only registered callers can open disputes
+    Closed,
+}
+
+/// Read the template of a caller, extending its lifetime.
+pub(crate) fn get_caller_template(env: &Env, caller: &Address) -> Option<DisputeTemplate> {
+    let key = DataKey::CallerTemplate(caller.clone());
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_caller_template(env: &Env, caller: &Address, template: &DisputeTemplate) {
+    let key = DataKey::CallerTemplate(caller.clone());
+
+    env.storage().persistent().set(&key, template);
+    extend_persistent(env, &key);
+}
+
+pub(crate) fn remove_caller_template(env: &Env, caller: &Address) -> bool {
+    let key = DataKey::CallerTemplate(caller.clone());
+
+    let registered = env.storage().persistent().has(&key);
+    env.storage().persistent().remove(&key);
+    registered
+}
+
+pub(crate) fn get_caller_policy(env: &Env) -> CallerPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::CallerPolicy)
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_caller_policy(env: &Env, policy: &CallerPolicy) {
+    env.storage().instance().set(&DataKey::CallerPolicy, policy);
+}
@@ -0,0 +1,126 @@
+use soroban_sdk::{Address, Env, Vec, contracttype};
+
+use crate::storage::storage::{DataKey, extend_persistent};
+
+/// Track record of a juror across disputes, stored under `DataKey::JurorStats`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JurorStats {
+    pub disputes: u32,         // Disputes the juror voted on
+    pub coherent_votes: u32,   // Votes for the winner
+    pub incoherent_votes: u32, // Votes for the loser
+    pub missed_votes: u32,     // Seats without a vote
+    pub rewards_earned: i128,  // Rewards and fee shares credited
+    pub last_active: u64,      // Time of the last vote
+}
+
+/// A juror as shown on the leaderboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub juror: Address,
+    pub reputation: u32,    // Decayed to the time of the query
+    pub coherence_bps: u32, // Coherent share of the votes with a known choice
+    pub stats: JurorStats,
+}
+
+/// Jurors kept in the leaderboard index
+pub(crate) const LEADERBOARD_SIZE: u32 = 50;
+
+/// A juror of the leaderboard index, stored under `DataKey::Leaderboard`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RankedJuror {
+    pub juror: Address,
+    pub reputation: u32, // As of the juror's last update
+}
+
+pub(crate) fn get_juror_stats(env: &Env, juror: &Address) -> JurorStats {
+    let key = DataKey::JurorStats(juror.clone());
+
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub(crate) fn set_juror_stats(env: &Env, juror: &Address, stats: &JurorStats) {
+    let key = DataKey::JurorStats(juror.clone());
+
+    env.storage().persistent().set(&key, stats);
+    extend_persistent(env, &key);
+}
+
+/********** Registry **********/
+
+pub(crate) fn get_voter_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::VoterCount)
+        .unwrap_or(0)
+}
+
+pub(crate) fn get_registered_voter_at(env: &Env, index: u32) -> Address {
+    let key = DataKey::VoterAt(index);
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap()
+}
+
+/// Append a newly registered voter to the registry jurors are drawn from.
+pub(crate) fn add_registered_voter(env: &Env, voter: &Address) {
+    let index = get_voter_count(env);
+    let key = DataKey::VoterAt(index);
+
+    env.storage().persistent().set(&key, voter);
+    extend_persistent(env, &key);
+    env.storage()
+        .instance()
+        .set(&DataKey::VoterCount, &(index + 1));
+}
+
+/********** Leaderboard **********/
+
+/// The leaderboard index, highest reputation first.
+pub(crate) fn get_ranking(env: &Env) -> Vec<RankedJuror> {
+    let key = DataKey::Leaderboard;
+
+    extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Move a juror to their rank for a new reputation.
+///
+/// The index keeps the `LEADERBOARD_SIZE` best jurors with some reputation,
+/// after those of equal reputation already listed. A juror pushed out of the
+/// index is only listed again by an update ranking them among the best.
+pub(crate) fn rank_juror(env: &Env, juror: &Address, reputation: u32) {
+    let previous = get_ranking(env);
+
+    let mut ranking = previous.clone();
+    if let Some(index) = ranking.iter().position(|ranked| ranked.juror == *juror) {
+        ranking.remove(index as u32);
+    }
+    let rank = ranking
+        .iter()
+        .position(|ranked| ranked.reputation < reputation)
+        .map_or(ranking.len(), |rank| rank as u32);
+    if reputation > 0 && rank < LEADERBOARD_SIZE {
+        ranking.insert(
+            rank,
+            RankedJuror {
+                juror: juror.clone(),
+                reputation,
+            },
+        );
+        if ranking.len() > LEADERBOARD_SIZE {
+            ranking.pop_back();
+        }
+    }
+
+    if ranking != previous {
+        let key = DataKey::Leaderboard;
+        env.storage().persistent().set(&key, &ranking);
+        extend_persistent(env, &key);
+    }
+}
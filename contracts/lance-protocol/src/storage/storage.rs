@@ -1,4 +1,21 @@
-use soroban_sdk::{Address, contracttype};
+use soroban_sdk::{Address, BytesN, Env, contracttype};
+
+/// Average close time of a ledger
+pub(crate) const LEDGER_SECONDS: u64 = 5;
+/// One day assuming 5s a ledger
+pub(crate) const ONE_DAY_LEDGERS: u32 = 17280;
+
+// The instance holds the admin, token and voting configs, which must never expire.
+const INSTANCE_BUMP: u32 = 30 * ONE_DAY_LEDGERS;
+const INSTANCE_THRESHOLD: u32 = INSTANCE_BUMP - 3 * ONE_DAY_LEDGERS;
+
+// Voters, balances and the entries of active disputes are extended on access.
+pub(crate) const LEDGER_BUMP: u32 = 30 * ONE_DAY_LEDGERS;
+const LEDGER_THRESHOLD: u32 = LEDGER_BUMP - 3 * ONE_DAY_LEDGERS;
+
+// Finished disputes are no longer extended on access. They are kept for this
+// long after being resolved so rewards can be claimed, then allowed to expire.
+pub(crate) const FINISHED_DISPUTE_RETENTION: u32 = 60 * ONE_DAY_LEDGERS;
 
 #[derive(Clone)]
 #[contracttype]
@@ -8,7 +25,80 @@ pub enum DataKey {
     Voters(Address),
     Disputes(u32),
     DisputeId,
-    Balances(Address),
+    Balances(Address, Address), // (user, token) -> redeemable balance
     AnonymousVoteConfig(u32),
     RewardClaimed(u32, Address), // (dispute_id, voter_address)
+    DisputeJuror(u32, Address),   // (dispute_id, juror) -> registration index
+    DisputeJurorAt(u32, u32),     // (dispute_id, index) -> juror
+    VoteCommit(u32, Address),     // (dispute_id, juror) -> commit hash
+    VoteCommitAt(u32, u32),       // (dispute_id, index) -> juror
+    VoteReveal(u32, Address),     // (dispute_id, juror) -> revealed vote
+    DisputeVote(u32, Address),    // (dispute_id, voter) -> public or anonymous vote
+    DisputeVoteAt(u32, u32),      // (dispute_id, index) -> voter
+    BallotId,
+    Ballots(u32),
+    BallotVoter(u32, Address),    // (ballot_id, voter) -> eligibility index
+    BallotVoterAt(u32, u32),      // (ballot_id, index) -> eligible voter
+    BallotVote(u32, Address),     // (ballot_id, voter) -> public or anonymous vote
+    BallotVoteAt(u32, u32),       // (ballot_id, index) -> voter
+    ArbitrationFee,
+    CourtId,
+    Courts(u32),
+    CourtMember(u32, Address), // (court_id, juror) -> joined the court itself
+    CourtJuror(u32, Address),  // (court_id, juror) -> seat in the pool
+    CourtJurorAt(u32, u32),    // (court_id, index) -> juror
+    EligibilityRules,
+    Counterparties(Address, Address), // Both addresses were parties of a dispute
+    Suspension(Address),
+    Governance,
+    JurorStats(Address),
+    VoterCount,
+    VoterAt(u32), // Registration index -> voter
+    Settlement(u32),
+    ProtocolConfig,
+    CallerTemplate(Address), // Integrator -> dispute template
+    CallerPolicy,
+    CreatorActivity(Address),
+    Justification(u32, Address), // (dispute_id, juror) -> justification hash
+    JustificationCommit(u32, BytesN<32>), // (dispute_id, anonymous commitment) -> nullifier once opened
+    AnonymousJustification(u32, BytesN<32>), // (dispute_id, nullifier) -> justification hash
+    AllowedToken(Address),
+    EmissionSchedule,
+    Emissions,
+    EmissionEpoch(u32),
+    EmissionWeight(u32, Address), // (epoch, juror) -> coherent weight
+    StakeVault(Address),
+    RewardDust(Address), // token -> rounding remainders of reward pools
+    Leaderboard,         // Top jurors by reputation
+}
+
+//********** Storage Utils **********//
+
+/// Bump the instance lifetime by the defined amount
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_THRESHOLD, INSTANCE_BUMP);
+}
+
+/// Bump a persistent entry by the defined amount, if it exists
+pub(crate) fn extend_persistent(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+}
+
+/// Make sure a persistent entry lives for at least the finished dispute
+/// retention period, if it exists
+pub(crate) fn retain_persistent(env: &Env, key: &DataKey) {
+    retain_persistent_for(env, key, FINISHED_DISPUTE_RETENTION);
+}
+
+/// Make sure a persistent entry lives for at least `ttl` ledgers, if it exists
+pub(crate) fn retain_persistent_for(env: &Env, key: &DataKey, ttl: u32) {
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(key, ttl, ttl);
+    }
 }
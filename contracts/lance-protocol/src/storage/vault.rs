@@ -0,0 +1,46 @@
+use soroban_sdk::{Address, Env, Map, contracttype};
+
+use crate::storage::storage::{DataKey, extend_persistent};
+
+/// Stake of a juror in the vault, stored under `DataKey::StakeVault`.
+///
+/// The vault backs the juror's seats on every dispute, including court
+/// disputes. Each seat locks part of the bonded stake until the juror is
+/// settled after the ruling, see `settle_jurors`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeVault {
+    pub staked: i128,                    // Bonded stake, including the locked stake
+    pub unbonding: i128,                 // Stake waiting out the unbonding period
+    pub unbonding_ends_at: u64,          // Time the unbonding stake can be withdrawn
+    pub active_disputes: Map<u32, i128>, // Stake locked by each dispute the juror sits on
+}
+
+/// Stake of a juror as shown to clients.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeBalance {
+    pub locked: i128,    // Bonded stake locked by unsettled disputes
+    pub free: i128,      // Bonded stake which can start unbonding
+    pub unbonding: i128, // Stake waiting out the unbonding period
+    pub unbonding_ends_at: u64,
+}
+
+pub(crate) fn get_stake_vault(env: &Env, juror: &Address) -> StakeVault {
+    let key = DataKey::StakeVault(juror.clone());
+
+    extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(StakeVault {
+        staked: 0,
+        unbonding: 0,
+        unbonding_ends_at: 0,
+        active_disputes: Map::new(env),
+    })
+}
+
+pub(crate) fn set_stake_vault(env: &Env, juror: &Address, vault: &StakeVault) {
+    let key = DataKey::StakeVault(juror.clone());
+
+    env.storage().persistent().set(&key, vault);
+    extend_persistent(env, &key);
+}
@@ -9,13 +9,57 @@ pub struct Vote {
     pub vote: bool,
 }
 
+/// How votes are cast on a dispute, selected when the dispute is created.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VotingMode {
+    /// Choices and weights are visible, tallied on-chain
+    Public,
+    /// Judges commit SHA256(vote || secret) and the creator reveals them
+    CommitReveal,
+    /// Choices are encrypted, tallies are proven against BLS12-381 commitments
+    Anonymous,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VoteChoice {
+    Creator = 0,
+    Counterpart = 1,
+    Abstain = 2,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub enum VoteAnon {
-    //PublicVote(PublicVote),
+    PublicVote(PublicVote),
     AnonymousVote(AnonymousVote),
 }
 
+impl VoteAnon {
+    pub fn address(&self) -> &Address {
+        match self {
+            VoteAnon::PublicVote(vote) => &vote.address,
+            VoteAnon::AnonymousVote(vote) => &vote.address,
+        }
+    }
+
+    pub fn weight(&self) -> u32 {
+        match self {
+            VoteAnon::PublicVote(vote) => vote.weight,
+            VoteAnon::AnonymousVote(vote) => vote.weight,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicVote {
+    pub address: Address,
+    pub weight: u32,
+    pub vote_choice: VoteChoice,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct AnonymousVoteConfig {
@@ -32,6 +76,7 @@ pub struct AnonymousVote {
     pub encrypted_seeds: Vec<String>,
     pub encrypted_votes: Vec<String>,
     pub commitments: Vec<BytesN<96>>,
+    pub justification_commit: Option<BytesN<32>>, // SHA256(nullifier || justification), disputes only
 }
 
 #[contracttype]
@@ -48,8 +93,10 @@ pub enum Badge {
 #[derive(Clone, Debug, PartialEq)]
 pub struct VoteData {
     pub voting_ends_at: u64,
-    //pub public_voting: bool,
+    pub voting_mode: VotingMode,
     pub votes: Vec<VoteAnon>,
+    /// Running weighted sum of the commitments per option [creator, counterpart, abstain]
+    pub tally_commitments: Vec<BytesN<96>>,
 }
 
 /// Get the anonymous voting configuration for a project.
@@ -1,5 +1,12 @@
 use soroban_sdk::{contracttype, Address, Env, Vec};
-use crate::storage::{error::Error, storage::DataKey};
+use crate::storage::{
+    error::Error,
+    reputation::{add_registered_voter, rank_juror},
+    storage::{DataKey, extend_persistent},
+};
+
+/// Inactivity after which a voter loses a tenth of their reputation
+pub(crate) const REPUTATION_DECAY_PERIOD: u64 = 30 * 24 * 3600;
 
 #[derive(Clone)]
 #[contracttype]
@@ -8,15 +15,46 @@ pub struct Voter {
     pub delegates: Vec<Address>,
     pub reputation: u32,
     pub balance: i128,
+    pub decayed_at: u64, // Start of the current decay period
 }
 
+/// Read a voter with their reputation decayed to the current time.
+///
+/// Decay is lazy: it is only stored with the next update of the voter.
 pub(crate) fn get_voter(env: &Env, voter: Address) -> Result<Voter, Error> {
     let key = DataKey::Voters(voter);
 
-    env.storage()
-        .instance()
+    let mut voter = env
+        .storage()
+        .persistent()
         .get(&key)
-        .ok_or(Error::UserNotFound)
+        .ok_or(Error::UserNotFound)?;
+    extend_persistent(env, &key);
+    decay_reputation(env, &mut voter);
+    Ok(voter)
+}
+
+/// Take a tenth of the reputation, rounded up, for each full decay period.
+fn decay_reputation(env: &Env, voter: &mut Voter) {
+    let elapsed = env.ledger().timestamp().saturating_sub(voter.decayed_at);
+    let periods = elapsed / REPUTATION_DECAY_PERIOD;
+    voter.decayed_at += periods * REPUTATION_DECAY_PERIOD;
+
+    // any u32 reputation reaches zero within a few hundred periods
+    for _ in 0..periods.min(u32::BITS as u64 * 10) {
+        if voter.reputation == 0 {
+            break;
+        }
+        voter.reputation -= voter.reputation.div_ceil(10);
+    }
+}
+
+/// Restart the decay period of an active voter.
+pub(crate) fn touch_voter(env: &Env, voter: &Address) {
+    if let Ok(mut voter) = get_voter(env, voter.clone()) {
+        voter.decayed_at = env.ledger().timestamp();
+        update_voter(env, voter, 0, 0);
+    }
 }
 
 pub(crate) fn set_voter(
@@ -28,22 +66,34 @@ pub(crate) fn set_voter(
         delegates: Vec::new(env),
         reputation: 0,
         balance: 0,
+        decayed_at: env.ledger().timestamp(),
     };
 
     let key = DataKey::Voters(address.clone());
 
-    env.storage().instance().set(&key, &new_voter);
+    if !env.storage().persistent().has(&key) {
+        add_registered_voter(env, &address);
+    }
+    env.storage().persistent().set(&key, &new_voter);
+    extend_persistent(env, &key);
+    rank_juror(env, &address, 0);
 }
 
+/// Apply balance and reputation deltas to a voter and store it.
+///
+/// Reputation is signed so penalties can lower it, never below zero. The
+/// voter moves to their rank on the leaderboard.
 pub(crate) fn update_voter(
     env: &Env,
     mut voter: Voter,
     balance_delta: i128,
-    reputation_delta: u32,
+    reputation_delta: i32,
 ) {
     voter.balance += balance_delta;
-    voter.reputation += reputation_delta;
+    voter.reputation = voter.reputation.saturating_add_signed(reputation_delta);
 
     let key = DataKey::Voters(voter.address.clone());
-    env.storage().instance().set(&key, &voter);
+    env.storage().persistent().set(&key, &voter);
+    extend_persistent(env, &key);
+    rank_juror(env, &voter.address, voter.reputation);
 }
@@ -6,3 +6,18 @@ pub mod test_voter;
 pub mod test_balance;
 pub mod test_integration;
 pub mod test_rewards;
+pub mod test_tally;
+pub mod test_ttl;
+pub mod test_voting_mode;
+pub mod test_ballot;
+pub mod test_fees;
+pub mod test_court;
+pub mod test_eligibility;
+pub mod test_reputation;
+pub mod test_settlement;
+pub mod test_phase;
+pub mod test_config;
+pub mod test_registry;
+pub mod test_emission;
+pub mod test_vault;
+pub mod test_budget;
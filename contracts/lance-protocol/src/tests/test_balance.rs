@@ -1,5 +1,8 @@
 use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token;
 
+use crate::methods::balance::set_balance;
+use crate::storage::error::Error;
 use crate::tests::test_utils::create_test_data;
 
 #[test]
@@ -8,7 +11,9 @@ fn test_get_balance_new_user() {
     
     let user = soroban_sdk::Address::generate(&setup.env);
     
-    let balance = setup.contract.get_balance(&user);
+    let balance = setup
+        .contract
+        .get_balance(&user, &setup.token_stellar.address);
     
     assert_eq!(balance, 0);
 }
@@ -21,9 +26,15 @@ fn test_get_balance_multiple_users() {
     let user2 = soroban_sdk::Address::generate(&setup.env);
     let user3 = soroban_sdk::Address::generate(&setup.env);
     
-    let balance1 = setup.contract.get_balance(&user1);
-    let balance2 = setup.contract.get_balance(&user2);
-    let balance3 = setup.contract.get_balance(&user3);
+    let balance1 = setup
+        .contract
+        .get_balance(&user1, &setup.token_stellar.address);
+    let balance2 = setup
+        .contract
+        .get_balance(&user2, &setup.token_stellar.address);
+    let balance3 = setup
+        .contract
+        .get_balance(&user3, &setup.token_stellar.address);
     
     assert_eq!(balance1, 0);
     assert_eq!(balance2, 0);
@@ -43,7 +54,9 @@ fn test_balance_after_voting() {
     let setup = create_test_data();
     
     // Initial balance should be 0
-    let initial_balance = setup.contract.get_balance(&setup.judge1);
+    let initial_balance = setup
+        .contract
+        .get_balance(&setup.judge1, &setup.token_stellar.address);
     assert_eq!(initial_balance, 0);
     
     // After voting and winning, balance might be updated
@@ -54,9 +67,15 @@ fn test_balance_after_voting() {
 fn test_multiple_judges_balance() {
     let setup = create_test_data();
     
-    let judge1_balance = setup.contract.get_balance(&setup.judge1);
-    let judge2_balance = setup.contract.get_balance(&setup.judge2);
-    let judge3_balance = setup.contract.get_balance(&setup.judge3);
+    let judge1_balance = setup
+        .contract
+        .get_balance(&setup.judge1, &setup.token_stellar.address);
+    let judge2_balance = setup
+        .contract
+        .get_balance(&setup.judge2, &setup.token_stellar.address);
+    let judge3_balance = setup
+        .contract
+        .get_balance(&setup.judge3, &setup.token_stellar.address);
     
     assert_eq!(judge1_balance, 0);
     assert_eq!(judge2_balance, 0);
@@ -67,9 +86,50 @@ fn test_multiple_judges_balance() {
 fn test_creator_and_counterpart_balance() {
     let setup = create_test_data();
     
-    let creator_balance = setup.contract.get_balance(&setup.creator);
-    let counterpart_balance = setup.contract.get_balance(&setup.counterpart);
+    let creator_balance = setup
+        .contract
+        .get_balance(&setup.creator, &setup.token_stellar.address);
+    let counterpart_balance = setup
+        .contract
+        .get_balance(&setup.counterpart, &setup.token_stellar.address);
     
     assert_eq!(creator_balance, 0);
     assert_eq!(counterpart_balance, 0);
 }
+
+#[test]
+fn test_partial_redeem_to_recipient() {
+    let setup = create_test_data();
+    let recipient = soroban_sdk::Address::generate(&setup.env);
+    let asset = setup.token_stellar.address.clone();
+    setup.token_stellar.mint(&setup.contract_id, &100);
+    setup.env.as_contract(&setup.contract_id, || {
+        set_balance(&setup.env, &setup.judge1, &asset, 100);
+    });
+
+    let result = setup
+        .contract
+        .try_redeem_to(&setup.judge1, &recipient, &asset, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    let result = setup
+        .contract
+        .try_redeem_to(&setup.judge1, &recipient, &asset, &101);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    let result = setup
+        .contract
+        .try_redeem_to(&setup.judge2, &recipient, &asset, &1);
+    assert_eq!(result, Err(Ok(Error::BalanceIsZero)));
+
+    let left = setup
+        .contract
+        .redeem_to(&setup.judge1, &recipient, &asset, &30);
+    assert_eq!(left, 70);
+    assert_eq!(setup.contract.get_balance(&setup.judge1, &asset), 70);
+    let token = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+    assert_eq!(token.balance(&recipient), 30);
+
+    // the rest is redeemed to the owner
+    assert_eq!(setup.contract.redeem(&setup.judge1, &asset), 70);
+    assert_eq!(token.balance(&setup.judge1), 70);
+    assert_eq!(setup.contract.get_balance(&setup.judge1, &asset), 0);
+}
@@ -0,0 +1,585 @@
+use soroban_sdk::{Address, String, Vec, testutils::Address as _, testutils::Ledger, vec};
+
+use crate::methods::ballot::{MAX_DRAWN_VOTERS_PER_CALL, MAX_LISTED_VOTERS_PER_CALL};
+use crate::storage::ballot::{
+    BallotConfig, BallotStatus, BallotVote, Eligibility, PublicBallotVote,
+};
+use crate::storage::config::ProtocolConfig;
+use crate::storage::error::Error;
+use crate::storage::vote::{AnonymousVote, VotingMode};
+use crate::tests::test_utils::{TestSetup, create_test_data};
+
+fn ballot_config(
+    setup: &TestSetup,
+    options: u32,
+    eligibility: Eligibility,
+    voting_mode: VotingMode,
+) -> BallotConfig {
+    let mut options_ = Vec::new(&setup.env);
+    for _ in 0..options {
+        options_.push_back(String::from_str(&setup.env, "option"));
+    }
+    BallotConfig {
+        title: String::from_str(&setup.env, "Which grant should be funded?"),
+        options: options_,
+        eligibility,
+        voting_mode,
+        voting_starts_at: setup.env.ledger().timestamp(),
+        voting_ends_at: setup.voting_ends_at,
+        quorum: 0,
+    }
+}
+
+fn judges(setup: &TestSetup) -> Vec<Address> {
+    vec![
+        &setup.env,
+        setup.judge1.clone(),
+        setup.judge2.clone(),
+        setup.judge3.clone(),
+    ]
+}
+
+fn public_vote(voter: &Address, weight: u32, option: u32) -> BallotVote {
+    BallotVote::PublicVote(PublicBallotVote {
+        address: voter.clone(),
+        weight,
+        option,
+    })
+}
+
+fn anonymous_vote(
+    setup: &TestSetup,
+    voter: &Address,
+    weight: u32,
+    votes: Vec<u128>,
+    seeds: Vec<u128>,
+) -> BallotVote {
+    let placeholder = String::from_str(&setup.env, "encrypted");
+    let mut encrypted = Vec::new(&setup.env);
+    for _ in 0..votes.len() {
+        encrypted.push_back(placeholder.clone());
+    }
+    BallotVote::AnonymousVote(AnonymousVote {
+        address: voter.clone(),
+        weight,
+        encrypted_seeds: encrypted.clone(),
+        encrypted_votes: encrypted,
+        commitments: setup
+            .contract
+            .build_commitments_from_votes(&setup.project_id, &votes, &seeds),
+        justification_commit: None,
+    })
+}
+
+#[test]
+fn test_public_ballot_decided() {
+    let setup = create_test_data();
+    let config = ballot_config(
+        &setup,
+        4,
+        Eligibility::Voters(judges(&setup)),
+        VotingMode::Public,
+    );
+
+    let ballot = setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+    assert_eq!(ballot.ballot_id, 1);
+    assert_eq!(ballot.status, BallotStatus::Open);
+    assert_eq!(ballot.voter_count, 3);
+    assert_eq!(
+        ballot.tally.tallies,
+        vec![&setup.env, 0u128, 0u128, 0u128, 0u128]
+    );
+
+    setup
+        .contract
+        .vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 5, 3));
+    setup
+        .contract
+        .vote_ballot(&setup.judge2, &1, &public_vote(&setup.judge2, 2, 0));
+    setup
+        .contract
+        .vote_ballot(&setup.judge3, &1, &public_vote(&setup.judge3, 1, 1));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup
+        .contract
+        .finalize_ballot(&setup.creator, &1, &None, &None);
+    assert_eq!(status, BallotStatus::Decided);
+
+    let ballot = setup.contract.get_ballot(&1);
+    assert_eq!(ballot.winning_option, Some(3));
+    assert_eq!(
+        ballot.tally.tallies,
+        vec![&setup.env, 2u128, 1u128, 0u128, 5u128]
+    );
+    assert_eq!(ballot.tally.total_weight, 8);
+    assert_eq!(ballot.vote_count, 3);
+
+    let votes = setup.contract.get_ballot_votes(&1, &1, &10);
+    assert_eq!(votes.len(), 2);
+    assert_eq!(votes.get(0), Some(public_vote(&setup.judge2, 2, 0)));
+    assert!(setup.contract.get_ballot_votes(&1, &3, &10).is_empty());
+}
+
+#[test]
+fn test_public_ballot_tied() {
+    let setup = create_test_data();
+    let config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(judges(&setup)),
+        VotingMode::Public,
+    );
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+
+    setup
+        .contract
+        .vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 0));
+    setup
+        .contract
+        .vote_ballot(&setup.judge2, &1, &public_vote(&setup.judge2, 1, 1));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup
+        .contract
+        .finalize_ballot(&setup.creator, &1, &None, &None);
+    assert_eq!(status, BallotStatus::Tied);
+    assert_eq!(setup.contract.get_ballot(&1).winning_option, None);
+}
+
+#[test]
+fn test_ballot_without_quorum() {
+    let setup = create_test_data();
+    let mut config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(judges(&setup)),
+        VotingMode::Public,
+    );
+    config.quorum = 3;
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+
+    setup
+        .contract
+        .vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 2, 0));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup
+        .contract
+        .finalize_ballot(&setup.creator, &1, &None, &None);
+    assert_eq!(status, BallotStatus::NoQuorum);
+    assert_eq!(setup.contract.get_ballot(&1).winning_option, None);
+
+    // can only be finalized once
+    assert_eq!(
+        setup
+            .contract
+            .try_finalize_ballot(&setup.creator, &1, &None, &None),
+        Err(Ok(Error::BallotNotOpen))
+    );
+}
+
+#[test]
+fn test_anonymous_ballot_proven_at_finalization() {
+    let setup = create_test_data();
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+
+    let config = ballot_config(
+        &setup,
+        4,
+        Eligibility::Voters(judges(&setup)),
+        VotingMode::Anonymous,
+    );
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+
+    let vote1 = anonymous_vote(
+        &setup,
+        &setup.judge1,
+        3,
+        vec![&setup.env, 0u128, 0u128, 1u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128, 4u128],
+    );
+    let vote2 = anonymous_vote(
+        &setup,
+        &setup.judge2,
+        2,
+        vec![&setup.env, 1u128, 0u128, 0u128, 0u128],
+        vec![&setup.env, 5u128, 6u128, 7u128, 8u128],
+    );
+    setup.contract.vote_ballot(&setup.judge1, &1, &vote1);
+    setup.contract.vote_ballot(&setup.judge2, &1, &vote2);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+
+    // tallies and seeds are scaled by the weights
+    let tallies = vec![&setup.env, 2u128, 0u128, 3u128, 0u128];
+    let seeds = vec![&setup.env, 13u128, 18u128, 23u128, 28u128];
+
+    let wrong_tallies = vec![&setup.env, 3u128, 0u128, 2u128, 0u128];
+    assert_eq!(
+        setup.contract.try_finalize_ballot(
+            &setup.creator,
+            &1,
+            &Some(wrong_tallies),
+            &Some(seeds.clone())
+        ),
+        Err(Ok(Error::InvalidProof))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_finalize_ballot(&setup.creator, &1, &None, &None),
+        Err(Ok(Error::TallySeedError))
+    );
+
+    let status =
+        setup
+            .contract
+            .finalize_ballot(&setup.creator, &1, &Some(tallies.clone()), &Some(seeds));
+    assert_eq!(status, BallotStatus::Decided);
+
+    let ballot = setup.contract.get_ballot(&1);
+    assert_eq!(ballot.winning_option, Some(2));
+    assert_eq!(ballot.tally.tallies, tallies);
+    assert_eq!(ballot.tally.total_weight, 5);
+}
+
+#[test]
+fn test_anonymous_ballot_rejects_wrong_commitment_count() {
+    let setup = create_test_data();
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+
+    let config = ballot_config(
+        &setup,
+        4,
+        Eligibility::Voters(judges(&setup)),
+        VotingMode::Anonymous,
+    );
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+
+    // a dispute sized vote on a four option ballot
+    let vote_ = anonymous_vote(
+        &setup,
+        &setup.judge1,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128],
+    );
+    assert_eq!(
+        setup.contract.try_vote_ballot(&setup.judge1, &1, &vote_),
+        Err(Ok(Error::BadCommitment))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 0)),
+        Err(Ok(Error::WrongVoteType))
+    );
+}
+
+#[test]
+fn test_ballot_vote_checks() {
+    let setup = create_test_data();
+    let mut config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(vec![&setup.env, setup.judge1.clone()]),
+        VotingMode::Public,
+    );
+    config.voting_starts_at = setup.env.ledger().timestamp() + 100;
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+    assert!(setup.contract.is_ballot_voter(&1, &setup.judge1));
+    assert!(!setup.contract.is_ballot_voter(&1, &setup.judge2));
+
+    // voting has not started yet
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 0)),
+        Err(Ok(Error::ProposalVotingTime))
+    );
+
+    setup.env.ledger().set_timestamp(config.voting_starts_at);
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge2, &1, &public_vote(&setup.judge2, 1, 0)),
+        Err(Ok(Error::NotEligible))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 2)),
+        Err(Ok(Error::InvalidBallotOptions))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 101, 0)),
+        Err(Ok(Error::VoterWeight))
+    );
+
+    setup
+        .contract
+        .vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 0));
+    assert_eq!(
+        setup
+            .contract
+            .try_vote_ballot(&setup.judge1, &1, &public_vote(&setup.judge1, 1, 1)),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // cannot finalize before the deadline
+    assert_eq!(
+        setup
+            .contract
+            .try_finalize_ballot(&setup.creator, &1, &None, &None),
+        Err(Ok(Error::ProposalVotingTime))
+    );
+}
+
+/// Register `count` voters, staking `stake` in the vault when positive
+fn register_voters(setup: &TestSetup, count: u32, stake: i128) -> Vec<Address> {
+    let mut voters = Vec::new(&setup.env);
+    for _ in 0..count {
+        let voter = Address::generate(&setup.env);
+        setup.contract.new_voter(&voter);
+        if stake > 0 {
+            setup.token_stellar.mint(&voter, &stake);
+            setup.contract.stake(&voter, &stake);
+        }
+        voters.push_back(voter);
+    }
+    voters
+}
+
+#[test]
+fn test_juror_draw() {
+    let setup = create_test_data();
+    setup.contract.set_protocol_config(
+        &setup.contract_admin,
+        &ProtocolConfig {
+            evidence_duration: 24 * 3600,
+            juror_stake: 100,
+            ..ProtocolConfig::default()
+        },
+    );
+    let staked = register_voters(&setup, 6, 100);
+    let unstaked = register_voters(&setup, 2, 0);
+
+    // jurors are drawn before voting starts
+    let mut config = ballot_config(&setup, 2, Eligibility::JurorDraw(4), VotingMode::Public);
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidEligibility))
+    );
+    config.voting_starts_at = setup.env.ledger().timestamp() + 100;
+    let ballot = setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+    assert_eq!(ballot.voter_count, 0);
+    assert_eq!(ballot.jurors, 4);
+
+    // the creator cannot pick the ledger of the draw
+    assert_eq!(
+        setup
+            .contract
+            .try_draw_ballot_voters(&setup.creator, &1, &1),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        setup.contract.try_draw_ballot_voters(
+            &setup.contract_admin,
+            &1,
+            &(MAX_DRAWN_VOTERS_PER_CALL + 1)
+        ),
+        Err(Ok(Error::BatchTooLarge))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_add_ballot_voters(&setup.creator, &1, &staked),
+        Err(Ok(Error::InvalidEligibility))
+    );
+
+    setup
+        .contract
+        .draw_ballot_voters(&setup.contract_admin, &1, &3);
+    let ballot = setup
+        .contract
+        .draw_ballot_voters(&setup.contract_admin, &1, &1);
+    assert_eq!(ballot.voter_count, 4);
+    assert_eq!(
+        setup
+            .contract
+            .try_draw_ballot_voters(&setup.contract_admin, &1, &1),
+        Err(Ok(Error::InvalidEligibility))
+    );
+
+    // only registered voters with the juror stake are drawn
+    let drawn = staked
+        .iter()
+        .filter(|voter| setup.contract.is_ballot_voter(&1, voter))
+        .count();
+    assert_eq!(drawn, 4);
+    for voter in unstaked.iter() {
+        assert!(!setup.contract.is_ballot_voter(&1, &voter));
+    }
+
+    setup.env.ledger().set_timestamp(config.voting_starts_at);
+    assert_eq!(
+        setup
+            .contract
+            .try_draw_ballot_voters(&setup.contract_admin, &1, &1),
+        Err(Ok(Error::ProposalVotingTime))
+    );
+}
+
+#[test]
+fn test_voters_listed_in_pages() {
+    let setup = create_test_data();
+    let voters = register_voters(&setup, MAX_LISTED_VOTERS_PER_CALL + 1, 0);
+
+    let mut config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(voters.clone()),
+        VotingMode::Public,
+    );
+    config.voting_starts_at = setup.env.ledger().timestamp() + 100;
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::BatchTooLarge))
+    );
+
+    let first_page = voters.slice(0..MAX_LISTED_VOTERS_PER_CALL);
+    config.eligibility = Eligibility::Voters(first_page.clone());
+    setup
+        .contract
+        .open_ballot(&setup.creator, &setup.project_id, &config);
+
+    let last_page = voters.slice(MAX_LISTED_VOTERS_PER_CALL..);
+    assert_eq!(
+        setup
+            .contract
+            .try_add_ballot_voters(&setup.judge1, &1, &last_page),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_add_ballot_voters(&setup.creator, &1, &first_page.slice(0..1)),
+        Err(Ok(Error::InvalidEligibility))
+    );
+    let ballot = setup
+        .contract
+        .add_ballot_voters(&setup.creator, &1, &last_page);
+    assert_eq!(ballot.voter_count, MAX_LISTED_VOTERS_PER_CALL + 1);
+    assert!(setup.contract.is_ballot_voter(&1, &voters.last().unwrap()));
+
+    // the voters are settled once voting starts
+    setup.env.ledger().set_timestamp(config.voting_starts_at);
+    assert_eq!(
+        setup.contract.try_add_ballot_voters(
+            &setup.creator,
+            &1,
+            &vec![&setup.env, setup.judge1.clone()]
+        ),
+        Err(Ok(Error::ProposalVotingTime))
+    );
+}
+
+#[test]
+fn test_open_ballot_validation() {
+    let setup = create_test_data();
+    let eligibility = Eligibility::Voters(judges(&setup));
+
+    let config = ballot_config(&setup, 1, eligibility.clone(), VotingMode::Public);
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidBallotOptions))
+    );
+
+    let config = ballot_config(&setup, 2, eligibility.clone(), VotingMode::CommitReveal);
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::WrongVoteType))
+    );
+
+    let mut config = ballot_config(&setup, 2, eligibility.clone(), VotingMode::Public);
+    config.voting_starts_at = config.voting_ends_at;
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidDuration))
+    );
+
+    let config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(Vec::new(&setup.env)),
+        VotingMode::Public,
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidEligibility))
+    );
+
+    // duplicates would count a voter twice
+    let mut voters = judges(&setup);
+    voters.push_back(setup.judge1.clone());
+    let config = ballot_config(
+        &setup,
+        2,
+        Eligibility::Voters(voters.clone()),
+        VotingMode::Public,
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidEligibility))
+    );
+    let mut config = ballot_config(&setup, 2, Eligibility::JurorDraw(0), VotingMode::Public);
+    config.voting_starts_at = setup.env.ledger().timestamp() + 100;
+    assert_eq!(
+        setup
+            .contract
+            .try_open_ballot(&setup.creator, &setup.project_id, &config),
+        Err(Ok(Error::InvalidEligibility))
+    );
+
+    assert_eq!(
+        setup.contract.try_get_ballot(&1),
+        Err(Ok(Error::BallotNotFound))
+    );
+}
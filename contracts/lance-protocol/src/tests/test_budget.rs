@@ -0,0 +1,797 @@
+extern crate std;
+
+use soroban_sdk::testutils::{Address as _, ContractFunctionSet, Ledger};
+use soroban_sdk::{Address, Bytes, Env, String, Val, Vec, vec};
+
+use crate::ProtocolContract;
+use crate::contract::ProtocolContractClient;
+use crate::methods::ballot::{MAX_DRAWN_VOTERS_PER_CALL, MAX_LISTED_VOTERS_PER_CALL};
+use crate::methods::court::MAX_DRAWN_JURORS_PER_CALL;
+use crate::methods::dispute::{MAX_CLAIMS_PER_CALL, SETTLE_PAGE_SIZE};
+use crate::methods::reputation::LEADERBOARD_PAGE_SIZE;
+use crate::methods::vote::{MAX_JURORS, MAX_REVEALS_PER_CALL};
+use crate::storage::ballot::{BallotConfig, Eligibility};
+use crate::storage::config::ProtocolConfig;
+use crate::storage::dispute::{DISPUTE_BUMP_PAGE_SIZE, DISPUTE_OPTIONS};
+use crate::storage::eligibility::EligibilityRules;
+use crate::storage::error::Error;
+use crate::storage::registry::DisputeTemplate;
+use crate::storage::reputation::LEADERBOARD_SIZE;
+use crate::storage::vote::{VoteAnon, VoteChoice, VotingMode};
+use crate::storage::voter::{get_voter, update_voter};
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, build_anonymous_vote, compute_commit_hash, create_test_data,
+    end_appeal_period, open_dispute, seat_and_vote, settle_all_jurors,
+};
+
+// The entrypoints are measured on disputes with growing juries, against the
+// network limits and against their cost when the tests were last calibrated.
+// The contract runs natively, so only host functions are metered and
+// instructions are a lower bound of the Wasm cost. Storage entry counts are
+// exact.
+//
+// The test ledger keeps every entry of the setup and the host meters copies
+// of it, which makes each call on a large jury costlier than on the network.
+// The entrypoints ruling, settling and extending a dispute are measured on
+// the ledger reloaded from a snapshot, which like the network only loads the
+// entries they access.
+
+// Per transaction limits of the Stellar public network
+const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+const TX_MEMORY_LIMIT: i64 = 41_943_040;
+const TX_MAX_FOOTPRINT_ENTRIES: u32 = 100;
+const TX_MAX_WRITE_ENTRIES: u32 = 50;
+const TX_MAX_WRITE_BYTES: u32 = 132_096;
+const TX_MAX_CONTRACT_EVENTS_SIZE: u32 = 16_384;
+
+/// Cost of an invocation when the tests were last calibrated. Instructions
+/// and memory get some headroom, storage entries are exact.
+struct Ceiling {
+    instructions: i64,
+    mem_bytes: i64,
+    footprint: u32,
+    write_entries: u32,
+}
+
+fn fits_in_transaction(env: &Env) -> bool {
+    let resources = env.cost_estimate().resources();
+    resources.instructions <= TX_MAX_INSTRUCTIONS
+        && resources.mem_bytes <= TX_MEMORY_LIMIT
+        && resources.disk_read_entries + resources.memory_read_entries <= TX_MAX_FOOTPRINT_ENTRIES
+        && resources.write_entries <= TX_MAX_WRITE_ENTRIES
+        && resources.write_bytes <= TX_MAX_WRITE_BYTES
+        && resources.contract_events_size_bytes <= TX_MAX_CONTRACT_EVENTS_SIZE
+}
+
+/// Check the last invocation fits in a transaction and did not get costlier.
+fn assert_cost(env: &Env, label: &str, voters: u32, ceiling: Ceiling) {
+    let resources = env.cost_estimate().resources();
+    let footprint = resources.disk_read_entries + resources.memory_read_entries;
+    assert!(
+        fits_in_transaction(env),
+        "{label} with {voters} voters exceeds the network limits"
+    );
+    assert!(
+        resources.instructions <= ceiling.instructions,
+        "{label} with {voters} voters: cpu regressed"
+    );
+    assert!(
+        resources.mem_bytes <= ceiling.mem_bytes,
+        "{label} with {voters} voters: memory regressed"
+    );
+    assert!(
+        footprint <= ceiling.footprint,
+        "{label} with {voters} voters: footprint regressed"
+    );
+    assert!(
+        resources.write_entries <= ceiling.write_entries,
+        "{label} with {voters} voters: writes regressed"
+    );
+}
+
+/// Every third juror votes for the counterpart and is penalized at the ruling
+fn juror_choice(index: u32) -> VoteChoice {
+    if index % 3 == 2 {
+        VoteChoice::Counterpart
+    } else {
+        VoteChoice::Creator
+    }
+}
+
+/// Seat new registered voters who cast public votes, from the `first` juror
+/// of the dispute to the `last` excluded
+fn cast_public_votes(setup: &TestSetup, dispute_id: u32, first: u32, last: u32) {
+    for index in first..last {
+        let voter = Address::generate(&setup.env);
+        setup.contract.new_voter(&voter);
+        seat_and_vote(setup, &voter, dispute_id, 1, juror_choice(index));
+    }
+}
+
+/// Secret of the commit of the juror at `index`
+fn juror_secret(env: &Env, index: u32) -> Bytes {
+    Bytes::from_array(env, &index.to_be_bytes())
+}
+
+/// Seat `voters` registered jurors who commit their votes
+fn commit_votes(setup: &TestSetup, dispute_id: u32, voters: u32) {
+    for index in 0..voters {
+        let juror = Address::generate(&setup.env);
+        setup.contract.new_voter(&juror);
+        setup.contract.register_to_vote(&juror, &dispute_id);
+
+        let vote = juror_choice(index) == VoteChoice::Creator;
+        let commit = compute_commit_hash(&setup.env, vote, &juror_secret(&setup.env, index));
+        setup.contract.commit_vote(&juror, &dispute_id, &commit);
+    }
+}
+
+/// Votes and secrets revealing the commits of the jurors from `first` to
+/// `last` excluded
+fn reveal_page(env: &Env, first: u32, last: u32) -> (Vec<bool>, Vec<Bytes>) {
+    let mut votes = Vec::new(env);
+    let mut secrets = Vec::new(env);
+    for index in first..last {
+        votes.push_back(juror_choice(index) == VoteChoice::Creator);
+        secrets.push_back(juror_secret(env, index));
+    }
+    (votes, secrets)
+}
+
+/// Cast anonymous votes for the creator from `voters` new voters, then prove
+/// and execute the tallies, checking the cost of the last vote, the proof
+/// and the ruling.
+fn assert_anonymous_dispute_cost(voters: u32, vote: Ceiling, proof: Ceiling, execute: Ceiling) {
+    let setup = create_test_data();
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+    let dispute_id = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Anonymous,
+            ..DisputeParams::new(&setup)
+        },
+    )
+    .dispute_id;
+
+    // every voter casts the same commitments, built once
+    let VoteAnon::AnonymousVote(template) = build_anonymous_vote(
+        &setup,
+        &setup.judge1,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128],
+    ) else {
+        unreachable!()
+    };
+    for _ in 0..voters {
+        let voter = Address::generate(&setup.env);
+        setup.contract.new_voter(&voter);
+        setup.contract.register_to_vote(&voter, &dispute_id);
+        let mut vote_ = template.clone();
+        vote_.address = voter.clone();
+        setup
+            .contract
+            .vote(&voter, &dispute_id, &VoteAnon::AnonymousVote(vote_));
+    }
+    assert_cost(&setup.env, "anonymous vote", voters, vote);
+
+    let count = voters as u128;
+    let tallies = vec![&setup.env, count, 0u128, 0u128];
+    let seeds = vec![&setup.env, count, 2 * count, 3 * count];
+    assert!(setup.contract.proof(&dispute_id, &tallies, &seeds));
+    assert_cost(&setup.env, "proof", voters, proof);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute_id, &Some(tallies), &Some(seeds));
+    assert_cost(&setup.env, "anonymous execute", voters, execute);
+}
+
+#[test]
+fn test_public_vote_cost() {
+    let setup = create_test_data();
+    let dispute_id = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Public,
+            ..DisputeParams::new(&setup)
+        },
+    )
+    .dispute_id;
+
+    // the same dispute gets more votes between the measures
+    cast_public_votes(&setup, dispute_id, 0, 10);
+    assert_cost(
+        &setup.env,
+        "vote",
+        10,
+        Ceiling {
+            instructions: 1_050_000,
+            mem_bytes: 234_000,
+            footprint: 17,
+            write_entries: 6,
+        },
+    );
+
+    cast_public_votes(&setup, dispute_id, 10, MAX_JURORS);
+    assert_cost(
+        &setup.env,
+        "vote",
+        MAX_JURORS,
+        Ceiling {
+            instructions: 4_240_000,
+            mem_bytes: 1_560_000,
+            footprint: 17,
+            write_entries: 6,
+        },
+    );
+}
+
+#[test]
+fn test_anonymous_dispute_cost() {
+    assert_anonymous_dispute_cost(
+        10,
+        Ceiling {
+            instructions: 12_400_000,
+            mem_bytes: 242_000,
+            footprint: 17,
+            write_entries: 6,
+        },
+        Ceiling {
+            instructions: 22_300_000,
+            mem_bytes: 33_000,
+            footprint: 2,
+            write_entries: 0,
+        },
+        Ceiling {
+            instructions: 22_700_000,
+            mem_bytes: 127_000,
+            footprint: 6,
+            write_entries: 3,
+        },
+    );
+    assert_anonymous_dispute_cost(
+        MAX_JURORS,
+        Ceiling {
+            instructions: 15_500_000,
+            mem_bytes: 1_560_000,
+            footprint: 17,
+            write_entries: 6,
+        },
+        Ceiling {
+            instructions: 22_300_000,
+            mem_bytes: 127_000,
+            footprint: 2,
+            write_entries: 0,
+        },
+        Ceiling {
+            instructions: 23_900_000,
+            mem_bytes: 692_000,
+            footprint: 6,
+            write_entries: 3,
+        },
+    );
+}
+
+#[test]
+fn test_jury_capped_at_measured_size() {
+    let setup = create_test_data();
+    let caller = Address::generate(&setup.env);
+    let template = DisputeTemplate {
+        court_id: None,
+        juror_count: MAX_JURORS,
+        voting_duration: setup.voting_duration,
+        voting_mode: VotingMode::Public,
+        arbitration_fee: None,
+        ruling_options: DISPUTE_OPTIONS,
+        token: None,
+    };
+
+    // larger juries are not measured, so they cannot be seated
+    for juror_count in [MAX_JURORS + 1, 1000] {
+        let result = setup.contract.try_register_caller(
+            &setup.contract_admin,
+            &caller,
+            &DisputeTemplate {
+                juror_count,
+                ..template.clone()
+            },
+        );
+        assert_eq!(result, Err(Ok(Error::VoteLimitExceeded)));
+    }
+    setup
+        .contract
+        .register_caller(&setup.contract_admin, &caller, &template);
+
+    // disputes of unregistered callers seat the measured jury at most
+    let (setup, dispute_id) = vote_public_dispute(MAX_JURORS);
+    let voter = Address::generate(&setup.env);
+    setup.env.ledger().set_timestamp(setup.voting_ends_at - 1);
+    setup.contract.new_voter(&voter);
+    let result = setup.contract.try_register_to_vote(&voter, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::VoteLimitExceeded))));
+}
+
+/// Native contract registered again on a reloaded ledger, where it was
+/// already constructed
+struct Reloaded;
+
+impl ContractFunctionSet for Reloaded {
+    fn call(&self, func: &str, env: Env, args: &[Val]) -> Option<Val> {
+        if func == "__constructor" {
+            return Some(Val::VOID.into());
+        }
+        ProtocolContract.call(func, env, args)
+    }
+}
+
+/// The given address in another environment
+fn same_address(env: &Env, address: &Address) -> Address {
+    Address::from_str(env, &std::format!("{}", address.to_string()))
+}
+
+/// Load the test ledger in a new environment, which only holds the entries
+/// accessed by the next invocations.
+fn reload(setup: &TestSetup) -> (Env, ProtocolContractClient<'static>) {
+    let env = Env::from_ledger_snapshot(setup.env.to_ledger_snapshot());
+    env.mock_all_auths();
+    let contract_id = env.register_at(&same_address(&env, &setup.contract_id), Reloaded, ());
+    let contract = ProtocolContractClient::new(&env, &contract_id);
+    (env, contract)
+}
+
+/// Open a public dispute with `voters` votes, at the end of its voting period.
+fn vote_public_dispute(voters: u32) -> (TestSetup, u32) {
+    let setup = create_test_data();
+    // on the test ledger, setting up a large jury exceeds the network limits
+    setup.env.cost_estimate().budget().reset_unlimited();
+    let dispute_id = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Public,
+            ..DisputeParams::new(&setup)
+        },
+    )
+    .dispute_id;
+    cast_public_votes(&setup, dispute_id, 0, voters);
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    (setup, dispute_id)
+}
+
+/// Rule on a public dispute with `voters` votes and settle its jurors,
+/// checking the cost of the ruling and of the last page of jurors, which do
+/// not depend on the jury.
+fn assert_public_ruling_cost(voters: u32) {
+    let (setup, dispute_id) = vote_public_dispute(voters);
+    let (env, contract) = reload(&setup);
+    contract.execute(
+        &same_address(&env, &setup.creator),
+        &dispute_id,
+        &None,
+        &None,
+    );
+    assert_cost(
+        &env,
+        "public execute",
+        voters,
+        Ceiling {
+            instructions: 368_000,
+            mem_bytes: 51_000,
+            footprint: 6,
+            write_entries: 3,
+        },
+    );
+
+    setup
+        .contract
+        .execute(&setup.creator, &dispute_id, &None, &None);
+    for _ in 1..voters.div_ceil(SETTLE_PAGE_SIZE) {
+        setup.contract.settle_jurors(&dispute_id, &SETTLE_PAGE_SIZE);
+    }
+    let (env, contract) = reload(&setup);
+    contract.settle_jurors(&dispute_id, &SETTLE_PAGE_SIZE);
+    assert_cost(
+        &env,
+        "settle_jurors",
+        voters,
+        Ceiling {
+            instructions: 3_240_000,
+            mem_bytes: 420_000,
+            footprint: 47,
+            write_entries: 24,
+        },
+    );
+}
+
+/// Commit the votes of a commit-reveal dispute with `voters` seated jurors
+/// and reveal them page by page, checking the cost of the last page, which
+/// resolves the dispute and does not depend on the jury.
+fn assert_reveal_cost(voters: u32) {
+    let setup = create_test_data();
+    // on the test ledger, setting up a large jury exceeds the network limits
+    setup.env.cost_estimate().budget().reset_unlimited();
+    let dispute_id = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::CommitReveal,
+            ..DisputeParams::new(&setup)
+        },
+    )
+    .dispute_id;
+    commit_votes(&setup, dispute_id, voters);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let last_page = voters - MAX_REVEALS_PER_CALL;
+    for first in (0..last_page).step_by(MAX_REVEALS_PER_CALL as usize) {
+        let (votes, secrets) = reveal_page(&setup.env, first, first + MAX_REVEALS_PER_CALL);
+        setup
+            .contract
+            .reveal_votes(&setup.creator, &dispute_id, &votes, &secrets);
+    }
+
+    let (env, contract) = reload(&setup);
+    let (votes, secrets) = reveal_page(&env, last_page, voters);
+    contract.reveal_votes(
+        &same_address(&env, &setup.creator),
+        &dispute_id,
+        &votes,
+        &secrets,
+    );
+    assert_cost(
+        &env,
+        "reveal_votes",
+        voters,
+        Ceiling {
+            instructions: 3_510_000,
+            mem_bytes: 533_000,
+            footprint: 65,
+            write_entries: 23,
+        },
+    );
+}
+
+#[test]
+fn test_public_ruling_cost_independent_of_jury() {
+    // the ruling only reads the running tally, jurors are settled in pages
+    assert_public_ruling_cost(10);
+    assert_public_ruling_cost(MAX_JURORS);
+}
+
+#[test]
+fn test_reveal_votes_cost_per_page() {
+    assert_reveal_cost(MAX_JURORS);
+}
+
+/// Extend the last page of a public dispute with `voters` votes and read its
+/// full view, which do not depend on the jury.
+fn assert_dispute_upkeep_cost(voters: u32) {
+    let (setup, dispute_id) = vote_public_dispute(voters);
+
+    let (env, contract) = reload(&setup);
+    contract.bump_dispute(
+        &dispute_id,
+        &(voters - DISPUTE_BUMP_PAGE_SIZE),
+        &DISPUTE_BUMP_PAGE_SIZE,
+    );
+    assert_cost(
+        &env,
+        "bump_dispute",
+        voters,
+        Ceiling {
+            instructions: 2_650_000,
+            mem_bytes: 350_000,
+            footprint: 64,
+            write_entries: 0,
+        },
+    );
+
+    let (env, contract) = reload(&setup);
+    contract.get_dispute(&dispute_id);
+    assert_cost(
+        &env,
+        "get_dispute",
+        voters,
+        Ceiling {
+            instructions: 2_310_000,
+            mem_bytes: 323_000,
+            footprint: 62,
+            write_entries: 0,
+        },
+    );
+}
+
+#[test]
+fn test_dispute_upkeep_cost_independent_of_jury() {
+    assert_dispute_upkeep_cost(MAX_JURORS);
+}
+
+#[test]
+fn test_claim_rewards_cost_per_batch() {
+    let setup = create_test_data();
+    setup.env.cost_estimate().budget().reset_unlimited();
+    setup
+        .contract
+        .set_emission_schedule(&setup.contract_admin, &1, &(7 * 24 * 3600));
+    setup.contract.new_voter(&setup.judge1);
+
+    // the juror is the whole coherent jury, so each claim also closes the
+    // pools and credits the emission weight
+    let mut dispute_ids = Vec::new(&setup.env);
+    for _ in 0..MAX_CLAIMS_PER_CALL {
+        let dispute_id = open_dispute(
+            &setup,
+            &DisputeParams {
+                fee_paid: true,
+                ..DisputeParams::with_fee(&setup)
+            },
+        )
+        .dispute_id;
+        seat_and_vote(&setup, &setup.judge1, dispute_id, 1, VoteChoice::Creator);
+        dispute_ids.push_back(dispute_id);
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    for dispute_id in dispute_ids.iter() {
+        setup
+            .contract
+            .execute(&setup.creator, &dispute_id, &None, &None);
+    }
+    end_appeal_period(&setup);
+    for dispute_id in dispute_ids.iter() {
+        settle_all_jurors(&setup, dispute_id);
+    }
+
+    let (env, contract) = reload(&setup);
+    let mut batch = Vec::new(&env);
+    batch.extend(dispute_ids.iter());
+    contract.claim_rewards(&same_address(&env, &setup.judge1), &batch);
+    assert_cost(
+        &env,
+        "claim_rewards",
+        MAX_CLAIMS_PER_CALL,
+        Ceiling {
+            instructions: 14_900_000,
+            mem_bytes: 2_100_000,
+            footprint: 69,
+            write_entries: 46,
+        },
+    );
+}
+
+/// Register `count` new voters, staking `stake` in the vault when positive
+fn register_voters(setup: &TestSetup, count: u32, stake: i128) -> Vec<Address> {
+    let mut voters = Vec::new(&setup.env);
+    for _ in 0..count {
+        let voter = Address::generate(&setup.env);
+        setup.contract.new_voter(&voter);
+        if stake > 0 {
+            setup.token_stellar.mint(&voter, &stake);
+            setup.contract.stake(&voter, &stake);
+        }
+        voters.push_back(voter);
+    }
+    voters
+}
+
+/// Public ballot over two options in `env`, starting after its voters are
+/// settled
+fn ballot_config(env: &Env, setup: &TestSetup, eligibility: Eligibility) -> BallotConfig {
+    BallotConfig {
+        title: String::from_str(env, "ballot"),
+        options: vec![
+            env,
+            String::from_str(env, "yes"),
+            String::from_str(env, "no"),
+        ],
+        eligibility,
+        voting_mode: VotingMode::Public,
+        voting_starts_at: setup.env.ledger().timestamp() + 100,
+        voting_ends_at: setup.voting_ends_at,
+        quorum: 0,
+    }
+}
+
+#[test]
+fn test_ballot_voters_cost_per_page() {
+    let setup = create_test_data();
+    setup.env.cost_estimate().budget().reset_unlimited();
+    let voters = register_voters(&setup, 2 * MAX_LISTED_VOTERS_PER_CALL, 0);
+
+    let (env, contract) = reload(&setup);
+    let first_page = Vec::from_iter(
+        &env,
+        voters
+            .iter()
+            .take(MAX_LISTED_VOTERS_PER_CALL as usize)
+            .map(|voter| same_address(&env, &voter)),
+    );
+    contract.open_ballot(
+        &same_address(&env, &setup.creator),
+        &setup.project_id,
+        &ballot_config(&env, &setup, Eligibility::Voters(first_page)),
+    );
+    assert_cost(
+        &env,
+        "open_ballot",
+        MAX_LISTED_VOTERS_PER_CALL,
+        Ceiling {
+            instructions: 2_920_000,
+            mem_bytes: 430_000,
+            footprint: 45,
+            write_entries: 43,
+        },
+    );
+
+    let first_page = voters.slice(0..MAX_LISTED_VOTERS_PER_CALL);
+    setup.contract.open_ballot(
+        &setup.creator,
+        &setup.project_id,
+        &ballot_config(&setup.env, &setup, Eligibility::Voters(first_page)),
+    );
+    let (env, contract) = reload(&setup);
+    let last_page = Vec::from_iter(
+        &env,
+        voters
+            .iter()
+            .skip(MAX_LISTED_VOTERS_PER_CALL as usize)
+            .map(|voter| same_address(&env, &voter)),
+    );
+    contract.add_ballot_voters(&same_address(&env, &setup.creator), &1, &last_page);
+    assert_cost(
+        &env,
+        "add_ballot_voters",
+        MAX_LISTED_VOTERS_PER_CALL,
+        Ceiling {
+            instructions: 2_930_000,
+            mem_bytes: 434_000,
+            footprint: 45,
+            write_entries: 42,
+        },
+    );
+}
+
+#[test]
+fn test_ballot_draw_cost_independent_of_pool() {
+    let setup = create_test_data();
+    setup.env.cost_estimate().budget().reset_unlimited();
+    setup.contract.set_protocol_config(
+        &setup.contract_admin,
+        &ProtocolConfig {
+            evidence_duration: 24 * 3600,
+            juror_stake: 100,
+            ..ProtocolConfig::default()
+        },
+    );
+    // most of the pool is passed over, the costliest draw reads a new voter
+    // on every attempt and fails
+    register_voters(&setup, 100, 0);
+    register_voters(&setup, MAX_DRAWN_VOTERS_PER_CALL, 100);
+    setup.contract.open_ballot(
+        &setup.creator,
+        &setup.project_id,
+        &ballot_config(
+            &setup.env,
+            &setup,
+            Eligibility::JurorDraw(MAX_DRAWN_VOTERS_PER_CALL),
+        ),
+    );
+
+    let (env, contract) = reload(&setup);
+    let result = contract.try_draw_ballot_voters(
+        &same_address(&env, &setup.contract_admin),
+        &1,
+        &MAX_DRAWN_VOTERS_PER_CALL,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidEligibility)));
+    assert_cost(
+        &env,
+        "draw_ballot_voters",
+        MAX_DRAWN_VOTERS_PER_CALL,
+        Ceiling {
+            instructions: 3_340_000,
+            mem_bytes: 563_000,
+            footprint: 87,
+            write_entries: 5,
+        },
+    );
+}
+
+#[test]
+fn test_draw_jurors_cost_independent_of_pool() {
+    let setup = create_test_data();
+    setup.env.cost_estimate().budget().reset_unlimited();
+    setup.contract.set_eligibility_rules(
+        &setup.contract_admin,
+        &EligibilityRules {
+            exclude_past_counterparties: true,
+            exclude_delegators: true,
+            min_reputation: 0,
+        },
+    );
+    let court_id = setup
+        .contract
+        .create_court(
+            &setup.contract_admin,
+            &String::from_str(&setup.env, "court"),
+            &None,
+            &100,
+            &100,
+            &None,
+        )
+        .court_id;
+
+    // every sampled juror is kept by the weighting and checked against each
+    // rule, then passed over as they started unbonding their stake: the
+    // costliest draw reads a new juror on every attempt and fails
+    for juror in register_voters(&setup, 100, 100).iter() {
+        setup.contract.join_court(&juror, &court_id);
+        setup.contract.request_unstake(&juror, &100);
+        setup.env.as_contract(&setup.contract_id, || {
+            let voter = get_voter(&setup.env, juror.clone()).unwrap();
+            update_voter(&setup.env, voter, 0, 100);
+        });
+    }
+    setup.token_stellar.mint(&setup.creator, &100);
+    setup.token_stellar.mint(&setup.counterpart, &100);
+    let dispute_id = open_dispute(
+        &setup,
+        &DisputeParams {
+            court_id: Some(court_id),
+            fee_paid: true,
+            ..DisputeParams::new(&setup)
+        },
+    )
+    .dispute_id;
+
+    let (env, contract) = reload(&setup);
+    let result = contract.try_draw_jurors(
+        &same_address(&env, &setup.contract_admin),
+        &dispute_id,
+        &MAX_DRAWN_JURORS_PER_CALL,
+    );
+    assert!(matches!(result, Err(Ok(Error::InvalidEligibility))));
+    assert_cost(
+        &env,
+        "draw_jurors",
+        MAX_DRAWN_JURORS_PER_CALL,
+        Ceiling {
+            instructions: 4_000_000,
+            mem_bytes: 610_000,
+            footprint: 92,
+            write_entries: 1,
+        },
+    );
+}
+
+#[test]
+fn test_leaderboard_cost_with_full_index() {
+    let setup = create_test_data();
+    setup.env.cost_estimate().budget().reset_unlimited();
+    for (index, voter) in register_voters(&setup, LEADERBOARD_SIZE + 10, 0)
+        .iter()
+        .enumerate()
+    {
+        setup.env.as_contract(&setup.contract_id, || {
+            let voter = get_voter(&setup.env, voter).unwrap();
+            update_voter(&setup.env, voter, 0, index as i32 + 1);
+        });
+    }
+
+    let (env, contract) = reload(&setup);
+    let page = contract.get_leaderboard(&0, &LEADERBOARD_PAGE_SIZE);
+    assert_eq!(page.len(), LEADERBOARD_PAGE_SIZE);
+    assert_cost(
+        &env,
+        "get_leaderboard",
+        LEADERBOARD_SIZE,
+        Ceiling {
+            instructions: 2_040_000,
+            mem_bytes: 280_000,
+            footprint: 52,
+            write_entries: 0,
+        },
+    );
+}
@@ -0,0 +1,220 @@
+use soroban_sdk::{
+    Address, Bytes,
+    testutils::{Address as _, Ledger},
+    vec,
+};
+
+use crate::storage::config::ProtocolConfig;
+use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, compute_commit_hash, create_test_data, init_contract, open_dispute,
+    seat_and_vote, try_open_dispute,
+};
+
+const ONE_DAY: u64 = 24 * 3600;
+
+/// Open a public dispute, returning the end of its voting period
+fn try_open_public_dispute(setup: &TestSetup, voting_duration: u64) -> Result<u64, Error> {
+    let params = DisputeParams {
+        voting_duration,
+        ..DisputeParams::new(setup)
+    };
+    try_open_dispute(setup, &params).map(|dispute| dispute.voting_ends_at)
+}
+
+#[test]
+fn test_voting_duration_within_bounds() {
+    let setup = create_test_data();
+    setup.env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        setup.contract.get_protocol_config(),
+        ProtocolConfig::default()
+    );
+
+    assert_eq!(
+        try_open_public_dispute(&setup, ONE_DAY - 1),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        try_open_public_dispute(&setup, 30 * ONE_DAY + 1),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        try_open_public_dispute(&setup, ONE_DAY),
+        Ok(1_000 + ONE_DAY)
+    );
+
+    let config = ProtocolConfig {
+        min_voting_duration: 7 * ONE_DAY,
+        max_voting_duration: 60 * ONE_DAY,
+        evidence_duration: ONE_DAY,
+        reveal_duration: ONE_DAY,
+        ..ProtocolConfig::default()
+    };
+    let result = setup
+        .contract
+        .try_set_protocol_config(&setup.creator, &config);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    for invalid in [
+        ProtocolConfig {
+            min_voting_duration: 61 * ONE_DAY,
+            ..config.clone()
+        },
+        ProtocolConfig {
+            evidence_duration: 8 * ONE_DAY,
+            ..config.clone()
+        },
+        ProtocolConfig {
+            reveal_duration: 0,
+            ..config.clone()
+        },
+    ] {
+        let result = setup
+            .contract
+            .try_set_protocol_config(&setup.contract_admin, &invalid);
+        assert_eq!(result, Err(Ok(Error::InvalidDuration)));
+    }
+
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+    assert_eq!(
+        try_open_public_dispute(&setup, ONE_DAY),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        try_open_public_dispute(&setup, 60 * ONE_DAY),
+        Ok(1_000 + 60 * ONE_DAY)
+    );
+}
+
+#[test]
+fn test_fee_deadline_follows_evidence_duration() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_duration: 10 * ONE_DAY,
+            ..DisputeParams::with_fee(&setup)
+        },
+    );
+    assert_eq!(dispute.fee_deadline, 3 * ONE_DAY);
+}
+
+#[test]
+fn test_unrevealed_votes_closed_without_ruling() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    assert_eq!(dispute.reveal_ends_at, setup.voting_ends_at + 2 * ONE_DAY);
+
+    let secret = Bytes::from_slice(&setup.env, b"secret");
+    setup.contract.commit_vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, true, &secret),
+    );
+
+    // the creator can still reveal
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let result = setup
+        .contract
+        .try_execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert!(result.is_err());
+
+    setup.env.ledger().set_timestamp(dispute.reveal_ends_at);
+    let result = setup.contract.try_reveal_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, true],
+        &vec![&setup.env, secret],
+    );
+    assert!(matches!(result, Err(Ok(Error::ProposalVotingTime))));
+
+    let outcome = setup
+        .contract
+        .execute(&setup.counterpart, &dispute.dispute_id, &None, &None);
+    assert_eq!(outcome, DisputeStatus::ABSTAIN);
+    let closed = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(closed.dispute_status, DisputeStatus::EXECUTED);
+    assert_eq!(closed.winner, None);
+}
+
+#[test]
+fn test_creation_limits() {
+    let setup = create_test_data();
+    let config = ProtocolConfig {
+        evidence_duration: ONE_DAY,
+        max_open_disputes: 1,
+        dispute_cooldown: ONE_DAY,
+        ..ProtocolConfig::default()
+    };
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+
+    let voting_ends_at = try_open_public_dispute(&setup, ONE_DAY).unwrap();
+    assert_eq!(
+        try_open_public_dispute(&setup, ONE_DAY),
+        Err(Error::InsufficientTime)
+    );
+
+    setup.env.ledger().set_timestamp(voting_ends_at);
+    assert_eq!(
+        try_open_public_dispute(&setup, ONE_DAY),
+        Err(Error::TooManyOpenDisputes)
+    );
+
+    // the ruling frees a slot
+    setup.contract.execute(&setup.creator, &1, &None, &None);
+    assert!(try_open_public_dispute(&setup, ONE_DAY).is_ok());
+}
+
+#[test]
+fn test_creation_bond_forfeited_to_counterpart() {
+    let setup = create_test_data();
+    let config = ProtocolConfig {
+        evidence_duration: ONE_DAY,
+        creation_bond: 100,
+        ..ProtocolConfig::default()
+    };
+    let result = setup.contract.try_set_protocol_config(
+        &setup.contract_admin,
+        &ProtocolConfig {
+            creation_bond: -1,
+            ..config.clone()
+        },
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+
+    for (dispute_id, choice) in [(1, VoteChoice::Creator), (2, VoteChoice::Counterpart)] {
+        setup.token_stellar.mint(&setup.creator, &100);
+        let voting_ends_at = try_open_public_dispute(&setup, ONE_DAY).unwrap();
+        let judge = Address::generate(&setup.env);
+        setup.contract.new_voter(&judge);
+        seat_and_vote(&setup, &judge, dispute_id, 1, choice);
+
+        setup.env.ledger().set_timestamp(voting_ends_at);
+        setup
+            .contract
+            .execute(&setup.creator, &dispute_id, &None, &None);
+    }
+
+    // refunded after the first ruling, forfeited after the second
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.creator, &setup.token_stellar.address),
+        100
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.counterpart, &setup.token_stellar.address),
+        100
+    );
+}
@@ -0,0 +1,398 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String, token, vec};
+
+use crate::methods::court::MAX_DRAWN_JURORS_PER_CALL;
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::storage::voter::{get_voter, update_voter};
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, build_public_vote, create_test_data, open_dispute,
+};
+
+const STAKE: i128 = 500;
+
+fn create_court(setup: &TestSetup, name: &str, parent: Option<u32>, min_stake: i128) -> u32 {
+    setup
+        .contract
+        .create_court(
+            &setup.contract_admin,
+            &String::from_str(&setup.env, name),
+            &parent,
+            &100,
+            &min_stake,
+            &None,
+        )
+        .court_id
+}
+
+/// Register a voter, stake in the vault and join a court
+fn join(setup: &TestSetup, juror: &Address, court_id: u32) {
+    setup.contract.new_voter(juror);
+    setup.token_stellar.mint(juror, &STAKE);
+    setup.contract.stake(juror, &STAKE);
+    setup.contract.join_court(juror, &court_id);
+}
+
+#[test]
+fn test_create_court() {
+    let setup = create_test_data();
+    let root = create_court(&setup, "development", None, STAKE);
+    let child = create_court(&setup, "smart contracts", Some(root), STAKE);
+
+    let court = setup.contract.get_court(&child);
+    assert_eq!(court.parent, Some(root));
+    assert_eq!(court.fee, 100);
+    assert_eq!(court.min_stake, STAKE);
+    assert_eq!(court.juror_count, 0);
+
+    let name = String::from_str(&setup.env, "design");
+    assert_eq!(
+        setup
+            .contract
+            .try_create_court(&setup.creator, &name, &None, &100, &STAKE, &None),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        setup.contract.try_create_court(
+            &setup.contract_admin,
+            &name,
+            &Some(9),
+            &100,
+            &STAKE,
+            &None
+        ),
+        Err(Ok(Error::CourtNotFound))
+    );
+    // a child court cannot be cheaper to join than its parent
+    assert_eq!(
+        setup
+            .contract
+            .try_create_court(&setup.contract_admin, &name, &Some(root), &100, &1, &None),
+        Err(Ok(Error::InsufficientStake))
+    );
+    assert_eq!(
+        setup.contract.try_get_court(&9),
+        Err(Ok(Error::CourtNotFound))
+    );
+}
+
+#[test]
+fn test_join_court_stakes_in_parent_pools() {
+    let setup = create_test_data();
+    let root = create_court(&setup, "development", None, STAKE);
+    let contracts = create_court(&setup, "smart contracts", Some(root), STAKE);
+    let frontend = create_court(&setup, "frontend", Some(root), STAKE);
+
+    assert_eq!(
+        setup.contract.try_join_court(&setup.judge1, &root),
+        Err(Ok(Error::UserNotFound))
+    );
+    setup.contract.new_voter(&setup.judge1);
+    setup.token_stellar.mint(&setup.judge1, &STAKE);
+    setup.contract.stake(&setup.judge1, &(STAKE - 1));
+    assert_eq!(
+        setup.contract.try_join_court(&setup.judge1, &root),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    setup.contract.stake(&setup.judge1, &1);
+    assert_eq!(setup.contract.join_court(&setup.judge1, &contracts), STAKE);
+    assert_eq!(
+        setup.contract.try_join_court(&setup.judge1, &contracts),
+        Err(Ok(Error::JudgeAlreadyVoted))
+    );
+    assert!(setup.contract.is_court_member(&contracts, &setup.judge1));
+    assert!(!setup.contract.is_court_member(&root, &setup.judge1));
+    assert!(setup.contract.is_court_juror(&contracts, &setup.judge1));
+    assert!(setup.contract.is_court_juror(&root, &setup.judge1));
+    assert!(!setup.contract.is_court_juror(&frontend, &setup.judge1));
+
+    // member of two child courts, the juror sits once in the parent
+    setup.contract.join_court(&setup.judge1, &frontend);
+    join(&setup, &setup.judge2, root);
+    assert_eq!(setup.contract.get_court(&root).juror_count, 2);
+
+    // the stake stays in the vault
+    setup.contract.leave_court(&setup.judge1, &contracts);
+    assert_eq!(setup.contract.get_stake(&setup.judge1).free, STAKE);
+    assert!(!setup.contract.is_court_juror(&contracts, &setup.judge1));
+    assert!(setup.contract.is_court_juror(&root, &setup.judge1));
+    assert_eq!(
+        setup.contract.try_leave_court(&setup.judge1, &contracts),
+        Err(Ok(Error::JudgeNotFound))
+    );
+
+    setup.contract.leave_court(&setup.judge1, &frontend);
+    assert!(!setup.contract.is_court_juror(&root, &setup.judge1));
+    assert!(setup.contract.is_court_juror(&root, &setup.judge2));
+    assert_eq!(setup.contract.get_court(&root).juror_count, 1);
+    assert_eq!(setup.contract.get_court(&contracts).juror_count, 0);
+}
+
+#[test]
+fn test_court_dispute_draws_from_pool() {
+    let setup = create_test_data();
+    let root = create_court(&setup, "development", None, STAKE);
+    let contracts = create_court(&setup, "smart contracts", Some(root), STAKE);
+    join(&setup, &setup.judge1, contracts);
+    join(&setup, &setup.judge2, contracts);
+    join(&setup, &setup.counterpart, contracts);
+    // only sits in the parent court
+    join(&setup, &setup.judge3, root);
+
+    assert_eq!(setup.contract.arbitration_cost(&Some(contracts)), 100);
+    setup.token_stellar.mint(&setup.creator, &100);
+    setup.token_stellar.mint(&setup.counterpart, &100);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            court_id: Some(contracts),
+            ..DisputeParams::new(&setup)
+        },
+    );
+    assert_eq!(dispute.court_id, Some(contracts));
+    assert_eq!(dispute.arbitration_fee, 100);
+
+    assert!(matches!(
+        setup
+            .contract
+            .try_draw_jurors(&setup.contract_admin, &dispute.dispute_id, &2),
+        Err(Ok(Error::ArbitrationFeeNotPaid))
+    ));
+    setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+
+    // court disputes only seat drawn jurors
+    assert!(matches!(
+        setup
+            .contract
+            .try_register_to_vote(&setup.judge1, &dispute.dispute_id),
+        Err(Ok(Error::NotEligible))
+    ));
+    // only the moderators can draw, the creator could grind for a jury
+    assert!(matches!(
+        setup
+            .contract
+            .try_draw_jurors(&setup.creator, &dispute.dispute_id, &2),
+        Err(Ok(Error::NotAuthorized))
+    ));
+    assert!(matches!(
+        setup.contract.try_draw_jurors(
+            &setup.contract_admin,
+            &dispute.dispute_id,
+            &(MAX_DRAWN_JURORS_PER_CALL + 1)
+        ),
+        Err(Ok(Error::BatchTooLarge))
+    ));
+    // the counterpart sits in the pool but is never drawn
+    assert!(matches!(
+        setup
+            .contract
+            .try_draw_jurors(&setup.contract_admin, &dispute.dispute_id, &3),
+        Err(Ok(Error::InvalidEligibility))
+    ));
+
+    let drawn = setup
+        .contract
+        .draw_jurors(&setup.contract_admin, &dispute.dispute_id, &2);
+    assert_eq!(drawn.juror_count, 2);
+    let jurors = setup
+        .contract
+        .get_dispute_jurors(&dispute.dispute_id, &0, &10);
+    assert!(jurors.contains(&setup.judge1));
+    assert!(jurors.contains(&setup.judge2));
+
+    // leaving the court does not release the stake of a seated juror
+    setup.contract.leave_court(&setup.judge1, &contracts);
+    assert_eq!(setup.contract.get_stake(&setup.judge1).locked, STAKE);
+    assert_eq!(
+        setup.contract.try_request_unstake(&setup.judge1, &STAKE),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_dispute(&dispute.dispute_id)
+            .vote_data
+            .votes,
+        vec![
+            &setup.env,
+            build_public_vote(&setup.judge1, 1, VoteChoice::Creator)
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_court_dispute_rejects_undrawn_voter() {
+    let setup = create_test_data();
+    let court_id = create_court(&setup, "design", None, 0);
+    setup.token_stellar.mint(&setup.creator, &100);
+    setup.token_stellar.mint(&setup.counterpart, &100);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            court_id: Some(court_id),
+            fee_paid: true,
+            ..DisputeParams::new(&setup)
+        },
+    );
+
+    let outsider = Address::generate(&setup.env);
+    setup.contract.vote(
+        &outsider,
+        &dispute.dispute_id,
+        &build_public_vote(&outsider, 1, VoteChoice::Creator),
+    );
+}
+
+#[test]
+fn test_draw_weighted_by_reputation() {
+    let setup = create_test_data();
+    let court_id = create_court(&setup, "design", None, 0);
+    join(&setup, &setup.judge1, court_id);
+    join(&setup, &setup.judge2, court_id);
+    // judge1 is at the reputation cap, twice as likely to sit as judge2
+    setup.env.as_contract(&setup.contract_id, || {
+        let voter = get_voter(&setup.env, setup.judge1.clone()).unwrap();
+        update_voter(&setup.env, voter, 0, 100);
+    });
+
+    let mut seats = [0, 0];
+    for _ in 0..45 {
+        setup.token_stellar.mint(&setup.creator, &100);
+        setup.token_stellar.mint(&setup.counterpart, &100);
+        let dispute = open_dispute(
+            &setup,
+            &DisputeParams {
+                court_id: Some(court_id),
+                fee_paid: true,
+                ..DisputeParams::new(&setup)
+            },
+        );
+        // a draw passing over every sampled juror can be retried later
+        if setup
+            .contract
+            .try_draw_jurors(&setup.contract_admin, &dispute.dispute_id, &1)
+            .is_err()
+        {
+            continue;
+        }
+        let jurors = setup
+            .contract
+            .get_dispute_jurors(&dispute.dispute_id, &0, &1);
+        if jurors.contains(&setup.judge1) {
+            seats[0] += 1;
+        } else {
+            seats[1] += 1;
+        }
+    }
+    assert!(seats[1] > 0);
+    assert!(seats[0] > seats[1]);
+}
+
+#[test]
+fn test_draw_requires_court_dispute() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    assert!(matches!(
+        setup
+            .contract
+            .try_draw_jurors(&setup.contract_admin, &dispute.dispute_id, &1),
+        Err(Ok(Error::CourtNotFound))
+    ));
+}
+
+#[test]
+fn test_court_pays_in_its_token() {
+    let setup = create_test_data();
+    let usdc = setup
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&setup.env));
+    let usdc_admin = token::StellarAssetClient::new(&setup.env, &usdc.address());
+    let name = String::from_str(&setup.env, "payments");
+
+    assert!(
+        setup
+            .contract
+            .is_token_allowed(&setup.token_stellar.address)
+    );
+    assert!(!setup.contract.is_token_allowed(&usdc.address()));
+    assert!(matches!(
+        setup.contract.try_create_court(
+            &setup.contract_admin,
+            &name,
+            &None,
+            &100,
+            &0,
+            &Some(usdc.address()),
+        ),
+        Err(Ok(Error::NotAuthorized))
+    ));
+    assert_eq!(
+        setup
+            .contract
+            .try_allow_token(&setup.creator, &usdc.address(), &true),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    setup
+        .contract
+        .allow_token(&setup.contract_admin, &usdc.address(), &true);
+    let court = setup.contract.create_court(
+        &setup.contract_admin,
+        &name,
+        &None,
+        &100,
+        &0,
+        &Some(usdc.address()),
+    );
+    assert_eq!(court.token, usdc.address());
+
+    usdc_admin.mint(&setup.creator, &100);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            court_id: Some(court.court_id),
+            ..DisputeParams::new(&setup)
+        },
+    );
+    assert_eq!(dispute.token, usdc.address());
+
+    setup.env.ledger().set_timestamp(dispute.fee_deadline);
+    setup
+        .contract
+        .claim_default_ruling(&setup.creator, &dispute.dispute_id);
+
+    // the refund is owed in the court's token only
+    let protocol_token = &setup.token_stellar.address;
+    assert_eq!(
+        setup.contract.get_balance(&setup.creator, protocol_token),
+        0
+    );
+    assert_eq!(
+        setup.contract.get_balance(&setup.creator, &usdc.address()),
+        100
+    );
+    assert_eq!(setup.contract.redeem(&setup.creator, &usdc.address()), 100);
+    let usdc_client = token::TokenClient::new(&setup.env, &usdc.address());
+    assert_eq!(usdc_client.balance(&setup.creator), 100);
+
+    // existing courts keep their token once it is disallowed
+    setup
+        .contract
+        .allow_token(&setup.contract_admin, &usdc.address(), &false);
+    assert!(!setup.contract.is_token_allowed(&usdc.address()));
+    assert_eq!(
+        setup.contract.get_court(&court.court_id).token,
+        usdc.address()
+    );
+}
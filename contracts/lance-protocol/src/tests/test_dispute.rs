@@ -1,7 +1,17 @@
-use soroban_sdk::{String, testutils::Address as _};
+use soroban_sdk::{
+    Bytes, String,
+    testutils::{Address as _, Ledger},
+    vec,
+};
 
+use crate::storage::dispute::JurorVote;
 use crate::storage::dispute_status::DisputeStatus;
-use crate::tests::test_utils::{create_test_data, init_contract};
+use crate::storage::vote::VotingMode;
+use crate::storage::storage::DataKey;
+use crate::tests::test_utils::{
+    build_anonymous_vote, compute_commit_hash, create_test_data, init_contract,
+    init_contract_with_mode,
+};
 
 #[test]
 fn test_create_dispute_success() {
@@ -14,8 +24,10 @@ fn test_create_dispute_success() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     assert_eq!(dispute.dispute_id, 1);
@@ -42,8 +54,10 @@ fn test_create_multiple_disputes() {
         &setup.creator,
         &setup.counterpart,
         &proof1,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     let new_creator = soroban_sdk::Address::generate(&setup.env);
@@ -54,8 +68,10 @@ fn test_create_multiple_disputes() {
         &new_creator,
         &new_counterpart,
         &proof2,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     assert_eq!(dispute1.dispute_id, 1);
@@ -72,9 +88,8 @@ fn test_dispute_initial_state() {
     assert_eq!(dispute.dispute_status, DisputeStatus::OPEN);
     // init_contract already calls register_to_vote for judge1, but that doesn't increment able_to_vote
     // Check that we have at least 0 voters initially (before registration)
-    assert_eq!(dispute.voters.len(), 0); // No commits yet
-    assert_eq!(dispute.vote_commits.len(), 0);
-    assert_eq!(dispute.votes.len(), 0);
+    assert_eq!(dispute.commit_count, 0); // No commits yet
+    assert_eq!(dispute.vote_count, 0);
     assert!(!dispute.waiting_for_judges);
 }
 
@@ -90,10 +105,100 @@ fn test_dispute_timestamps() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     assert_eq!(dispute.initial_timestamp, initial_time);
     assert!(dispute.finish_timestamp.is_none());
 }
+
+#[test]
+fn test_dispute_stored_in_persistent_storage() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    setup.env.as_contract(&setup.contract_id, || {
+        let storage = setup.env.storage();
+        assert!(storage.persistent().has(&DataKey::Disputes(dispute.dispute_id)));
+        assert!(!storage.instance().has(&DataKey::Disputes(dispute.dispute_id)));
+        assert!(
+            storage
+                .persistent()
+                .has(&DataKey::DisputeJuror(dispute.dispute_id, setup.judge1.clone()))
+        );
+    });
+}
+
+#[test]
+fn test_get_dispute_assembles_juror_entries() {
+    let setup = create_test_data();
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
+
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+
+    let vote_ = build_anonymous_vote(
+        &setup,
+        &setup.judge2,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128],
+    );
+    setup
+        .contract
+        .vote(&setup.judge2, &dispute.dispute_id, &vote_);
+
+    let assembled = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(
+        assembled.able_to_vote,
+        vec![&setup.env, setup.judge1.clone(), setup.judge2.clone()]
+    );
+    assert_eq!(assembled.voters.len(), 0);
+    assert_eq!(assembled.votes.len(), 0);
+    assert_eq!(assembled.vote_data.votes, vec![&setup.env, vote_]);
+    assert_eq!(assembled.vote_data.voting_ends_at, setup.voting_ends_at);
+    assert_eq!(assembled.vote_data.voting_mode, VotingMode::Anonymous);
+}
+
+#[test]
+fn test_get_dispute_assembles_commits_and_reveals() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    let secret = Bytes::from_slice(&setup.env, b"secret_1");
+    let commit_hash = compute_commit_hash(&setup.env, false, &secret);
+    setup
+        .contract
+        .commit_vote(&setup.judge1, &dispute.dispute_id, &commit_hash);
+
+    let committed = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(committed.voters, vec![&setup.env, setup.judge1.clone()]);
+    assert_eq!(committed.vote_commits, vec![&setup.env, commit_hash]);
+    assert_eq!(committed.votes.len(), 0);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let revealed = setup.contract.reveal_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, false],
+        &vec![&setup.env, secret],
+    );
+    assert_eq!(revealed.votes_against, 1);
+    let votes = setup
+        .contract
+        .get_dispute_votes(&dispute.dispute_id, &0, &10);
+    let JurorVote::Commit(committed) = votes.get(0).unwrap() else {
+        panic!("expected a commit");
+    };
+    assert_eq!(committed.juror, setup.judge1);
+    assert_eq!(committed.reveal, Some(false));
+}
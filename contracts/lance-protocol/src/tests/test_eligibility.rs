@@ -0,0 +1,224 @@
+use soroban_sdk::{Address, testutils::Address as _};
+
+use crate::methods::eligibility::MAX_DELEGATES;
+use crate::storage::eligibility::EligibilityRules;
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::storage::voter::{get_voter, update_voter};
+use crate::tests::test_utils::{DisputeParams, build_public_vote, create_test_data, open_dispute};
+
+#[test]
+fn test_default_rules() {
+    let setup = create_test_data();
+
+    assert_eq!(
+        setup.contract.get_eligibility_rules(),
+        EligibilityRules {
+            exclude_past_counterparties: true,
+            exclude_delegators: true,
+            min_reputation: 0,
+        }
+    );
+
+    let result = setup
+        .contract
+        .try_set_eligibility_rules(&setup.creator, &EligibilityRules::default());
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let result = setup
+        .contract
+        .try_suspend_voter(&setup.creator, &setup.judge1, &None);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_parties_cannot_judge() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.new_voter(&setup.counterpart);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.counterpart, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+    assert_eq!(
+        setup
+            .contract
+            .try_check_juror(&dispute.dispute_id, &setup.creator),
+        Err(Ok(Error::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_past_counterparty_is_conflicted() {
+    let setup = create_test_data();
+    // judge1 had a dispute with the counterpart before
+    open_dispute(
+        &setup,
+        &DisputeParams {
+            creator: setup.judge1.clone(),
+            ..DisputeParams::new(&setup)
+        },
+    );
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.new_voter(&setup.judge1);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ConflictOfInterest))));
+
+    // the rule can be turned off by the admin
+    setup.contract.set_eligibility_rules(
+        &setup.contract_admin,
+        &EligibilityRules {
+            exclude_past_counterparties: false,
+            ..EligibilityRules::default()
+        },
+    );
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+}
+
+#[test]
+fn test_delegator_of_party_is_rejected() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.new_voter(&setup.creator);
+    setup.contract.new_voter(&setup.judge1);
+    setup.contract.delegate_vote(&setup.judge1, &setup.creator);
+    assert_eq!(
+        setup
+            .contract
+            .try_check_juror(&dispute.dispute_id, &setup.judge1),
+        Err(Ok(Error::DelegatesToParty))
+    );
+
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::DelegatesToParty))));
+
+    // delegating to anyone else is fine
+    setup.contract.new_voter(&setup.judge3);
+    setup.contract.delegate_vote(&setup.judge2, &setup.judge3);
+    assert_eq!(
+        setup
+            .contract
+            .try_check_juror(&dispute.dispute_id, &setup.judge2),
+        Ok(Ok(()))
+    );
+}
+
+#[test]
+fn test_delegation_limits() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+    setup.contract.new_voter(&setup.creator);
+
+    setup.contract.delegate_vote(&setup.judge1, &setup.creator);
+    assert_eq!(
+        setup
+            .contract
+            .try_delegate_vote(&setup.judge1, &setup.creator),
+        Err(Ok(Error::JudgeAlreadyVoted))
+    );
+
+    // undelegating restores the juror's eligibility
+    setup
+        .contract
+        .undelegate_vote(&setup.judge1, &setup.creator);
+    assert_eq!(
+        setup
+            .contract
+            .try_check_juror(&dispute.dispute_id, &setup.judge1),
+        Ok(Ok(()))
+    );
+    assert_eq!(
+        setup
+            .contract
+            .try_undelegate_vote(&setup.judge1, &setup.creator),
+        Err(Ok(Error::VoterNotFound))
+    );
+
+    for _ in 0..MAX_DELEGATES {
+        setup
+            .contract
+            .delegate_vote(&Address::generate(&setup.env), &setup.creator);
+    }
+    assert_eq!(
+        setup
+            .contract
+            .try_delegate_vote(&setup.judge1, &setup.creator),
+        Err(Ok(Error::VoteLimitExceeded))
+    );
+}
+
+#[test]
+fn test_reputation_floor() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.set_eligibility_rules(
+        &setup.contract_admin,
+        &EligibilityRules {
+            min_reputation: 1,
+            ..EligibilityRules::default()
+        },
+    );
+
+    setup.contract.new_voter(&setup.judge1);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ReputationTooLow))));
+
+    // one coherent vote later
+    setup.env.as_contract(&setup.contract_id, || {
+        let voter = get_voter(&setup.env, setup.judge1.clone()).unwrap();
+        update_voter(&setup.env, voter, 0, 1);
+    });
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+}
+
+#[test]
+fn test_banned_voter_cannot_judge() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.new_voter(&setup.judge1);
+    setup
+        .contract
+        .suspend_voter(&setup.contract_admin, &setup.judge1, &None);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::VoterSuspended))));
+
+    setup
+        .contract
+        .reinstate_voter(&setup.contract_admin, &setup.judge1);
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #57)")]
+fn test_banned_voter_cannot_vote() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    let judge = Address::generate(&setup.env);
+    setup
+        .contract
+        .suspend_voter(&setup.contract_admin, &judge, &None);
+    setup.contract.vote(
+        &judge,
+        &dispute.dispute_id,
+        &build_public_vote(&judge, 1, VoteChoice::Creator),
+    );
+}
@@ -0,0 +1,262 @@
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{String, vec};
+
+use crate::methods::emission::EMISSION_CLAIM_PERIOD;
+use crate::storage::emission::Emissions;
+use crate::storage::error::Error;
+use crate::storage::vote::{VoteChoice, VotingMode};
+use crate::tests::test_utils::{
+    build_anonymous_vote, build_public_vote, create_test_data, end_appeal_period,
+    init_contract_with_mode, settle_all_jurors, stake_judges,
+};
+
+const EPOCH: u64 = 7 * 24 * 3600;
+
+#[test]
+fn test_emission_shared_by_coherent_weight() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let asset = setup.token_stellar.address.clone();
+
+    let result = setup
+        .contract
+        .try_set_emission_schedule(&setup.creator, &900, &EPOCH);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let result = setup
+        .contract
+        .try_set_emission_schedule(&setup.contract_admin, &900, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidDuration)));
+
+    setup.token_stellar.mint(&setup.contract_admin, &600);
+    let result = setup.contract.try_fund_emissions(&setup.creator, &600);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    assert_eq!(
+        setup.contract.fund_emissions(&setup.contract_admin, &600),
+        600
+    );
+
+    // judge1 votes with twice the weight of judge2, judge3 votes for the loser
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+    for judge in [&setup.judge2, &setup.judge3] {
+        setup.contract.new_voter(judge);
+        setup.contract.register_to_vote(judge, &dispute.dispute_id);
+    }
+    for (judge, weight, choice) in [
+        (&setup.judge1, 2, VoteChoice::Creator),
+        (&setup.judge2, 1, VoteChoice::Creator),
+        (&setup.judge3, 1, VoteChoice::Counterpart),
+    ] {
+        setup.contract.vote(
+            judge,
+            &dispute.dispute_id,
+            &build_public_vote(judge, weight, choice),
+        );
+    }
+    setup
+        .contract
+        .set_emission_schedule(&setup.contract_admin, &900, &EPOCH);
+
+    // the coherent weight counts towards the epoch of the ruling
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(
+        setup.contract.get_emission_epoch(&0).unwrap().total_weight,
+        3
+    );
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+
+    let result = setup.contract.try_claim_emission(&setup.judge1, &0);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+
+    // the epoch emits what is left in the reservoir, jurors claiming their
+    // reward later still share it
+    let now = setup.env.ledger().timestamp();
+    setup.env.ledger().set_timestamp(now + EPOCH);
+    setup
+        .contract
+        .claim_reward(&setup.judge2, &dispute.dispute_id);
+    assert_eq!(
+        setup.contract.get_emission_epoch(&0).unwrap().total_weight,
+        3
+    );
+    assert_eq!(setup.contract.claim_emission(&setup.judge1, &0), 400);
+    assert_eq!(setup.contract.claim_emission(&setup.judge2, &0), 200);
+    let result = setup.contract.try_claim_emission(&setup.judge2, &0);
+    assert_eq!(result, Err(Ok(Error::AlreadyClaimed)));
+    let result = setup.contract.try_claim_emission(&setup.judge3, &0);
+    assert_eq!(result, Err(Ok(Error::NotEligible)));
+
+    assert_eq!(setup.contract.get_balance(&setup.judge1, &asset), 400);
+    assert_eq!(setup.contract.get_balance(&setup.judge2, &asset), 200);
+    let epoch = setup.contract.get_emission_epoch(&0).unwrap();
+    assert_eq!(epoch.emission, Some(600));
+    assert_eq!(epoch.distributed, 600);
+    assert_eq!(
+        setup.contract.get_emissions(),
+        Emissions {
+            reservoir: 0,
+            allocated: 0,
+            distributed: 600,
+        }
+    );
+
+    // a new schedule starts the next epoch right away
+    setup
+        .contract
+        .set_emission_schedule(&setup.contract_admin, &0, &EPOCH);
+    assert_eq!(
+        setup.contract.get_emission_schedule().unwrap().first_epoch,
+        2
+    );
+}
+
+#[test]
+fn test_unclaimed_emission_returns_to_reservoir() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    setup.token_stellar.mint(&setup.contract_admin, &600);
+    setup.contract.fund_emissions(&setup.contract_admin, &600);
+    setup
+        .contract
+        .set_emission_schedule(&setup.contract_admin, &600, &EPOCH);
+
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+    for (judge, weight) in [(&setup.judge1, 2), (&setup.judge2, 1)] {
+        setup.contract.vote(
+            judge,
+            &dispute.dispute_id,
+            &build_public_vote(judge, weight, VoteChoice::Creator),
+        );
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    let ruled_at = setup.env.ledger().timestamp();
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup.contract.claim_reward(judge, &dispute.dispute_id);
+    }
+
+    // judge2 never claims their part of the epoch
+    let now = setup.env.ledger().timestamp();
+    setup.env.ledger().set_timestamp(now + EPOCH);
+    assert_eq!(setup.contract.claim_emission(&setup.judge1, &0), 400);
+    let result = setup.contract.try_reclaim_emission(&0);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(ruled_at + EMISSION_CLAIM_PERIOD);
+    let result = setup.contract.try_claim_emission(&setup.judge2, &0);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+    assert_eq!(setup.contract.reclaim_emission(&0), 200);
+    let result = setup.contract.try_reclaim_emission(&0);
+    assert_eq!(result, Err(Ok(Error::AlreadyClaimed)));
+
+    let epoch = setup.contract.get_emission_epoch(&0).unwrap();
+    assert_eq!(epoch.emission, Some(400));
+    assert_eq!(epoch.distributed, 400);
+    assert_eq!(
+        setup.contract.get_emissions(),
+        Emissions {
+            reservoir: 200,
+            allocated: 0,
+            distributed: 400,
+        }
+    );
+}
+
+#[test]
+fn test_anonymous_emission_credited_on_proven_vote() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+    setup.token_stellar.mint(&setup.contract_admin, &600);
+    setup.contract.fund_emissions(&setup.contract_admin, &600);
+    setup
+        .contract
+        .set_emission_schedule(&setup.contract_admin, &600, &EPOCH);
+
+    // judge3 votes for the loser
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
+    for judge in [&setup.judge2, &setup.judge3] {
+        setup.contract.new_voter(judge);
+        setup.contract.register_to_vote(judge, &dispute.dispute_id);
+    }
+    for (judge, weight, votes) in [
+        (&setup.judge1, 20, vec![&setup.env, 1u128, 0, 0]),
+        (&setup.judge2, 10, vec![&setup.env, 1u128, 0, 0]),
+        (&setup.judge3, 10, vec![&setup.env, 0u128, 1, 0]),
+    ] {
+        let seeds = vec![&setup.env, 1u128, 2, 3];
+        setup.contract.vote(
+            judge,
+            &dispute.dispute_id,
+            &build_anonymous_vote(&setup, judge, weight, votes, seeds),
+        );
+    }
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup.contract.execute(
+        &setup.creator,
+        &dispute.dispute_id,
+        &Some(vec![&setup.env, 30u128, 10, 0]),
+        &Some(vec![&setup.env, 40u128, 80, 120]),
+    );
+    assert_eq!(
+        setup.contract.get_emission_epoch(&0).unwrap().total_weight,
+        30
+    );
+    let result = setup
+        .contract
+        .try_prove_coherent_vote(&setup.judge1, &dispute.dispute_id, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+
+    // claiming the reward does not credit anonymous voters
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    for judge in [&setup.judge1, &setup.judge2, &setup.judge3] {
+        setup.contract.claim_reward(judge, &dispute.dispute_id);
+    }
+
+    // only the votes for the winner open to a vote with their seed
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup
+            .contract
+            .prove_coherent_vote(judge, &dispute.dispute_id, &1);
+    }
+    let result = setup
+        .contract
+        .try_prove_coherent_vote(&setup.judge1, &dispute.dispute_id, &1);
+    assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+    let result = setup
+        .contract
+        .try_prove_coherent_vote(&setup.judge3, &dispute.dispute_id, &1);
+    assert_eq!(result, Err(Ok(Error::InvalidProof)));
+
+    let now = setup.env.ledger().timestamp();
+    setup.env.ledger().set_timestamp(now + EPOCH);
+    assert_eq!(setup.contract.claim_emission(&setup.judge1, &0), 400);
+    assert_eq!(setup.contract.claim_emission(&setup.judge2, &0), 200);
+    let result = setup.contract.try_claim_emission(&setup.judge3, &0);
+    assert_eq!(result, Err(Ok(Error::NotEligible)));
+    let epoch = setup.contract.get_emission_epoch(&0).unwrap();
+    assert_eq!(epoch.emission, Some(600));
+    assert_eq!(epoch.distributed, 600);
+}
@@ -0,0 +1,312 @@
+use soroban_sdk::{
+    Address,
+    testutils::{Address as _, Ledger},
+    token,
+};
+
+use crate::storage::dispute::REWARD_CLAIM_PERIOD;
+use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::tests::test_utils::{
+    DisputeParams, FEE, TestSetup, build_public_vote, create_test_data, end_appeal_period,
+    open_dispute, seat_and_vote, settle_all_jurors, stake_judges,
+};
+
+fn token_balance(setup: &TestSetup, address: &Address) -> i128 {
+    token::TokenClient::new(&setup.env, &setup.token_stellar.address).balance(address)
+}
+
+#[test]
+fn test_arbitration_cost_set_by_admin() {
+    let setup = create_test_data();
+    assert_eq!(setup.contract.arbitration_cost(&None), 0);
+
+    setup
+        .contract
+        .set_arbitration_fee(&setup.contract_admin, &FEE);
+    assert_eq!(setup.contract.arbitration_cost(&None), FEE);
+
+    let result = setup.contract.try_set_arbitration_fee(&setup.creator, &1);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let result = setup
+        .contract
+        .try_set_arbitration_fee(&setup.contract_admin, &-1);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_both_parties_deposit_fee() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+
+    assert_eq!(dispute.arbitration_fee, FEE);
+    assert!(!dispute.counterpart_paid);
+    assert_eq!(token_balance(&setup, &setup.creator), 0);
+    assert_eq!(token_balance(&setup, &setup.contract_id), FEE);
+
+    // jurors wait for the counterpart
+    setup.contract.new_voter(&setup.judge1);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ArbitrationFeeNotPaid))));
+
+    let result = setup
+        .contract
+        .try_pay_arbitration_fee(&setup.creator, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+
+    let paid = setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+    assert!(paid.counterpart_paid);
+    assert_eq!(token_balance(&setup, &setup.counterpart), 0);
+    assert_eq!(token_balance(&setup, &setup.contract_id), 2 * FEE);
+
+    let result = setup
+        .contract
+        .try_pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ArbitrationFeeAlreadyPaid))));
+
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+}
+
+#[test]
+fn test_default_ruling_when_counterpart_does_not_pay() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+
+    let result = setup
+        .contract
+        .try_claim_default_ruling(&setup.creator, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::InsufficientTime))));
+
+    setup.env.ledger().set_timestamp(dispute.fee_deadline);
+    let result = setup
+        .contract
+        .try_pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::FeeDeadlinePassed))));
+    let result = setup
+        .contract
+        .try_claim_default_ruling(&setup.counterpart, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+
+    let resolved = setup
+        .contract
+        .claim_default_ruling(&setup.creator, &dispute.dispute_id);
+    assert_eq!(resolved.dispute_status, DisputeStatus::FINISHED);
+    assert_eq!(resolved.winner, Some(setup.creator.clone()));
+    assert_eq!(resolved.reward_pool, 0);
+
+    // the creator's fee is refunded
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.creator, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(
+        setup
+            .contract
+            .redeem(&setup.creator, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(token_balance(&setup, &setup.creator), FEE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+fn test_vote_requires_both_fees() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+fn test_execute_requires_both_fees() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+}
+
+#[test]
+fn test_loser_fee_rewards_coherent_jurors() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+    setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+
+    for (judge, weight, choice) in [
+        (&setup.judge1, 3, VoteChoice::Creator),
+        (&setup.judge2, 1, VoteChoice::Creator),
+        (&setup.judge3, 2, VoteChoice::Counterpart),
+    ] {
+        setup.contract.new_voter(judge);
+        seat_and_vote(&setup, judge, dispute.dispute_id, weight, choice);
+    }
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(status, DisputeStatus::CREATOR);
+
+    // the winner is refunded, the loser pays the jurors
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.creator, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.counterpart, &setup.token_stellar.address),
+        0
+    );
+    assert_eq!(
+        setup.contract.get_dispute(&dispute.dispute_id).reward_pool,
+        FEE
+    );
+
+    // shared by weight among the jurors who voted for the winner
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge2, &dispute.dispute_id);
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.judge1, &setup.token_stellar.address),
+        FEE * 3 / 4
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.judge2, &setup.token_stellar.address),
+        FEE / 4
+    );
+
+    let result = setup
+        .contract
+        .try_claim_reward(&setup.judge3, &dispute.dispute_id);
+    assert_eq!(result, Err(Ok(Error::NotWithMajority)));
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.judge3, &setup.token_stellar.address),
+        0
+    );
+}
+
+#[test]
+fn test_unclaimed_rewards_swept_after_claim_period() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+    setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+
+    for (judge, weight) in [(&setup.judge1, 2), (&setup.judge2, 1)] {
+        seat_and_vote(
+            &setup,
+            judge,
+            dispute.dispute_id,
+            weight,
+            VoteChoice::Creator,
+        );
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    end_appeal_period(&setup);
+    let final_at = setup.env.ledger().timestamp();
+    settle_all_jurors(&setup, dispute.dispute_id);
+
+    // judge2 never claims their third of the pool
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+    let result = setup
+        .contract
+        .try_sweep_unclaimed_rewards(&dispute.dispute_id);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(final_at + REWARD_CLAIM_PERIOD);
+    let result = setup
+        .contract
+        .try_claim_reward(&setup.judge2, &dispute.dispute_id);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+
+    // the share and the rounding remainder feed the rewards reservoir
+    assert_eq!(
+        setup.contract.sweep_unclaimed_rewards(&dispute.dispute_id),
+        (FEE - FEE * 2 / 3, 0)
+    );
+    assert_eq!(setup.contract.get_emissions().reservoir, FEE - FEE * 2 / 3);
+    let result = setup
+        .contract
+        .try_sweep_unclaimed_rewards(&dispute.dispute_id);
+    assert_eq!(result, Err(Ok(Error::AlreadyClaimed)));
+}
+
+#[test]
+fn test_fees_refunded_without_winner() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+    setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute.dispute_id);
+
+    let judge = Address::generate(&setup.env);
+    setup.contract.new_voter(&judge);
+    seat_and_vote(&setup, &judge, dispute.dispute_id, 1, VoteChoice::Abstain);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(status, DisputeStatus::ABSTAIN);
+
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.creator, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.counterpart, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(
+        setup.contract.get_dispute(&dispute.dispute_id).reward_pool,
+        0
+    );
+}
@@ -1,6 +1,10 @@
-use soroban_sdk::{Bytes, String, testutils::Address as _};
+use soroban_sdk::{
+    Bytes, String,
+    testutils::{Address as _, Ledger},
+};
 
 use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::vote::VotingMode;
 use crate::tests::test_utils::{compute_commit_hash, create_test_data};
 
 #[test]
@@ -19,8 +23,10 @@ fn test_full_dispute_lifecycle() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     assert_eq!(dispute.dispute_status, DisputeStatus::OPEN);
@@ -59,13 +65,14 @@ fn test_full_dispute_lifecycle() {
     let votes = soroban_sdk::vec![&setup.env, true, true, false];
     let secrets = soroban_sdk::vec![&setup.env, secret1, secret2, secret3];
 
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     let final_dispute =
         setup
             .contract
             .reveal_votes(&setup.creator, &dispute.dispute_id, &votes, &secrets);
 
     // Step 6: Verify final state
-    assert_eq!(final_dispute.dispute_status, DisputeStatus::FINISHED);
+    assert_eq!(final_dispute.dispute_status, DisputeStatus::EXECUTED);
     assert_eq!(final_dispute.votes_for, 2);
     assert_eq!(final_dispute.votes_against, 1);
     assert_eq!(final_dispute.winner, Some(setup.creator.clone()));
@@ -83,8 +90,10 @@ fn test_multiple_disputes_independent() {
         &setup.creator,
         &setup.counterpart,
         &proof1,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // Create second dispute with different parties
@@ -97,8 +106,10 @@ fn test_multiple_disputes_independent() {
         &creator2,
         &counterpart2,
         &proof2,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // Register same judges for both disputes
@@ -145,8 +156,10 @@ fn test_unanimous_vote_for_creator() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // All judges register
@@ -183,6 +196,7 @@ fn test_unanimous_vote_for_creator() {
     let votes = soroban_sdk::vec![&setup.env, true, true, true];
     let secrets = soroban_sdk::vec![&setup.env, secret1, secret2, secret3];
 
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     let final_dispute =
         setup
             .contract
@@ -208,8 +222,10 @@ fn test_unanimous_vote_for_counterpart() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // All judges register
@@ -246,6 +262,7 @@ fn test_unanimous_vote_for_counterpart() {
     let votes = soroban_sdk::vec![&setup.env, false, false, false];
     let secrets = soroban_sdk::vec![&setup.env, secret1, secret2, secret3];
 
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     let final_dispute =
         setup
             .contract
@@ -269,8 +286,10 @@ fn test_single_judge_decides() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     setup
@@ -286,6 +305,7 @@ fn test_single_judge_decides() {
     let votes = soroban_sdk::vec![&setup.env, true];
     let secrets = soroban_sdk::vec![&setup.env, secret];
 
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     let final_dispute =
         setup
             .contract
@@ -306,8 +326,10 @@ fn test_large_number_of_judges() {
         &setup.creator,
         &setup.counterpart,
         &proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // Create 5 judges
@@ -349,6 +371,7 @@ fn test_large_number_of_judges() {
     }
 
     // Reveal all votes
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     let final_dispute =
         setup
             .contract
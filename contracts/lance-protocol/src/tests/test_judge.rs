@@ -3,14 +3,15 @@ use soroban_sdk::{String, testutils::Ledger, vec};
 use crate::{
     storage::{
         dispute_status::DisputeStatus,
-        vote::{AnonymousVote, VoteAnon},
+        vote::{AnonymousVote, VoteAnon, VotingMode},
     },
-    tests::test_utils::{create_test_data, init_contract},
+    tests::test_utils::{create_test_data, init_contract_with_mode, stake_judges},
 };
 
 #[test]
 fn test_vote_maths() {
     let setup = create_test_data();
+    stake_judges(&setup);
 
     let public_key = String::from_str(&setup.env, "public key random");
     setup
@@ -18,7 +19,7 @@ fn test_vote_maths() {
         .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
 
     let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
-    let dispute = init_contract(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
 
     /*
        let proposal_id = setup.contract.create_proposal(
@@ -53,6 +54,7 @@ fn test_vote_maths() {
             &vec![&setup.env, 3u128, 1u128, 1u128],
             &vec![&setup.env, 5u128, 4u128, 6u128],
         ),
+        justification_commit: None,
     });
     setup
         .contract
@@ -91,6 +93,7 @@ fn test_vote_maths() {
             &vec![&setup.env, 0u128, 0u128, 1u128],
             &vec![&setup.env, 0u128, 0u128, 0u128],
         ),
+        justification_commit: None,
     });
 
     assert_eq!(
@@ -0,0 +1,177 @@
+use soroban_sdk::{Bytes, testutils::Ledger, vec};
+
+use crate::storage::dispute::{APPEAL_PERIOD, Ruling};
+use crate::storage::dispute_status::{DisputePhase, DisputeStatus};
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, compute_commit_hash, create_test_data, init_contract, open_dispute,
+    seat_and_vote, settle_all_jurors,
+};
+
+fn phase(setup: &TestSetup, dispute_id: u32) -> (DisputePhase, DisputeStatus, u64) {
+    let dispute = setup.contract.get_dispute(&dispute_id);
+    (
+        dispute.phase,
+        dispute.dispute_status,
+        dispute.phase_started_at,
+    )
+}
+
+#[test]
+fn test_public_dispute_lifecycle() {
+    let setup = create_test_data();
+    let dispute = open_dispute(&setup, &DisputeParams::with_fee(&setup));
+    let dispute_id = dispute.dispute_id;
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (DisputePhase::Evidence, DisputeStatus::OPEN, 0)
+    );
+
+    setup.contract.new_voter(&setup.judge1);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ArbitrationFeeNotPaid))));
+
+    setup.env.ledger().set_timestamp(100);
+    setup
+        .contract
+        .pay_arbitration_fee(&setup.counterpart, &dispute_id);
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (DisputePhase::Registration, DisputeStatus::OPEN, 100)
+    );
+
+    setup.env.ledger().set_timestamp(200);
+    seat_and_vote(&setup, &setup.judge1, dispute_id, 1, VoteChoice::Creator);
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (DisputePhase::Voting, DisputeStatus::VOTING, 200)
+    );
+
+    let result = setup
+        .contract
+        .try_execute(&setup.creator, &dispute_id, &None, &None);
+    assert!(result.is_err());
+
+    // the end of the voting period needs no transaction
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (
+            DisputePhase::Tally,
+            DisputeStatus::VOTING,
+            setup.voting_ends_at
+        )
+    );
+    let result = setup
+        .contract
+        .try_propose_settlement(&setup.creator, &dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::ProposalVotingTime))));
+    let result = setup.contract.try_claim_reward(&setup.judge1, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ProposalActive))));
+    let result = setup.contract.try_get_ruling(&dispute_id);
+    assert_eq!(result, Err(Ok(Error::ProposalActive)));
+
+    let ruled_at = setup.voting_ends_at + 10;
+    setup.env.ledger().set_timestamp(ruled_at);
+    let outcome = setup
+        .contract
+        .execute(&setup.creator, &dispute_id, &None, &None);
+    assert_eq!(outcome, DisputeStatus::CREATOR);
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (DisputePhase::Appealable, DisputeStatus::EXECUTED, ruled_at)
+    );
+    let ruling = Ruling {
+        dispute_id,
+        round: 1,
+        winning_option: VoteChoice::Creator,
+        creator_share_bps: 10_000,
+        finalized_at: ruled_at + APPEAL_PERIOD,
+        is_final: false,
+    };
+    assert_eq!(setup.contract.get_ruling(&dispute_id), ruling);
+
+    let result = setup.contract.try_claim_reward(&setup.judge1, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::InsufficientTime))));
+
+    setup.env.ledger().set_timestamp(ruled_at + APPEAL_PERIOD);
+    assert_eq!(
+        phase(&setup, dispute_id),
+        (
+            DisputePhase::Final,
+            DisputeStatus::FINISHED,
+            ruled_at + APPEAL_PERIOD
+        )
+    );
+    assert_eq!(
+        setup.contract.get_ruling(&dispute_id),
+        Ruling {
+            is_final: true,
+            ..ruling
+        }
+    );
+    let result = setup.contract.try_claim_reward(&setup.judge1, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ProposalActive))));
+    settle_all_jurors(&setup, dispute_id);
+    setup.contract.claim_reward(&setup.judge1, &dispute_id);
+
+    let result = setup
+        .contract
+        .try_execute(&setup.creator, &dispute_id, &None, &None);
+    assert!(result.is_err());
+    let result = setup
+        .contract
+        .try_pay_arbitration_fee(&setup.counterpart, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::DisputeAlreadyResolved))));
+}
+
+#[test]
+fn test_commit_reveal_lifecycle() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    let dispute_id = dispute.dispute_id;
+    // free disputes skip the evidence phase
+    assert_eq!(dispute.phase, DisputePhase::Registration);
+    let result = setup
+        .contract
+        .try_pay_arbitration_fee(&setup.counterpart, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ArbitrationFeeAlreadyPaid))));
+
+    let secret = Bytes::from_slice(&setup.env, b"secret");
+    let committed = setup.contract.commit_vote(
+        &setup.judge1,
+        &dispute_id,
+        &compute_commit_hash(&setup.env, true, &secret),
+    );
+    assert_eq!(committed.phase, DisputePhase::Voting);
+
+    // votes are revealed once nobody can commit anymore
+    let votes = vec![&setup.env, true];
+    let secrets = vec![&setup.env, secret];
+    let result = setup
+        .contract
+        .try_reveal_votes(&setup.creator, &dispute_id, &votes, &secrets);
+    assert!(matches!(result, Err(Ok(Error::ProposalVotingTime))));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup.contract.new_voter(&setup.judge2);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge2, &dispute_id);
+    assert!(matches!(result, Err(Ok(Error::ProposalVotingTime))));
+
+    let revealed = setup
+        .contract
+        .reveal_votes(&setup.creator, &dispute_id, &votes, &secrets);
+    assert_eq!(revealed.phase, DisputePhase::Appealable);
+    assert_eq!(revealed.dispute_status, DisputeStatus::EXECUTED);
+    assert_eq!(revealed.winner, Some(setup.creator.clone()));
+
+    let result = setup
+        .contract
+        .try_reveal_votes(&setup.creator, &dispute_id, &votes, &secrets);
+    assert!(matches!(result, Err(Ok(Error::DisputeAlreadyResolved))));
+}
@@ -0,0 +1,190 @@
+use soroban_sdk::{Address, String, testutils::Address as _};
+
+use crate::storage::dispute::{DISPUTE_OPTIONS, DisputeRecord};
+use crate::storage::dispute_status::DisputePhase;
+use crate::storage::error::Error;
+use crate::storage::registry::{CallerPolicy, DisputeTemplate};
+use crate::storage::vote::VotingMode;
+use crate::tests::test_utils::{
+    DisputeParams, FEE, TestSetup, create_test_data, open_dispute, try_open_dispute,
+};
+
+fn template(setup: &TestSetup) -> DisputeTemplate {
+    DisputeTemplate {
+        court_id: None,
+        juror_count: 2,
+        voting_duration: setup.voting_duration,
+        voting_mode: VotingMode::CommitReveal,
+        arbitration_fee: Some(FEE),
+        ruling_options: DISPUTE_OPTIONS,
+        token: None,
+    }
+}
+
+fn create_template_dispute(setup: &TestSetup, caller: &Address) -> DisputeRecord {
+    setup.token_stellar.mint(&setup.creator, &FEE);
+    setup.contract.create_template_dispute(
+        &setup.project_id,
+        &setup.creator,
+        &setup.counterpart,
+        &setup.proof,
+        caller,
+    )
+}
+
+#[test]
+fn test_registered_caller_disputes_follow_template() {
+    let setup = create_test_data();
+    let caller = Address::generate(&setup.env);
+
+    let result = setup
+        .contract
+        .try_register_caller(&setup.creator, &caller, &template(&setup));
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let invalid = DisputeTemplate {
+        juror_count: 0,
+        ..template(&setup)
+    };
+    let result = setup
+        .contract
+        .try_register_caller(&setup.contract_admin, &caller, &invalid);
+    assert_eq!(result, Err(Ok(Error::VoteLimitExceeded)));
+    let invalid = DisputeTemplate {
+        ruling_options: 2,
+        ..template(&setup)
+    };
+    let result = setup
+        .contract
+        .try_register_caller(&setup.contract_admin, &caller, &invalid);
+    assert_eq!(result, Err(Ok(Error::InvalidBallotOptions)));
+
+    setup
+        .contract
+        .register_caller(&setup.contract_admin, &caller, &template(&setup));
+    assert_eq!(
+        setup.contract.get_caller_template(&caller),
+        Some(template(&setup))
+    );
+
+    let dispute = create_template_dispute(&setup, &caller);
+    assert_eq!(dispute.called_contract, caller);
+    assert_eq!(dispute.voting_mode, VotingMode::CommitReveal);
+    assert_eq!(dispute.arbitration_fee, FEE);
+    assert_eq!(dispute.max_jurors, 2);
+    assert_eq!(dispute.phase, DisputePhase::Evidence);
+
+    // the template cannot be bypassed with custom parameters
+    let result = setup.contract.try_create_dispute_demo(
+        &setup.project_id,
+        &setup.creator,
+        &setup.counterpart,
+        &setup.proof,
+        &setup.voting_duration,
+        &caller,
+        &VotingMode::Public,
+        &None,
+    );
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+
+    setup
+        .contract
+        .unregister_caller(&setup.contract_admin, &caller);
+    assert_eq!(setup.contract.get_caller_template(&caller), None);
+    let result = setup.contract.try_create_template_dispute(
+        &setup.project_id,
+        &setup.creator,
+        &setup.counterpart,
+        &String::from_str(&setup.env, "proof"),
+        &caller,
+    );
+    assert!(matches!(result, Err(Ok(Error::CallerNotRegistered))));
+}
+
+#[test]
+fn test_template_caps_jurors() {
+    let setup = create_test_data();
+    let caller = Address::generate(&setup.env);
+    let free = DisputeTemplate {
+        arbitration_fee: Some(0),
+        ..template(&setup)
+    };
+    setup
+        .contract
+        .register_caller(&setup.contract_admin, &caller, &free);
+    let dispute = create_template_dispute(&setup, &caller);
+
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup.contract.new_voter(judge);
+        setup.contract.register_to_vote(judge, &dispute.dispute_id);
+    }
+    setup.contract.new_voter(&setup.judge3);
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge3, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::VoteLimitExceeded))));
+}
+
+#[test]
+fn test_unregistered_caller_policy() {
+    let setup = create_test_data();
+    assert_eq!(setup.contract.get_caller_policy(), CallerPolicy::Open);
+
+    let result = setup
+        .contract
+        .try_set_caller_policy(&setup.contract_admin, &CallerPolicy::Fee(-1));
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    setup
+        .contract
+        .set_caller_policy(&setup.contract_admin, &CallerPolicy::Fee(FEE));
+    setup.token_stellar.mint(&setup.creator, &FEE);
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+    assert_eq!(dispute.arbitration_fee, FEE);
+
+    setup
+        .contract
+        .set_caller_policy(&setup.contract_admin, &CallerPolicy::Closed);
+    let result = try_open_dispute(&setup, &DisputeParams::new(&setup));
+    assert!(matches!(result, Err(Error::CallerNotRegistered)));
+}
+
+#[test]
+fn test_create_dispute_follows_caller_policy() {
+    let setup = create_test_data();
+    open_dispute(&setup, &DisputeParams::new(&setup));
+    setup
+        .contract
+        .set_caller_policy(&setup.contract_admin, &CallerPolicy::Fee(FEE));
+    setup.token_stellar.mint(&setup.creator, &FEE);
+
+    let dispute = setup.contract.create_dispute(
+        &setup.project_id,
+        &setup.creator,
+        &setup.counterpart,
+        &setup.proof,
+        &setup.voting_duration,
+        &setup.contract_id,
+        &VotingMode::Public,
+        &None,
+    );
+    assert_eq!(dispute.dispute_id, 2);
+    assert_eq!(dispute.arbitration_fee, FEE);
+    let stored = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(stored.creator, setup.creator);
+    assert_eq!(stored.arbitration_fee, FEE);
+
+    setup
+        .contract
+        .set_caller_policy(&setup.contract_admin, &CallerPolicy::Closed);
+    let result = setup.contract.try_create_dispute(
+        &setup.project_id,
+        &setup.judge1,
+        &setup.counterpart,
+        &setup.proof,
+        &setup.voting_duration,
+        &setup.contract_id,
+        &VotingMode::Public,
+        &None,
+    );
+    assert!(matches!(result, Err(Ok(Error::CallerNotRegistered))));
+}
@@ -0,0 +1,387 @@
+use soroban_sdk::{Address, Bytes, Vec, testutils::Address as _, testutils::Ledger, vec};
+
+use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::eligibility::Suspension;
+use crate::storage::error::Error;
+use crate::storage::reputation::{JurorStats, LEADERBOARD_SIZE};
+use crate::storage::vote::{VoteChoice, VotingMode};
+use crate::storage::voter::{REPUTATION_DECAY_PERIOD, get_voter, update_voter};
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, compute_commit_hash, create_test_data, end_appeal_period,
+    open_dispute, seat_and_vote, settle_all_jurors, stake_judges,
+};
+
+/// Register a voter with some reputation earned on past disputes
+fn new_voter_with_reputation(setup: &TestSetup, voter: &Address, reputation: i32) {
+    setup.contract.new_voter(voter);
+    setup.env.as_contract(&setup.contract_id, || {
+        let voter = get_voter(&setup.env, voter.clone()).unwrap();
+        update_voter(&setup.env, voter, 0, reputation);
+    });
+}
+
+fn reputation(setup: &TestSetup, voter: &Address) -> u32 {
+    setup.contract.get_user(voter).reputation
+}
+
+#[test]
+fn test_suspension_expires() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Public,
+            ..DisputeParams::new(&setup)
+        },
+    );
+    let now = setup.env.ledger().timestamp();
+    setup.contract.new_voter(&setup.judge1);
+
+    let result = setup
+        .contract
+        .try_suspend_voter(&setup.contract_admin, &setup.judge1, &Some(now));
+    assert_eq!(result, Err(Ok(Error::InvalidDuration)));
+
+    setup
+        .contract
+        .suspend_voter(&setup.contract_admin, &setup.judge1, &Some(now + 100));
+    assert_eq!(
+        setup.contract.get_suspension(&setup.judge1),
+        Some(Suspension {
+            suspended_at: now,
+            until: Some(now + 100),
+        })
+    );
+    let result = setup
+        .contract
+        .try_register_to_vote(&setup.judge1, &dispute.dispute_id);
+    assert!(matches!(result, Err(Ok(Error::VoterSuspended))));
+
+    setup.env.ledger().set_timestamp(now + 100);
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+}
+
+#[test]
+fn test_governance_moderates_voters() {
+    let setup = create_test_data();
+    let governance = Address::generate(&setup.env);
+
+    let result = setup
+        .contract
+        .try_suspend_voter(&governance, &setup.judge1, &None);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    let result = setup
+        .contract
+        .try_set_governance(&setup.creator, &Some(governance.clone()));
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    setup
+        .contract
+        .set_governance(&setup.contract_admin, &Some(governance.clone()));
+    setup
+        .contract
+        .suspend_voter(&governance, &setup.judge1, &None);
+    assert_eq!(
+        setup.contract.get_suspension(&setup.judge1).unwrap().until,
+        None
+    );
+
+    setup.contract.reinstate_voter(&governance, &setup.judge1);
+    assert_eq!(setup.contract.get_suspension(&setup.judge1), None);
+    let result = setup
+        .contract
+        .try_reinstate_voter(&governance, &setup.judge1);
+    assert_eq!(result, Err(Ok(Error::VoterNotFound)));
+
+    setup.contract.set_governance(&setup.contract_admin, &None);
+    let result = setup
+        .contract
+        .try_suspend_voter(&governance, &setup.judge1, &None);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_suspended_juror_cannot_commit() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::CommitReveal,
+            ..DisputeParams::new(&setup)
+        },
+    );
+    setup.contract.new_voter(&setup.judge1);
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+
+    setup
+        .contract
+        .suspend_voter(&setup.contract_admin, &setup.judge1, &None);
+    let secret = Bytes::from_slice(&setup.env, b"secret");
+    let result = setup.contract.try_commit_vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, true, &secret),
+    );
+    assert!(matches!(result, Err(Ok(Error::VoterSuspended))));
+}
+
+#[test]
+fn test_public_penalties() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Public,
+            ..DisputeParams::new(&setup)
+        },
+    );
+    new_voter_with_reputation(&setup, &setup.judge1, 3);
+    new_voter_with_reputation(&setup, &setup.judge2, 3);
+    new_voter_with_reputation(&setup, &setup.judge3, 1);
+
+    for (judge, weight, choice) in [
+        (&setup.judge1, 3, VoteChoice::Creator),
+        (&setup.judge2, 1, VoteChoice::Counterpart),
+    ] {
+        seat_and_vote(&setup, judge, dispute.dispute_id, weight, choice);
+    }
+    // judge3 takes a seat but never votes
+    setup
+        .contract
+        .register_to_vote(&setup.judge3, &dispute.dispute_id);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(status, DisputeStatus::CREATOR);
+    settle_all_jurors(&setup, dispute.dispute_id);
+
+    assert_eq!(reputation(&setup, &setup.judge1), 3);
+    assert_eq!(reputation(&setup, &setup.judge2), 2);
+    // reputation never goes below zero
+    assert_eq!(reputation(&setup, &setup.judge3), 0);
+}
+
+#[test]
+fn test_commit_reveal_penalties() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::CommitReveal,
+            ..DisputeParams::new(&setup)
+        },
+    );
+
+    let mut votes = vec![&setup.env];
+    let mut secrets = vec![&setup.env];
+    for (judge, vote, secret) in [(&setup.judge1, true, b"s1"), (&setup.judge2, false, b"s2")] {
+        new_voter_with_reputation(&setup, judge, 3);
+        setup.contract.register_to_vote(judge, &dispute.dispute_id);
+
+        let secret = Bytes::from_slice(&setup.env, secret);
+        setup.contract.commit_vote(
+            judge,
+            &dispute.dispute_id,
+            &compute_commit_hash(&setup.env, vote, &secret),
+        );
+        votes.push_back(vote);
+        secrets.push_back(secret);
+    }
+    new_voter_with_reputation(&setup, &setup.judge3, 3);
+    setup
+        .contract
+        .register_to_vote(&setup.judge3, &dispute.dispute_id);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    // a tie goes to the counterpart
+    let resolved =
+        setup
+            .contract
+            .reveal_votes(&setup.creator, &dispute.dispute_id, &votes, &secrets);
+    assert_eq!(resolved.winner, Some(setup.counterpart.clone()));
+    settle_all_jurors(&setup, dispute.dispute_id);
+
+    assert_eq!(reputation(&setup, &setup.judge1), 2);
+    assert_eq!(reputation(&setup, &setup.judge2), 3);
+    assert_eq!(reputation(&setup, &setup.judge3), 1);
+}
+
+#[test]
+fn test_reputation_decays_when_inactive() {
+    let setup = create_test_data();
+    new_voter_with_reputation(&setup, &setup.judge1, 100);
+    new_voter_with_reputation(&setup, &setup.judge2, 100);
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(REPUTATION_DECAY_PERIOD - 1);
+    assert_eq!(reputation(&setup, &setup.judge1), 100);
+
+    // a tenth, rounded up, per period
+    setup.env.ledger().set_timestamp(REPUTATION_DECAY_PERIOD);
+    assert_eq!(reputation(&setup, &setup.judge1), 90);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(3 * REPUTATION_DECAY_PERIOD);
+    assert_eq!(reputation(&setup, &setup.judge1), 72);
+
+    // voting restarts the decay period
+    let voted_at = 3 * REPUTATION_DECAY_PERIOD + REPUTATION_DECAY_PERIOD / 2;
+    setup.env.ledger().set_timestamp(voted_at);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_duration: REPUTATION_DECAY_PERIOD,
+            ..DisputeParams::new(&setup)
+        },
+    );
+    seat_and_vote(
+        &setup,
+        &setup.judge2,
+        dispute.dispute_id,
+        1,
+        VoteChoice::Creator,
+    );
+    assert_eq!(reputation(&setup, &setup.judge2), 72);
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(4 * REPUTATION_DECAY_PERIOD);
+    assert_eq!(reputation(&setup, &setup.judge1), 64);
+    assert_eq!(reputation(&setup, &setup.judge2), 72);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(voted_at + REPUTATION_DECAY_PERIOD);
+    assert_eq!(reputation(&setup, &setup.judge2), 64);
+}
+
+#[test]
+fn test_juror_stats_and_leaderboard() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            voting_mode: VotingMode::Public,
+            ..DisputeParams::new(&setup)
+        },
+    );
+    new_voter_with_reputation(&setup, &setup.judge3, 1);
+    new_voter_with_reputation(&setup, &setup.judge2, 3);
+    new_voter_with_reputation(&setup, &setup.judge1, 3);
+
+    for (judge, weight, choice) in [
+        (&setup.judge1, 2, VoteChoice::Creator),
+        (&setup.judge2, 1, VoteChoice::Counterpart),
+    ] {
+        seat_and_vote(&setup, judge, dispute.dispute_id, weight, choice);
+    }
+    setup
+        .contract
+        .register_to_vote(&setup.judge3, &dispute.dispute_id);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+
+    let stats = setup.contract.get_juror_stats(&setup.judge1);
+    assert_eq!(
+        stats,
+        JurorStats {
+            disputes: 1,
+            coherent_votes: 1,
+            incoherent_votes: 0,
+            missed_votes: 0,
+            rewards_earned: 10,
+            last_active: 0,
+        }
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_juror_stats(&setup.judge2)
+            .incoherent_votes,
+        1
+    );
+    assert_eq!(
+        setup.contract.get_juror_stats(&setup.judge3).missed_votes,
+        1
+    );
+
+    // jurors are ranked by reputation, judge3 lost the only point they had
+    let board = setup.contract.get_leaderboard(&0, &10);
+    assert_eq!(board.len(), 2);
+    let expected = [(&setup.judge1, 4, 10_000), (&setup.judge2, 2, 0)];
+    for (entry, (juror, reputation, coherence_bps)) in board.iter().zip(expected) {
+        assert_eq!(entry.juror, *juror);
+        assert_eq!(entry.reputation, reputation);
+        assert_eq!(entry.coherence_bps, coherence_bps);
+    }
+
+    let page = setup.contract.get_leaderboard(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().juror, setup.judge2);
+    assert_eq!(setup.contract.get_leaderboard(&2, &10).len(), 0);
+}
+
+#[test]
+fn test_leaderboard_keeps_the_best_jurors() {
+    let setup = create_test_data();
+    // the juror at index i has i + 1 reputation
+    let mut jurors = Vec::new(&setup.env);
+    for reputation in 1..=LEADERBOARD_SIZE + 1 {
+        let juror = Address::generate(&setup.env);
+        new_voter_with_reputation(&setup, &juror, reputation as i32);
+        jurors.push_back(juror);
+    }
+    let set_reputation = |index: u32, delta: i32| {
+        setup.env.as_contract(&setup.contract_id, || {
+            let voter = get_voter(&setup.env, jurors.get_unchecked(index)).unwrap();
+            update_voter(&setup.env, voter, 0, delta);
+        });
+    };
+    let ranks = |offset: u32| {
+        let mut ranks = Vec::new(&setup.env);
+        for entry in setup.contract.get_leaderboard(&offset, &25).iter() {
+            ranks.push_back(jurors.first_index_of(entry.juror).unwrap());
+        }
+        ranks
+    };
+
+    // the weakest juror was pushed out of the index
+    let mut board = ranks(0);
+    board.append(&ranks(25));
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    for (rank, index) in board.iter().enumerate() {
+        assert_eq!(index, LEADERBOARD_SIZE - rank as u32);
+    }
+
+    // a penalty moves the best juror after those of equal reputation
+    set_reputation(50, -2);
+    assert_eq!(ranks(0).slice(0..3), vec![&setup.env, 49, 48, 50]);
+
+    // a juror losing all reputation leaves the index, the weakest juror only
+    // comes back with an update
+    set_reputation(49, -50);
+    assert_eq!(ranks(0).first(), Some(48));
+    assert_eq!(ranks(25).len(), LEADERBOARD_SIZE - 26);
+    set_reputation(0, 0);
+    assert_eq!(ranks(25).last(), Some(0));
+}
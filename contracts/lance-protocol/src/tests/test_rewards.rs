@@ -5,14 +5,20 @@ use soroban_sdk::{
 };
 
 use crate::{
+    methods::dispute::MAX_CLAIMS_PER_CALL,
     storage::dispute_status::DisputeStatus,
-    storage::vote::{AnonymousVote, VoteAnon},
-    tests::test_utils::{create_test_data, init_contract},
+    storage::error::Error,
+    storage::vote::{AnonymousVote, VoteAnon, VoteChoice, VotingMode},
+    tests::test_utils::{
+        build_public_vote, create_test_data, end_appeal_period, init_contract_with_mode,
+        settle_all_jurors, stake_judges,
+    },
 };
 
 #[test]
 fn test_claim_reward_success() {
     let setup = create_test_data();
+    stake_judges(&setup);
 
     let public_key = String::from_str(&setup.env, "public key random");
     setup
@@ -20,7 +26,7 @@ fn test_claim_reward_success() {
         .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
 
     let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
-    let dispute = init_contract(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
 
     // Judge1 votes
     let vote_ = VoteAnon::AnonymousVote(AnonymousVote {
@@ -43,6 +49,7 @@ fn test_claim_reward_success() {
             &vec![&setup.env, 3u128, 1u128, 1u128],
             &vec![&setup.env, 5u128, 4u128, 6u128],
         ),
+        justification_commit: None,
     });
     setup
         .contract
@@ -65,7 +72,9 @@ fn test_claim_reward_success() {
 
     assert_eq!(vote_result, DisputeStatus::CREATOR);
 
-    // Claim reward
+    // Claim reward once the ruling is final
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
     setup.contract.claim_reward(&setup.judge1, &dispute.dispute_id);
 
     // Check updated balance and reputation
@@ -78,6 +87,7 @@ fn test_claim_reward_success() {
 #[should_panic(expected = "Error(Contract, #39)")]
 fn test_claim_reward_twice_fails() {
     let setup = create_test_data();
+    stake_judges(&setup);
 
     let public_key = String::from_str(&setup.env, "public key random");
     setup
@@ -85,7 +95,7 @@ fn test_claim_reward_twice_fails() {
         .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
 
     let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
-    let dispute = init_contract(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
 
     // Judge1 votes
     let vote_ = VoteAnon::AnonymousVote(AnonymousVote {
@@ -108,6 +118,7 @@ fn test_claim_reward_twice_fails() {
             &vec![&setup.env, 3u128, 1u128, 1u128],
             &vec![&setup.env, 5u128, 4u128, 6u128],
         ),
+        justification_commit: None,
     });
     setup
         .contract
@@ -123,6 +134,8 @@ fn test_claim_reward_twice_fails() {
     );
 
     // Claim reward first time - should succeed
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
     setup.contract.claim_reward(&setup.judge1, &dispute.dispute_id);
 
     // Try to claim again - should panic with AlreadyClaimed error (#39)
@@ -133,13 +146,14 @@ fn test_claim_reward_twice_fails() {
 #[should_panic(expected = "Error(Contract, #37)")]
 fn test_claim_reward_before_execution_fails() {
     let setup = create_test_data();
+    stake_judges(&setup);
 
     let public_key = String::from_str(&setup.env, "public key random");
     setup
         .contract
         .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
 
-    let dispute = init_contract(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
 
     // Judge1 votes
     let vote_ = VoteAnon::AnonymousVote(AnonymousVote {
@@ -162,11 +176,60 @@ fn test_claim_reward_before_execution_fails() {
             &vec![&setup.env, 3u128, 1u128, 1u128],
             &vec![&setup.env, 5u128, 4u128, 6u128],
         ),
+        justification_commit: None,
     });
     setup
         .contract
         .vote(&setup.judge1, &dispute.dispute_id, &vote_);
 
-    // Try to claim reward before execution - should panic with ProposalActive error (#37)
+    // Try to claim reward after voting but before execution - should panic
+    // with ProposalActive error (#37)
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
     setup.contract.claim_reward(&setup.judge1, &dispute.dispute_id);
 }
+
+#[test]
+fn test_claim_rewards_in_batch() {
+    let setup = create_test_data();
+    setup.contract.new_voter(&setup.judge1);
+
+    let mut dispute_ids = vec![&setup.env];
+    for _ in 0..2 {
+        let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+        setup.contract.vote(
+            &setup.judge1,
+            &dispute.dispute_id,
+            &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+        );
+        dispute_ids.push_back(dispute.dispute_id);
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    for dispute_id in dispute_ids.iter() {
+        setup
+            .contract
+            .execute(&setup.creator, &dispute_id, &None, &None);
+    }
+    end_appeal_period(&setup);
+    for dispute_id in dispute_ids.iter() {
+        settle_all_jurors(&setup, dispute_id);
+    }
+
+    let mut too_many = vec![&setup.env];
+    for _ in 0..=MAX_CLAIMS_PER_CALL {
+        too_many.push_back(dispute_ids.get(0).unwrap());
+    }
+    let result = setup.contract.try_claim_rewards(&setup.judge1, &too_many);
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
+
+    let claimed = setup.contract.claim_rewards(&setup.judge1, &dispute_ids);
+    assert_eq!(claimed, 20);
+    let voter = setup.contract.get_user(&setup.judge1);
+    assert_eq!(voter.balance, 20);
+    assert_eq!(voter.reputation, 2);
+
+    // claims are only paid once
+    let result = setup
+        .contract
+        .try_claim_rewards(&setup.judge1, &dispute_ids);
+    assert!(result.is_err());
+}
@@ -0,0 +1,163 @@
+use soroban_sdk::{
+    Address, Env, contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::storage::dispute::Settlement;
+use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::error::Error;
+use crate::storage::vote::VoteChoice;
+use crate::tests::test_utils::{DisputeParams, FEE, create_test_data, open_dispute};
+
+/// Stands in for a marketplace holding the disputed payment
+#[contract]
+struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    pub fn dispute_settled(env: Env, dispute_id: u32, creator_share_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("settled"), &(dispute_id, creator_share_bps));
+    }
+}
+
+#[test]
+fn test_settlement_closes_dispute() {
+    let setup = create_test_data();
+    let escrow = setup.env.register(EscrowContract, ());
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            called_contract: escrow.clone(),
+            fee_paid: true,
+            ..DisputeParams::with_fee(&setup)
+        },
+    );
+
+    let pending = setup
+        .contract
+        .propose_settlement(&setup.creator, &dispute.dispute_id, &7_000);
+    assert_eq!(
+        setup.contract.get_settlement(&dispute.dispute_id),
+        Some(Settlement {
+            proposer: setup.creator.clone(),
+            creator_share_bps: 7_000,
+        })
+    );
+    assert_eq!(pending.dispute_status, DisputeStatus::OPEN);
+
+    let settled = setup
+        .contract
+        .accept_settlement(&setup.counterpart, &dispute.dispute_id, &7_000);
+    assert_eq!(settled.dispute_status, DisputeStatus::SETTLED);
+    assert_eq!(settled.winner, None);
+    assert!(settled.finish_timestamp.is_some());
+    let ruling = setup.contract.get_ruling(&dispute.dispute_id);
+    assert_eq!(ruling.winning_option, VoteChoice::Abstain);
+    assert_eq!(ruling.creator_share_bps, 7_000);
+    assert!(ruling.is_final);
+
+    // both fees are refunded
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.creator, &setup.token_stellar.address),
+        FEE
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_balance(&setup.counterpart, &setup.token_stellar.address),
+        FEE
+    );
+
+    // the escrow is told how to release the payment
+    let notified: Option<(u32, u32)> = setup.env.as_contract(&escrow, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .get(&symbol_short!("settled"))
+    });
+    assert_eq!(notified, Some((dispute.dispute_id, 7_000)));
+
+    let result = setup
+        .contract
+        .try_propose_settlement(&setup.creator, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::DisputeAlreadyResolved))));
+}
+
+#[test]
+fn test_settlement_requires_both_parties() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            called_contract: Address::generate(&setup.env),
+            fee_paid: true,
+            ..DisputeParams::with_fee(&setup)
+        },
+    );
+
+    let result = setup
+        .contract
+        .try_propose_settlement(&setup.judge1, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+    let result =
+        setup
+            .contract
+            .try_propose_settlement(&setup.creator, &dispute.dispute_id, &10_001);
+    assert!(matches!(result, Err(Ok(Error::InvalidAmount))));
+    let result =
+        setup
+            .contract
+            .try_accept_settlement(&setup.counterpart, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::SettlementNotFound))));
+
+    setup
+        .contract
+        .propose_settlement(&setup.creator, &dispute.dispute_id, &5_000);
+    let result = setup
+        .contract
+        .try_accept_settlement(&setup.creator, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::NotAuthorized))));
+
+    // a counter-proposal replaces the pending one
+    setup
+        .contract
+        .propose_settlement(&setup.counterpart, &dispute.dispute_id, &2_000);
+    let result = setup
+        .contract
+        .try_accept_settlement(&setup.creator, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::SettlementNotFound))));
+
+    // without a callback the settlement still stands
+    let settled = setup
+        .contract
+        .accept_settlement(&setup.creator, &dispute.dispute_id, &2_000);
+    assert_eq!(settled.dispute_status, DisputeStatus::SETTLED);
+}
+
+#[test]
+fn test_settlement_before_voting_ends() {
+    let setup = create_test_data();
+    let dispute = open_dispute(
+        &setup,
+        &DisputeParams {
+            called_contract: Address::generate(&setup.env),
+            fee_paid: true,
+            ..DisputeParams::with_fee(&setup)
+        },
+    );
+    setup
+        .contract
+        .propose_settlement(&setup.creator, &dispute.dispute_id, &5_000);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let result =
+        setup
+            .contract
+            .try_accept_settlement(&setup.counterpart, &dispute.dispute_id, &5_000);
+    assert!(matches!(result, Err(Ok(Error::ProposalVotingTime))));
+}
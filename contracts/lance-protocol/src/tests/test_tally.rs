@@ -0,0 +1,171 @@
+use soroban_sdk::{Address, String, testutils::Address as _, testutils::Ledger, vec};
+
+use crate::{
+    methods::tally::empty_tally_commitments,
+    storage::dispute::DISPUTE_OPTIONS,
+    storage::dispute_status::DisputeStatus,
+    storage::vote::VotingMode,
+    tests::test_utils::{TestSetup, build_anonymous_vote, create_test_data, stake_judges},
+};
+
+fn setup_anonymous_dispute(setup: &TestSetup) -> u32 {
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+
+    setup
+        .contract
+        .create_dispute_demo(
+            &setup.project_id,
+            &setup.creator,
+            &setup.counterpart,
+            &setup.proof,
+            &setup.voting_duration,
+            &setup.contract_id,
+            &VotingMode::Anonymous,
+            &None,
+        )
+        .dispute_id
+}
+
+/// Seat `count` new voters and cast their votes for the creator with weight 1
+/// and seeds [1, 2, 3]
+fn cast_creator_votes(setup: &TestSetup, dispute_id: u32, count: u32) {
+    for _ in 0..count {
+        let voter = Address::generate(&setup.env);
+        setup.contract.new_voter(&voter);
+        setup.contract.register_to_vote(&voter, &dispute_id);
+        let vote_ = build_anonymous_vote(
+            setup,
+            &voter,
+            1,
+            vec![&setup.env, 1u128, 0u128, 0u128],
+            vec![&setup.env, 1u128, 2u128, 3u128],
+        );
+        setup.contract.vote(&voter, &dispute_id, &vote_);
+    }
+}
+
+/// Execute a dispute and return the CPU instructions it consumed
+fn execute_cost(setup: &TestSetup, dispute_id: u32, count: u32) -> u64 {
+    let count = count as u128;
+    setup.env.cost_estimate().budget().reset_default();
+    let status = setup.contract.execute(
+        &setup.creator,
+        &dispute_id,
+        &Some(vec![&setup.env, count, 0u128, 0u128]),
+        &Some(vec![&setup.env, count, 2 * count, 3 * count]),
+    );
+    assert_eq!(status, DisputeStatus::CREATOR);
+    setup.env.cost_estimate().budget().cpu_instruction_cost()
+}
+
+#[test]
+fn test_tally_commitments_start_at_identity() {
+    let setup = create_test_data();
+    let dispute_id = setup_anonymous_dispute(&setup);
+
+    let dispute = setup.contract.get_dispute(&dispute_id);
+    assert_eq!(
+        dispute.vote_data.tally_commitments,
+        empty_tally_commitments(&setup.env, DISPUTE_OPTIONS)
+    );
+}
+
+#[test]
+fn test_tally_commitments_accumulate_weighted_votes() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute_id = setup_anonymous_dispute(&setup);
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup.contract.register_to_vote(judge, &dispute_id);
+    }
+
+    // judge1 votes for the creator with weight 3, judge2 for the counterpart with weight 2
+    let vote1 = build_anonymous_vote(
+        &setup,
+        &setup.judge1,
+        3,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 5u128, 4u128, 6u128],
+    );
+    setup.contract.vote(&setup.judge1, &dispute_id, &vote1);
+
+    let vote2 = build_anonymous_vote(
+        &setup,
+        &setup.judge2,
+        2,
+        vec![&setup.env, 0u128, 1u128, 0u128],
+        vec![&setup.env, 7u128, 1u128, 2u128],
+    );
+    setup.contract.vote(&setup.judge2, &dispute_id, &vote2);
+
+    let dispute = setup.contract.get_dispute(&dispute_id);
+    assert_eq!(dispute.vote_data.tally_commitments.len(), 3);
+    assert_ne!(
+        dispute.vote_data.tally_commitments,
+        empty_tally_commitments(&setup.env, DISPUTE_OPTIONS)
+    );
+
+    // weighted tallies: [3*1 + 2*0, 3*0 + 2*1, 0], seeds: [3*5 + 2*7, 3*4 + 2*1, 3*6 + 2*2]
+    let tallies = vec![&setup.env, 3u128, 2u128, 0u128];
+    let seeds = vec![&setup.env, 29u128, 14u128, 22u128];
+    assert!(setup.contract.proof(&dispute_id, &tallies, &seeds));
+
+    let wrong_seeds = vec![&setup.env, 29u128, 14u128, 21u128];
+    assert!(!setup.contract.proof(&dispute_id, &tallies, &wrong_seeds));
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute_id, &Some(tallies), &Some(seeds));
+    // 3 > 2 + 0
+    assert_eq!(status, DisputeStatus::CREATOR);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+fn test_execute_rejects_unweighted_tallies() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute_id = setup_anonymous_dispute(&setup);
+    setup.contract.register_to_vote(&setup.judge1, &dispute_id);
+
+    let vote_ = build_anonymous_vote(
+        &setup,
+        &setup.judge1,
+        3,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 5u128, 4u128, 6u128],
+    );
+    setup.contract.vote(&setup.judge1, &dispute_id, &vote_);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    // tallies not scaled by the voter weight
+    setup.contract.execute(
+        &setup.creator,
+        &dispute_id,
+        &Some(vec![&setup.env, 1u128, 0u128, 0u128]),
+        &Some(vec![&setup.env, 5u128, 4u128, 6u128]),
+    );
+}
+
+#[test]
+fn test_execute_cost_is_constant_in_number_of_votes() {
+    let setup = create_test_data();
+    let small_dispute = setup_anonymous_dispute(&setup);
+    let large_dispute = setup_anonymous_dispute(&setup);
+
+    cast_creator_votes(&setup, small_dispute, 1);
+    cast_creator_votes(&setup, large_dispute, 50);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let small_cost = execute_cost(&setup, small_dispute, 1);
+    let large_cost = execute_cost(&setup, large_dispute, 50);
+
+    // Only the (de)serialisation of the stored votes may grow, not the
+    // curve arithmetic: a single extra weighted commitment costs far more
+    // than this margin.
+    assert!(large_cost < small_cost + small_cost / 10);
+}
@@ -0,0 +1,217 @@
+use soroban_sdk::{
+    Address, Bytes,
+    testutils::{Address as _, Ledger, storage::Persistent},
+    vec,
+};
+
+use crate::storage::dispute::{APPEAL_PERIOD, DISPUTE_BUMP_PAGE_SIZE};
+use crate::storage::storage::{DataKey, FINISHED_DISPUTE_RETENTION, LEDGER_BUMP, ONE_DAY_LEDGERS};
+use crate::tests::test_utils::{TestSetup, compute_commit_hash, create_test_data, init_contract};
+
+fn persistent_ttl(setup: &TestSetup, key: &DataKey) -> u32 {
+    setup.env.as_contract(&setup.contract_id, || {
+        setup.env.storage().persistent().get_ttl(key)
+    })
+}
+
+fn advance_ledgers(setup: &TestSetup, ledgers: u32) {
+    let sequence = setup.env.ledger().sequence();
+    setup.env.ledger().set_sequence_number(sequence + ledgers);
+}
+
+/// Voting, reveal and appeal periods of the disputes of the setup
+const DISPUTE_LEDGERS: u32 = 6 * ONE_DAY_LEDGERS;
+
+#[test]
+fn test_voter_and_balance_extended_on_write() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    assert_eq!(
+        persistent_ttl(&setup, &DataKey::Voters(setup.judge1.clone())),
+        LEDGER_BUMP
+    );
+    assert_eq!(
+        persistent_ttl(&setup, &DataKey::Disputes(dispute.dispute_id)),
+        LEDGER_BUMP
+    );
+    // per-juror entries live through the dispute and its retention period
+    assert_eq!(
+        persistent_ttl(
+            &setup,
+            &DataKey::DisputeJuror(dispute.dispute_id, setup.judge1.clone())
+        ),
+        DISPUTE_LEDGERS + FINISHED_DISPUTE_RETENTION
+    );
+}
+
+#[test]
+fn test_juror_entries_expire_with_the_dispute() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    // an entry written a day later expires at the same ledger
+    advance_ledgers(&setup, ONE_DAY_LEDGERS);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 24 * 3600);
+    let secret = Bytes::from_slice(&setup.env, b"secret_1");
+    let commit_hash = compute_commit_hash(&setup.env, true, &secret);
+    setup
+        .contract
+        .commit_vote(&setup.judge1, &dispute.dispute_id, &commit_hash);
+
+    assert_eq!(
+        persistent_ttl(
+            &setup,
+            &DataKey::VoteCommit(dispute.dispute_id, setup.judge1.clone())
+        ),
+        DISPUTE_LEDGERS - ONE_DAY_LEDGERS + FINISHED_DISPUTE_RETENTION
+    );
+    assert_eq!(
+        persistent_ttl(
+            &setup,
+            &DataKey::DisputeJuror(dispute.dispute_id, setup.judge1.clone())
+        ),
+        DISPUTE_LEDGERS - ONE_DAY_LEDGERS + FINISHED_DISPUTE_RETENTION
+    );
+}
+
+#[test]
+fn test_active_dispute_extended_on_access() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    let key = DataKey::Disputes(dispute.dispute_id);
+
+    advance_ledgers(&setup, 10 * ONE_DAY_LEDGERS);
+    assert_eq!(
+        persistent_ttl(&setup, &key),
+        LEDGER_BUMP - 10 * ONE_DAY_LEDGERS
+    );
+
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+
+    assert_eq!(persistent_ttl(&setup, &key), LEDGER_BUMP);
+}
+
+#[test]
+fn test_finished_dispute_kept_for_retention_only() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    let key = DataKey::Disputes(dispute.dispute_id);
+
+    let secret = Bytes::from_slice(&setup.env, b"secret_1");
+    let commit_hash = compute_commit_hash(&setup.env, true, &secret);
+    setup
+        .contract
+        .commit_vote(&setup.judge1, &dispute.dispute_id, &commit_hash);
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup.contract.reveal_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, true],
+        &vec![&setup.env, secret],
+    );
+
+    assert_eq!(persistent_ttl(&setup, &key), FINISHED_DISPUTE_RETENTION);
+
+    // reading a finished dispute no longer extends it
+    advance_ledgers(&setup, 10 * ONE_DAY_LEDGERS);
+    setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(
+        persistent_ttl(&setup, &key),
+        FINISHED_DISPUTE_RETENTION - 10 * ONE_DAY_LEDGERS
+    );
+}
+
+#[test]
+fn test_bump_extends_user_records() {
+    let setup = create_test_data();
+    setup.contract.new_voter(&setup.judge1);
+    let key = DataKey::Voters(setup.judge1.clone());
+
+    advance_ledgers(&setup, 10 * ONE_DAY_LEDGERS);
+    assert_eq!(
+        persistent_ttl(&setup, &key),
+        LEDGER_BUMP - 10 * ONE_DAY_LEDGERS
+    );
+
+    setup
+        .contract
+        .bump(&setup.judge1, &setup.token_stellar.address);
+    assert_eq!(persistent_ttl(&setup, &key), LEDGER_BUMP);
+}
+
+#[test]
+fn test_bump_dispute_extends_juror_entries() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    let juror_key = DataKey::DisputeJuror(dispute.dispute_id, setup.judge1.clone());
+
+    // the dispute is ruled late, past the end of its appeal period
+    advance_ledgers(&setup, 10 * ONE_DAY_LEDGERS);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(dispute.reveal_ends_at + APPEAL_PERIOD + 4 * 24 * 3600);
+    assert_eq!(
+        persistent_ttl(&setup, &juror_key),
+        DISPUTE_LEDGERS + FINISHED_DISPUTE_RETENTION - 10 * ONE_DAY_LEDGERS
+    );
+
+    setup.contract.bump_dispute(&dispute.dispute_id, &0, &1);
+    assert_eq!(
+        persistent_ttl(&setup, &juror_key),
+        FINISHED_DISPUTE_RETENTION
+    );
+}
+
+#[test]
+fn test_bump_dispute_is_paged() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    let jurors = DISPUTE_BUMP_PAGE_SIZE + 2;
+    for _ in 1..jurors {
+        let juror = Address::generate(&setup.env);
+        setup.contract.new_voter(&juror);
+        setup.contract.register_to_vote(&juror, &dispute.dispute_id);
+    }
+    let juror_ttl = |index: u32| {
+        let key = DataKey::DisputeJurorAt(dispute.dispute_id, index);
+        persistent_ttl(&setup, &key)
+    };
+
+    advance_ledgers(&setup, 10 * ONE_DAY_LEDGERS);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(dispute.reveal_ends_at + APPEAL_PERIOD + 4 * 24 * 3600);
+    let stale = DISPUTE_LEDGERS + FINISHED_DISPUTE_RETENTION - 10 * ONE_DAY_LEDGERS;
+
+    // a page is capped, whatever the limit
+    setup
+        .contract
+        .bump_dispute(&dispute.dispute_id, &0, &jurors);
+    assert_eq!(juror_ttl(0), FINISHED_DISPUTE_RETENTION);
+    assert_eq!(
+        juror_ttl(DISPUTE_BUMP_PAGE_SIZE - 1),
+        FINISHED_DISPUTE_RETENTION
+    );
+    assert_eq!(juror_ttl(DISPUTE_BUMP_PAGE_SIZE), stale);
+
+    setup
+        .contract
+        .bump_dispute(&dispute.dispute_id, &DISPUTE_BUMP_PAGE_SIZE, &jurors);
+    assert_eq!(juror_ttl(jurors - 1), FINISHED_DISPUTE_RETENTION);
+}
+
+#[test]
+fn test_bump_dispute_not_found() {
+    let setup = create_test_data();
+
+    assert!(setup.contract.try_bump_dispute(&42, &0, &1).is_err());
+}
@@ -1,9 +1,14 @@
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Bytes, BytesN, Env, String, token, vec};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, token, vec};
 
 use crate::ProtocolContract;
 use crate::contract::ProtocolContractClient;
-use crate::storage::Dispute;
+use crate::methods::dispute::SETTLE_PAGE_SIZE;
+use crate::storage::config::ProtocolConfig;
+use crate::storage::dispute::APPEAL_PERIOD;
+use crate::storage::dispute::DisputeRecord;
+use crate::storage::error::Error;
+use crate::storage::vote::{AnonymousVote, PublicVote, VoteAnon, VoteChoice, VotingMode};
 
 /// Helper function to compute commit hash off-chain
 /// Hash = SHA256(vote_string || secret)
@@ -15,6 +20,9 @@ pub fn compute_commit_hash(env: &Env, vote: bool, secret: &Bytes) -> BytesN<32>
     env.crypto().sha256(&data).into()
 }
 
+/// Arbitration fee of the disputes opened with [`DisputeParams::with_fee`]
+pub const FEE: i128 = 1_000;
+
 pub struct TestSetup {
     pub env: Env,
     pub contract: ProtocolContractClient<'static>,
@@ -22,13 +30,14 @@ pub struct TestSetup {
     pub creator: Address,
     pub counterpart: Address,
     pub proof: String,
-    // pub token_stellar: token::StellarAssetClient<'static>,
+    pub token_stellar: token::StellarAssetClient<'static>,
     pub contract_admin: Address,
     pub judge1: Address,
     pub judge2: Address,
     pub judge3: Address,
     pub project_id: u32,
-    pub voting_ends_at: u64,
+    pub voting_duration: u64,
+    pub voting_ends_at: u64, // End of the voting period of disputes created at setup
 }
 
 pub fn create_env() -> Env {
@@ -44,7 +53,7 @@ pub fn create_test_data() -> TestSetup {
     let issuer = Address::generate(&env);
     let sac = env.register_stellar_asset_contract_v2(issuer.clone());
     let token_client = token::TokenClient::new(&env, &sac.address());
-    // let token_stellar = token::StellarAssetClient::new(&env, &sac.address());
+    let token_stellar = token::StellarAssetClient::new(&env, &sac.address());
 
     let contract_admin = Address::generate(&env);
     let contract_id = env.register(ProtocolContract, (&contract_admin, token_client.address));
@@ -78,7 +87,8 @@ pub fn create_test_data() -> TestSetup {
     let judge3 = Address::generate(&env);
     let proof = String::from_str(&env, "test proof 1");
     let project_id = 1;
-    let voting_ends_at = env.ledger().timestamp() + 3600 * 24 * 2;
+    let voting_duration = 3600 * 24 * 2;
+    let voting_ends_at = env.ledger().timestamp() + voting_duration;
 
     TestSetup {
         env,
@@ -86,18 +96,100 @@ pub fn create_test_data() -> TestSetup {
         contract_id,
         creator,
         counterpart,
-        // token_stellar,
+        token_stellar,
         contract_admin,
         proof,
         judge1,
         judge2,
         judge3,
         project_id,
+        voting_duration,
         voting_ends_at,
     }
 }
 
-pub fn init_contract(setup: &TestSetup) -> Dispute {
+/// Parameters of a dispute opened by [`open_dispute`]
+#[derive(Clone)]
+pub struct DisputeParams {
+    pub creator: Address,
+    pub counterpart: Address,
+    pub voting_duration: u64,
+    pub called_contract: Address,
+    pub voting_mode: VotingMode,
+    pub court_id: Option<u32>,
+    pub arbitration_fee: Option<i128>, // Set by the admin and minted to both parties
+    pub fee_paid: bool,                // The counterpart matches the creator's fee
+}
+
+impl DisputeParams {
+    /// A public dispute between the parties of the setup, without fee
+    pub fn new(setup: &TestSetup) -> Self {
+        DisputeParams {
+            creator: setup.creator.clone(),
+            counterpart: setup.counterpart.clone(),
+            voting_duration: setup.voting_duration,
+            called_contract: setup.contract_id.clone(),
+            voting_mode: VotingMode::Public,
+            court_id: None,
+            arbitration_fee: None,
+            fee_paid: false,
+        }
+    }
+
+    /// A public dispute charging the arbitration fee, paid by the creator only
+    pub fn with_fee(setup: &TestSetup) -> Self {
+        DisputeParams {
+            arbitration_fee: Some(FEE),
+            ..DisputeParams::new(setup)
+        }
+    }
+}
+
+/// Helper function to open a dispute, returning the contract error if any
+pub fn try_open_dispute(setup: &TestSetup, params: &DisputeParams) -> Result<DisputeRecord, Error> {
+    if let Some(fee) = params.arbitration_fee {
+        setup
+            .contract
+            .set_arbitration_fee(&setup.contract_admin, &fee);
+        setup.token_stellar.mint(&params.creator, &fee);
+        setup.token_stellar.mint(&params.counterpart, &fee);
+    }
+
+    let dispute = match setup.contract.try_create_dispute_demo(
+        &setup.project_id,
+        &params.creator,
+        &params.counterpart,
+        &setup.proof,
+        &params.voting_duration,
+        &params.called_contract,
+        &params.voting_mode,
+        &params.court_id,
+    ) {
+        Ok(Ok(dispute)) => dispute,
+        Err(Ok(error)) => return Err(error),
+        _ => panic!("unexpected result"),
+    };
+    if params.fee_paid {
+        return Ok(setup
+            .contract
+            .pay_arbitration_fee(&params.counterpart, &dispute.dispute_id));
+    }
+    Ok(dispute)
+}
+
+/// Helper function to open a dispute
+pub fn open_dispute(setup: &TestSetup, params: &DisputeParams) -> DisputeRecord {
+    match try_open_dispute(setup, params) {
+        Ok(dispute) => dispute,
+        Err(error) => panic!("the dispute cannot be opened: {error:?}"),
+    }
+}
+
+pub fn init_contract(setup: &TestSetup) -> DisputeRecord {
+    init_contract_with_mode(setup, VotingMode::CommitReveal)
+}
+
+pub fn init_contract_with_mode(setup: &TestSetup, voting_mode: VotingMode) -> DisputeRecord {
     let _name = String::from_str(&setup.env, "tansu");
     let _url = String::from_str(&setup.env, "github.com/tansu");
     let _ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
@@ -107,16 +199,15 @@ pub fn init_contract(setup: &TestSetup) -> Dispute {
     //setup.token_stellar.mint(&setup.grogu, &genesis_amount);
     //setup.token_stellar.mint(&setup.mando, &genesis_amount);
 
-    let dispute = setup.contract.create_dispute_demo(
-        &setup.project_id,
-        &setup.creator,
-        &setup.counterpart,
-        &setup.proof,
-        &setup.voting_ends_at,
-        &setup.contract_id,
+    let dispute = open_dispute(
+        setup,
+        &DisputeParams {
+            voting_mode,
+            ..DisputeParams::new(setup)
+        },
     );
 
-    assert_eq!(dispute.vote_data.votes, vec![&setup.env]);
+    assert_eq!(dispute.vote_count, 0);
 
     setup.contract.new_voter(&setup.judge1);
     setup
@@ -125,3 +216,102 @@ pub fn init_contract(setup: &TestSetup) -> Dispute {
 
     dispute
 }
+
+/// Stake voting a dispute locks when seating a juror, in [`stake_judges`]
+pub const JUROR_STAKE: i128 = 100;
+
+/// Helper function to lock `JUROR_STAKE` from each judge of the setup when
+/// seated, so they can vote with a weight up to it
+pub fn stake_judges(setup: &TestSetup) {
+    let config = setup.contract.get_protocol_config();
+    let config = ProtocolConfig {
+        evidence_duration: config.min_voting_duration,
+        juror_stake: JUROR_STAKE,
+        ..config
+    };
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+    for judge in [&setup.judge1, &setup.judge2, &setup.judge3] {
+        setup.contract.new_voter(judge);
+        setup.token_stellar.mint(judge, &JUROR_STAKE);
+        setup.contract.stake(judge, &JUROR_STAKE);
+    }
+}
+
+/// Helper function to build an anonymous vote with commitments computed
+/// from the given votes and seeds using the project's voting config
+pub fn build_anonymous_vote(
+    setup: &TestSetup,
+    voter: &Address,
+    weight: u32,
+    votes: Vec<u128>,
+    seeds: Vec<u128>,
+) -> VoteAnon {
+    let placeholder = String::from_str(&setup.env, "encrypted");
+    VoteAnon::AnonymousVote(AnonymousVote {
+        address: voter.clone(),
+        weight,
+        encrypted_seeds: vec![
+            &setup.env,
+            placeholder.clone(),
+            placeholder.clone(),
+            placeholder.clone(),
+        ],
+        encrypted_votes: vec![
+            &setup.env,
+            placeholder.clone(),
+            placeholder.clone(),
+            placeholder,
+        ],
+        commitments: setup
+            .contract
+            .build_commitments_from_votes(&setup.project_id, &votes, &seeds),
+        justification_commit: None,
+    })
+}
+
+/// Helper function to build a public vote
+pub fn build_public_vote(voter: &Address, weight: u32, vote_choice: VoteChoice) -> VoteAnon {
+    VoteAnon::PublicVote(PublicVote {
+        address: voter.clone(),
+        weight,
+        vote_choice,
+    })
+}
+
+/// Helper function to let the ruling of a dispute become final
+pub fn end_appeal_period(setup: &TestSetup) {
+    let now = setup.env.ledger().timestamp();
+    setup.env.ledger().set_timestamp(now + APPEAL_PERIOD);
+}
+
+/// Helper function to seat a voter on a public dispute and cast their vote
+pub fn seat_and_vote(
+    setup: &TestSetup,
+    voter: &Address,
+    dispute_id: u32,
+    weight: u32,
+    vote_choice: VoteChoice,
+) {
+    setup.contract.register_to_vote(voter, &dispute_id);
+    setup.contract.vote(
+        voter,
+        &dispute_id,
+        &build_public_vote(voter, weight, vote_choice),
+    );
+}
+
+/// Helper function to settle every juror of a ruled dispute
+pub fn settle_all_jurors(setup: &TestSetup, dispute_id: u32) {
+    loop {
+        match setup
+            .contract
+            .try_settle_jurors(&dispute_id, &SETTLE_PAGE_SIZE)
+        {
+            Ok(Ok(record)) if record.settled_count < record.juror_count => {}
+            Ok(Ok(_)) | Err(Ok(Error::AlreadyClaimed)) => return,
+            _ => panic!("the jurors cannot be settled"),
+        }
+    }
+}
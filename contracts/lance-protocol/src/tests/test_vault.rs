@@ -0,0 +1,246 @@
+use soroban_sdk::{Address, testutils::Address as _, testutils::Ledger, token};
+
+use crate::storage::config::ProtocolConfig;
+use crate::storage::error::Error;
+use crate::storage::vault::StakeBalance;
+use crate::storage::vote::VoteChoice;
+use crate::tests::test_utils::{
+    DisputeParams, TestSetup, build_public_vote, create_test_data, end_appeal_period, open_dispute,
+    settle_all_jurors,
+};
+
+const STAKE: i128 = 500;
+
+/// Register a voter, stake in the vault and take a seat on the dispute
+fn seat(setup: &TestSetup, juror: &Address, dispute_id: u32, stake: i128) {
+    setup.contract.new_voter(juror);
+    setup.token_stellar.mint(juror, &stake);
+    setup.contract.stake(juror, &stake);
+    setup.contract.register_to_vote(juror, &dispute_id);
+}
+
+#[test]
+fn test_stake_locked_until_ruling() {
+    let setup = create_test_data();
+    let outsider = Address::generate(&setup.env);
+    assert_eq!(
+        setup.contract.try_stake(&outsider, &STAKE),
+        Err(Ok(Error::UserNotFound))
+    );
+
+    let config = ProtocolConfig {
+        evidence_duration: 24 * 3600,
+        juror_stake: 300,
+        ..ProtocolConfig::default()
+    };
+    let result = setup.contract.try_set_protocol_config(
+        &setup.contract_admin,
+        &ProtocolConfig {
+            juror_stake: -1,
+            ..config.clone()
+        },
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+
+    setup.contract.new_voter(&setup.judge1);
+    setup.token_stellar.mint(&setup.judge1, &STAKE);
+    assert_eq!(
+        setup.contract.try_stake(&setup.judge1, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    setup.contract.stake(&setup.judge1, &200);
+    assert!(matches!(
+        setup
+            .contract
+            .try_register_to_vote(&setup.judge1, &dispute.dispute_id),
+        Err(Ok(Error::InsufficientStake))
+    ));
+
+    // a seat only locks the stake the dispute asks for
+    setup.contract.stake(&setup.judge1, &(STAKE - 200));
+    setup
+        .contract
+        .register_to_vote(&setup.judge1, &dispute.dispute_id);
+    let stake = setup.contract.get_stake(&setup.judge1);
+    assert_eq!(stake.locked, 300);
+    assert_eq!(stake.free, 200);
+    assert_eq!(
+        setup.contract.try_request_unstake(&setup.judge1, &201),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+    );
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(setup.contract.get_stake(&setup.judge1).locked, 300);
+
+    // settling the jurors releases the stake
+    settle_all_jurors(&setup, dispute.dispute_id);
+    assert!(matches!(
+        setup.contract.try_settle_jurors(&dispute.dispute_id, &1),
+        Err(Ok(Error::AlreadyClaimed))
+    ));
+    assert_eq!(setup.contract.get_stake(&setup.judge1).free, STAKE);
+    assert_eq!(
+        setup
+            .contract
+            .try_request_unstake(&setup.judge1, &(STAKE + 1)),
+        Err(Ok(Error::InsufficientStake))
+    );
+    let unbonding_ends_at = setup.voting_ends_at + ProtocolConfig::default().unbonding_period;
+    assert_eq!(
+        setup.contract.request_unstake(&setup.judge1, &200),
+        StakeBalance {
+            locked: 0,
+            free: 300,
+            unbonding: 200,
+            unbonding_ends_at,
+        }
+    );
+
+    assert_eq!(
+        setup.contract.try_withdraw_stake(&setup.judge1),
+        Err(Ok(Error::InsufficientTime))
+    );
+    setup.env.ledger().set_timestamp(unbonding_ends_at);
+    assert_eq!(setup.contract.withdraw_stake(&setup.judge1), 200);
+    assert_eq!(
+        setup.contract.try_withdraw_stake(&setup.judge1),
+        Err(Ok(Error::BalanceIsZero))
+    );
+    let token = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+    assert_eq!(token.balance(&setup.judge1), 200);
+    assert_eq!(setup.contract.get_stake(&setup.judge1).free, 300);
+}
+
+#[test]
+fn test_absent_juror_slashed() {
+    let setup = create_test_data();
+    let asset = setup.token_stellar.address.clone();
+    let config = ProtocolConfig {
+        evidence_duration: 24 * 3600,
+        juror_stake: 301,
+        missed_vote_slash_bps: 5_000,
+        ..ProtocolConfig::default()
+    };
+    let result = setup.contract.try_set_protocol_config(
+        &setup.contract_admin,
+        &ProtocolConfig {
+            missed_vote_slash_bps: 10_001,
+            ..config.clone()
+        },
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+    for judge in [&setup.judge1, &setup.judge2] {
+        seat(&setup, judge, dispute.dispute_id, 301);
+    }
+    seat(&setup, &setup.judge3, dispute.dispute_id, 303);
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup.contract.vote(
+            judge,
+            &dispute.dispute_id,
+            &build_public_vote(judge, 1, VoteChoice::Creator),
+        );
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+
+    settle_all_jurors(&setup, dispute.dispute_id);
+
+    // half the locked stake is shared by the voters, the remainder is kept
+    // for emissions
+    assert_eq!(setup.contract.get_stake(&setup.judge3).free, 153);
+    assert_eq!(
+        setup.contract.get_dispute(&dispute.dispute_id).slashed_pool,
+        150
+    );
+    end_appeal_period(&setup);
+    for judge in [&setup.judge1, &setup.judge2] {
+        setup.contract.claim_reward(judge, &dispute.dispute_id);
+    }
+    assert_eq!(setup.contract.get_balance(&setup.judge1, &asset), 75);
+    assert_eq!(setup.contract.get_balance(&setup.judge2, &asset), 75);
+    assert_eq!(setup.contract.get_emissions().reservoir, 0);
+    assert_eq!(
+        setup.contract.get_juror_stats(&setup.judge3).missed_votes,
+        1
+    );
+}
+
+#[test]
+fn test_incoherent_stake_redistributed() {
+    let setup = create_test_data();
+    let asset = setup.token_stellar.address.clone();
+    let config = ProtocolConfig {
+        evidence_duration: 24 * 3600,
+        juror_stake: 1_000,
+        incoherent_slash_bps: 1_000,
+        ..ProtocolConfig::default()
+    };
+    setup
+        .contract
+        .set_protocol_config(&setup.contract_admin, &config);
+    let dispute = open_dispute(&setup, &DisputeParams::new(&setup));
+    for judge in [&setup.judge1, &setup.judge2, &setup.judge3] {
+        seat(&setup, judge, dispute.dispute_id, 1_000);
+    }
+    for (judge, weight, choice) in [
+        (&setup.judge1, 1, VoteChoice::Creator),
+        (&setup.judge2, 2, VoteChoice::Creator),
+        (&setup.judge3, 2, VoteChoice::Counterpart),
+    ] {
+        setup.contract.vote(
+            judge,
+            &dispute.dispute_id,
+            &build_public_vote(judge, weight, choice),
+        );
+    }
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    settle_all_jurors(&setup, dispute.dispute_id);
+
+    // a tenth of the locked stake per unit of weight joins the slashed pool
+    assert_eq!(
+        setup.contract.get_dispute(&dispute.dispute_id).slashed_pool,
+        200
+    );
+    assert_eq!(setup.contract.get_stake(&setup.judge3).free, 800);
+
+    end_appeal_period(&setup);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge2, &dispute.dispute_id);
+    assert_eq!(setup.contract.get_balance(&setup.judge1, &asset), 66);
+    assert_eq!(setup.contract.get_balance(&setup.judge2, &asset), 133);
+
+    // the rounding remainder of the protocol token feeds the reservoir
+    assert_eq!(setup.contract.get_emissions().reservoir, 1);
+    assert_eq!(
+        setup
+            .contract
+            .try_sweep_reward_dust(&setup.contract_admin, &asset, &setup.contract_admin),
+        Err(Ok(Error::BalanceIsZero))
+    );
+}
@@ -1,5 +1,6 @@
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{testutils::Address as _, vec};
 
+use crate::storage::vote::VotingMode;
 use crate::tests::test_utils::create_test_data;
 
 #[test]
@@ -65,14 +66,22 @@ fn test_voter_can_be_judge() {
         &setup.creator,
         &setup.counterpart,
         &setup.proof,
-        &setup.voting_ends_at,
+        &setup.voting_duration,
         &setup.contract_id,
+        &VotingMode::CommitReveal,
+        &None,
     );
 
     // Register the voter as a judge
     let updated_dispute = setup.contract.register_to_vote(&judge, &dispute.dispute_id);
 
-    assert!(updated_dispute.able_to_vote.contains(&judge));
+    assert_eq!(updated_dispute.juror_count, 1);
+    assert_eq!(
+        setup
+            .contract
+            .get_dispute_jurors(&dispute.dispute_id, &0, &10),
+        vec![&setup.env, judge]
+    );
 }
 
 #[test]
@@ -0,0 +1,416 @@
+use soroban_sdk::{Bytes, BytesN, String, testutils::Ledger, vec};
+
+use crate::storage::dispute_status::DisputeStatus;
+use crate::storage::error::Error;
+use crate::storage::vote::{VoteAnon, VoteChoice, VotingMode};
+use crate::tests::test_utils::{
+    JUROR_STAKE, TestSetup, build_anonymous_vote, build_public_vote, compute_commit_hash,
+    create_test_data, end_appeal_period, init_contract, init_contract_with_mode, settle_all_jurors,
+    stake_judges,
+};
+
+fn setup_anonymous_config(setup: &TestSetup) {
+    let public_key = String::from_str(&setup.env, "public key random");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.contract_admin, &setup.project_id, &public_key);
+}
+
+#[test]
+fn test_public_votes_tallied_on_chain() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+    assert_eq!(dispute.voting_mode, VotingMode::Public);
+
+    let vote1 = build_public_vote(&setup.judge1, 5, VoteChoice::Creator);
+    let vote2 = build_public_vote(&setup.judge2, 2, VoteChoice::Counterpart);
+    let vote3 = build_public_vote(&setup.judge3, 1, VoteChoice::Abstain);
+    for judge in [&setup.judge2, &setup.judge3] {
+        setup.contract.new_voter(judge);
+        setup.contract.register_to_vote(judge, &dispute.dispute_id);
+    }
+    setup
+        .contract
+        .vote(&setup.judge1, &dispute.dispute_id, &vote1);
+    setup
+        .contract
+        .vote(&setup.judge2, &dispute.dispute_id, &vote2);
+    setup
+        .contract
+        .vote(&setup.judge3, &dispute.dispute_id, &vote3);
+
+    let voted = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(voted.vote_data.votes, vec![&setup.env, vote1, vote2, vote3]);
+
+    // no tallies or seeds needed, 5 > 2 + 1
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(status, DisputeStatus::CREATOR);
+
+    let executed = setup.contract.get_dispute(&dispute.dispute_id);
+    assert_eq!(executed.votes_for, 5);
+    assert_eq!(executed.votes_against, 2);
+    assert_eq!(executed.winner, Some(setup.creator.clone()));
+}
+
+#[test]
+fn test_public_claim_reward_requires_majority_choice() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 3, VoteChoice::Counterpart),
+    );
+    setup.contract.vote(
+        &setup.judge2,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge2, 1, VoteChoice::Creator),
+    );
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    let status = setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+    assert_eq!(status, DisputeStatus::COUNTERPART);
+
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+    assert_eq!(setup.contract.get_user(&setup.judge1).reputation, 1);
+
+    assert_eq!(
+        setup
+            .contract
+            .try_claim_reward(&setup.judge2, &dispute.dispute_id),
+        Err(Ok(Error::NotWithMajority))
+    );
+}
+
+#[test]
+fn test_commit_reveal_claim_reward_uses_revealed_vote() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+
+    let secret1 = Bytes::from_slice(&setup.env, b"secret_1");
+    let secret2 = Bytes::from_slice(&setup.env, b"secret_2");
+    setup.contract.commit_vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, false, &secret1),
+    );
+    setup.contract.commit_vote(
+        &setup.judge2,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, true, &secret2),
+    );
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup.contract.reveal_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, false, true],
+        &vec![&setup.env, secret1, secret2],
+    );
+
+    // tie goes to the counterpart
+    end_appeal_period(&setup);
+    settle_all_jurors(&setup, dispute.dispute_id);
+    setup
+        .contract
+        .claim_reward(&setup.judge1, &dispute.dispute_id);
+    assert_eq!(
+        setup
+            .contract
+            .try_claim_reward(&setup.judge2, &dispute.dispute_id),
+        Err(Ok(Error::NotWithMajority))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_public_vote_rejected_on_anonymous_dispute() {
+    let setup = create_test_data();
+    setup_anonymous_config(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_anonymous_vote_rejected_on_public_dispute() {
+    let setup = create_test_data();
+    setup_anonymous_config(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+
+    let vote_ = build_anonymous_vote(
+        &setup,
+        &setup.judge1,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128],
+    );
+    setup
+        .contract
+        .vote(&setup.judge1, &dispute.dispute_id, &vote_);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_anonymous_vote_requires_a_seat() {
+    let setup = create_test_data();
+    setup_anonymous_config(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
+    setup.contract.new_voter(&setup.judge2);
+
+    let vote_ = build_anonymous_vote(
+        &setup,
+        &setup.judge2,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 1u128, 2u128, 3u128],
+    );
+    setup
+        .contract
+        .vote(&setup.judge2, &dispute.dispute_id, &vote_);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+fn test_vote_weight_single_without_juror_stake() {
+    let setup = create_test_data();
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 2, VoteChoice::Creator),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+fn test_vote_weight_capped_by_locked_stake() {
+    let setup = create_test_data();
+    stake_judges(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, JUROR_STAKE as u32, VoteChoice::Creator),
+    );
+    setup.contract.vote(
+        &setup.judge2,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge2, JUROR_STAKE as u32 + 1, VoteChoice::Creator),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_vote_rejected_on_commit_reveal_dispute() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &build_public_vote(&setup.judge1, 1, VoteChoice::Creator),
+    );
+}
+
+#[test]
+fn test_commit_rejected_outside_commit_reveal() {
+    let setup = create_test_data();
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+
+    let secret = Bytes::from_slice(&setup.env, b"secret_1");
+    let commit_hash = compute_commit_hash(&setup.env, true, &secret);
+    assert!(matches!(
+        setup
+            .contract
+            .try_commit_vote(&setup.judge1, &dispute.dispute_id, &commit_hash),
+        Err(Ok(Error::WrongVoteType))
+    ));
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    assert!(matches!(
+        setup.contract.try_reveal_votes(
+            &setup.creator,
+            &dispute.dispute_id,
+            &vec![&setup.env],
+            &vec![&setup.env],
+        ),
+        Err(Ok(Error::WrongVoteType))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_execute_rejected_on_commit_reveal_dispute() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.creator, &dispute.dispute_id, &None, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_proof_rejected_on_public_dispute() {
+    let setup = create_test_data();
+    setup_anonymous_config(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Public);
+
+    setup.contract.proof(
+        &dispute.dispute_id,
+        &vec![&setup.env, 0u128, 0u128, 0u128],
+        &vec![&setup.env, 0u128, 0u128, 0u128],
+    );
+}
+
+#[test]
+fn test_justification_attached_at_reveal() {
+    let setup = create_test_data();
+    let dispute = init_contract(&setup);
+    setup.contract.new_voter(&setup.judge2);
+    setup
+        .contract
+        .register_to_vote(&setup.judge2, &dispute.dispute_id);
+    let justification = BytesN::from_array(&setup.env, &[7; 32]);
+
+    // the justification is committed with the vote
+    let secret1 = Bytes::from_slice(&setup.env, b"secret_1");
+    let secret2 = Bytes::from_slice(&setup.env, b"secret_2");
+    let mut justified = secret1.clone();
+    justified.append(&justification.clone().into());
+    setup.contract.commit_vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, true, &justified),
+    );
+    setup.contract.commit_vote(
+        &setup.judge2,
+        &dispute.dispute_id,
+        &compute_commit_hash(&setup.env, true, &secret2),
+    );
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+
+    let result = setup.contract.try_reveal_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, true, true],
+        &vec![&setup.env, secret1.clone(), secret2.clone()],
+    );
+    assert!(matches!(result, Err(Ok(Error::InvalidReveal))));
+
+    setup.contract.reveal_justified_votes(
+        &setup.creator,
+        &dispute.dispute_id,
+        &vec![&setup.env, true, true],
+        &vec![&setup.env, secret1, secret2],
+        &vec![&setup.env, Some(justification.clone()), None],
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_justification(&dispute.dispute_id, &setup.judge1),
+        Some(justification)
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_justification(&dispute.dispute_id, &setup.judge2),
+        None
+    );
+}
+
+#[test]
+fn test_anonymous_justification_keyed_by_nullifier() {
+    let setup = create_test_data();
+    setup_anonymous_config(&setup);
+    let dispute = init_contract_with_mode(&setup, VotingMode::Anonymous);
+    let nullifier = BytesN::from_array(&setup.env, &[3; 32]);
+    let justification = BytesN::from_array(&setup.env, &[7; 32]);
+
+    let mut opening = Bytes::from(nullifier.clone());
+    opening.append(&justification.clone().into());
+    let VoteAnon::AnonymousVote(mut vote) = build_anonymous_vote(
+        &setup,
+        &setup.judge1,
+        1,
+        vec![&setup.env, 1u128, 0u128, 0u128],
+        vec![&setup.env, 5u128, 4u128, 6u128],
+    ) else {
+        panic!("not an anonymous vote");
+    };
+    vote.justification_commit = Some(setup.env.crypto().sha256(&opening).into());
+    setup.contract.vote(
+        &setup.judge1,
+        &dispute.dispute_id,
+        &VoteAnon::AnonymousVote(vote),
+    );
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    setup.contract.execute(
+        &setup.creator,
+        &dispute.dispute_id,
+        &Some(vec![&setup.env, 1u128, 0u128, 0u128]),
+        &Some(vec![&setup.env, 5u128, 4u128, 6u128]),
+    );
+
+    // justifications are published once the ruling is final
+    let result =
+        setup
+            .contract
+            .try_justify_anonymous_vote(&dispute.dispute_id, &nullifier, &justification);
+    assert_eq!(result, Err(Ok(Error::InsufficientTime)));
+    end_appeal_period(&setup);
+
+    let forged = BytesN::from_array(&setup.env, &[8; 32]);
+    let result =
+        setup
+            .contract
+            .try_justify_anonymous_vote(&dispute.dispute_id, &nullifier, &forged);
+    assert_eq!(result, Err(Ok(Error::VoterNotFound)));
+
+    setup
+        .contract
+        .justify_anonymous_vote(&dispute.dispute_id, &nullifier, &justification);
+    assert_eq!(
+        setup
+            .contract
+            .get_anonymous_justification(&dispute.dispute_id, &nullifier),
+        Some(justification.clone())
+    );
+
+    let result =
+        setup
+            .contract
+            .try_justify_anonymous_vote(&dispute.dispute_id, &nullifier, &justification);
+    assert_eq!(result, Err(Ok(Error::AlreadyVoted)));
+}
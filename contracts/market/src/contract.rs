@@ -1,5 +1,6 @@
 use soroban_sdk::{contract, contractimpl, Env, String, Address};
 use crate::methods::{
+    admin::set_lance_protocol,
     service::*,
     balance::*,
     initialize::initialize,
@@ -13,6 +14,8 @@ use crate::storage::{
 pub trait ContractTrait {
     fn __constructor(env: Env, admin: Address, token: Address) -> Result<(), Error>; // Keep as is
 
+    fn set_lance_protocol(env: Env, admin: Address, contract: Address) -> Result<(), Error>;
+
     fn new_user(
         env: Env,
         user: Address, // This is fine, but let's see the impl
@@ -64,6 +67,12 @@ pub trait ContractTrait {
         reason: String,
     ) -> Result<u32, Error> ;
 
+    fn dispute_settled(
+        env: &Env,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<(), Error>;
+
     fn get_balance(env: &Env, 
         employee: Address
     ) -> i128;
@@ -84,6 +93,11 @@ impl ContractTrait for Contract {
         initialize(&env, admin, token)
     }
 
+    // Set the lance-protocol contract disputes are opened on, from the admin.
+    fn set_lance_protocol(env: Env, admin: Address, contract: Address) -> Result<(), Error> {
+        set_lance_protocol(&env, admin, contract)
+    }
+
     fn new_user(
         env: Env,
         user: Address, // The implementation takes Address by value
@@ -158,6 +172,16 @@ impl ContractTrait for Contract {
      ) -> Result<u32, Error> {
           crate::methods::dispute::create_dispute(env, creator, id, proof)
     } 
+
+    // Called back by lance-protocol once both parties settled a dispute,
+    // releasing the escrowed payment per the agreed split.
+    fn dispute_settled(
+        env: &Env,
+        dispute_id: u32,
+        creator_share_bps: u32,
+    ) -> Result<(), Error> {
+        crate::methods::dispute::dispute_settled(env, dispute_id, creator_share_bps)
+    }
     
     // Redeem the balance for the employee, setting it to zero and returning the amount to be transferred.
     fn redeem(
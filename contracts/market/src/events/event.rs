@@ -8,6 +8,17 @@ pub(crate) fn created_dispute(env: &Env, creator: &Address, service_id: &u32) {
     env.events().publish(topics, data);
 }
 
+pub(crate) fn settled_dispute(
+    env: &Env,
+    service_id: &u32,
+    dispute_id: &u32,
+    employer_share_bps: &u32,
+) {
+    let topics = (Symbol::new(env, "settled_dispute"), service_id);
+    let data = (dispute_id, employer_share_bps);
+    env.events().publish(topics, data);
+}
+
 pub(crate) fn created_service(env: &Env, creator: &Address, service_id: &u32) {
     let topics: (Symbol, &u32) = (Symbol::new(env,"created_service"), service_id);
     let data = (creator, service_id);
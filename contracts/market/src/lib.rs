@@ -6,4 +6,7 @@ mod methods;
 mod storage;
 mod utils;
 
+#[cfg(test)]
+mod tests;
+
 pub use crate::contract::Contract;
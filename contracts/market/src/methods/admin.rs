@@ -24,4 +24,26 @@ pub(crate) fn set_admin(env: &Env, admin: &Address) {
     let key = DataKey::Admin;
 
     env.storage().instance().set(&key, admin);
+}
+
+/*
+ * Set the lance-protocol contract disputes are opened on, from the admin.
+*/
+pub fn set_lance_protocol(env: &Env, admin: Address, contract: Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    if get_admin(env)? != admin {
+        return Err(Error::NotAuthorized);
+    }
+
+    let key = DataKey::LanceProtocolContract;
+    env.storage().instance().set(&key, &contract);
+
+    Ok(())
+}
+
+pub(crate) fn get_lance_protocol(env: &Env) -> Result<Address, Error> {
+    let key = DataKey::LanceProtocolContract;
+
+    env.storage().instance().get(&key).ok_or(Error::InvalidKey)
 }
\ No newline at end of file
@@ -1,11 +1,15 @@
-use crate::events::event::created_dispute;
-use crate::storage::{error::Error, service::*, service_status::ServiceStatus, storage::DataKey};
+use crate::events::event::{created_dispute, settled_dispute};
+use crate::methods::{
+    admin::get_lance_protocol,
+    balance::{get_balance, set_balance},
+};
+use crate::storage::{
+    constants::MAX_SHARE_BPS, error::Error, service::*, service_status::ServiceStatus,
+};
 use soroban_sdk::{Address, Env, String};
 
-const TIME_ONE_DAY: u64 = 24 * 60 * 60;
-
 // Import the lance-protocol contract client
-mod lance_protocol {
+pub(crate) mod lance_protocol {
     soroban_sdk::contractimport!(
         file = "../../target/wasm32v1-none/release/lance_protocol.optimized.wasm"
     );
@@ -34,30 +38,23 @@ pub fn create_dispute(
     }
 
     // Get the lance-protocol contract address from storage
-    let lance_protocol_contract = env
-        .storage()
-        .instance()
-        .get::<DataKey, Address>(&DataKey::LanceProtocolContract)
-        .ok_or(Error::InvalidKey)?;
-
-    // Calculate voting end time (e.g., 7 days from now)
-    let voting_ends_at = env.ledger().timestamp() + (7 * TIME_ONE_DAY);
+    let lance_protocol_contract = get_lance_protocol(env)?;
 
-    // Call lance-protocol contract to create the dispute
+    // Call lance-protocol contract to create the dispute, with the court,
+    // durations and fee of the template this market is registered with
     let lance_client = lance_protocol::Client::new(env, &lance_protocol_contract);
-    let dispute = lance_client.create_dispute(
+    let dispute = lance_client.create_template_dispute(
         &service_id,                     // project_id (using service_id as project_id)
         &employer,                       // creator
         &employee,                       // counterpart
         &proof,                          // proof
-        &voting_ends_at,                 // voting_ends_at
         &env.current_contract_address(), // called_contract (this market contract)
-        &service.milestone_payment,      // amount of the service
     );
 
     // Update service status to DISPUTING
     service.status = ServiceStatus::DISPUTING;
     set_service(env, service_id, service.clone());
+    set_disputed_service(env, dispute.dispute_id, service_id);
 
     // Emit event
     created_dispute(env, &creator, &service_id);
@@ -82,11 +79,7 @@ pub fn update_dispute(
     }
 
     // Get the lance-protocol contract address
-    let lance_protocol_contract = env
-        .storage()
-        .instance()
-        .get::<DataKey, Address>(&DataKey::LanceProtocolContract)
-        .ok_or(Error::InvalidKey)?;
+    let lance_protocol_contract = get_lance_protocol(env)?;
 
     // Call lance-protocol to get the dispute
     let lance_client = lance_protocol::Client::new(env, &lance_protocol_contract);
@@ -102,3 +95,45 @@ pub fn update_dispute(
 
     Ok(())
 }
+
+/*
+ * Release the escrowed milestone payment of a disputed service once both
+ * parties settled on lance-protocol, which calls this back. The employer,
+ * creator of the dispute, gets `creator_share_bps` of the payment and the
+ * employee the rest, both to redeem from their balance, and the service is
+ * removed.
+*/
+pub fn dispute_settled(env: &Env, dispute_id: u32, creator_share_bps: u32) -> Result<(), Error> {
+    get_lance_protocol(env)?.require_auth();
+
+    if creator_share_bps > MAX_SHARE_BPS {
+        return Err(Error::InvalidAmount);
+    }
+
+    let service_id = get_disputed_service(env, dispute_id)?;
+    let service = get_service(env, service_id)?;
+    if service.status != ServiceStatus::DISPUTING {
+        return Err(Error::InvalidDisputeStatus);
+    }
+
+    let employer_share =
+        service.milestone_payment * creator_share_bps as i128 / MAX_SHARE_BPS as i128;
+    let employee_share = service.milestone_payment - employer_share;
+    set_balance(
+        env,
+        &service.employer,
+        get_balance(env, &service.employer) + employer_share,
+    );
+    set_balance(
+        env,
+        &service.employee,
+        get_balance(env, &service.employee) + employee_share,
+    );
+
+    remove_service(env, service_id);
+    remove_disputed_service(env, dispute_id);
+
+    settled_dispute(env, &service_id, &dispute_id, &creator_share_bps);
+
+    Ok(())
+}
@@ -10,4 +10,7 @@ pub const SECONDS_PER_MONTH: i128 = 2628000;
 
 pub const SECONDS_PER_WEEK: u64 = 604800;
 
-pub const SECONDS_PER_DAY: u64 = 86400;
\ No newline at end of file
+pub const SECONDS_PER_DAY: u64 = 86400;
+
+/// Basis points of the whole disputed payment
+pub const MAX_SHARE_BPS: u32 = 10_000;
\ No newline at end of file
@@ -33,4 +33,24 @@ pub fn get_service(env: &Env, service_id: u32) -> Result<Service, Error> {
 pub fn remove_service(env: &Env, service_id: u32) {
     let key = DataKey::Services(service_id);
     env.storage().instance().remove(&key);  
+}
+
+pub fn set_disputed_service(env: &Env, dispute_id: u32, service_id: u32) {
+    let key = DataKey::Disputes(dispute_id);
+
+    env.storage().instance().set(&key, &service_id)
+}
+
+pub fn get_disputed_service(env: &Env, dispute_id: u32) -> Result<u32, Error> {
+    let key = DataKey::Disputes(dispute_id);
+
+    env.storage()
+        .instance()
+        .get(&key)
+        .ok_or(Error::DisputeNotFound)
+}
+
+pub fn remove_disputed_service(env: &Env, dispute_id: u32) {
+    let key = DataKey::Disputes(dispute_id);
+    env.storage().instance().remove(&key);
 }
\ No newline at end of file
@@ -9,7 +9,7 @@ pub enum DataKey {
     TotalPrincipal,
     Users(Address),
     Services(u32),
-    Disputes(u32),
+    Disputes(u32), // lance-protocol dispute ID -> disputed service ID
     DisputeId,
     Balances(Address),
     LanceProtocolContract,
@@ -0,0 +1,115 @@
+use soroban_sdk::{
+    Address, Env, String,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+};
+
+use crate::contract::{Contract, ContractClient};
+use crate::methods::dispute::lance_protocol;
+use crate::storage::error::Error;
+
+const SERVICE_ID: u32 = 1;
+const PAYMENT: i128 = 1_000;
+
+struct TestSetup {
+    env: Env,
+    market: ContractClient<'static>,
+    lance: lance_protocol::Client<'static>,
+    token: TokenClient<'static>,
+    employee: Address,
+    employer: Address,
+}
+
+/// Deploy the market next to the real lance-protocol contract, with a
+/// disputed service holding one milestone payment in escrow
+fn create_disputed_service() -> (TestSetup, u32) {
+    let env = Env::default();
+    env.mock_all_auths();
+    // uploading the lance-protocol wasm alone goes over the default budget
+    env.cost_estimate().budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let lance_id = env.register(lance_protocol::WASM, (&admin, sac.address()));
+    let market_id = env.register(Contract, (&admin, sac.address()));
+    let market = ContractClient::new(&env, &market_id);
+    market.set_lance_protocol(&admin, &lance_id);
+    let lance = lance_protocol::Client::new(&env, &lance_id);
+    lance.register_caller(
+        &admin,
+        &market_id,
+        &lance_protocol::DisputeTemplate {
+            court_id: None,
+            juror_count: 5,
+            voting_duration: 7 * 24 * 3600,
+            voting_mode: lance_protocol::VotingMode::Public,
+            arbitration_fee: Some(0),
+            ruling_options: 3,
+            token: None,
+        },
+    );
+
+    let employee = Address::generate(&env);
+    let employer = Address::generate(&env);
+    StellarAssetClient::new(&env, &sac.address()).mint(&employer, &PAYMENT);
+    market.create_service(&employee, &employer, &SERVICE_ID, &30, &None, &PAYMENT);
+    market.accept_service(&employer, &SERVICE_ID);
+    let dispute_id = market.create_dispute(
+        &employer,
+        &SERVICE_ID,
+        &String::from_str(&env, "milestone not delivered"),
+    );
+
+    let setup = TestSetup {
+        lance,
+        token: TokenClient::new(&env, &sac.address()),
+        env,
+        market,
+        employee,
+        employer,
+    };
+    (setup, dispute_id)
+}
+
+#[test]
+fn test_settlement_releases_escrow() {
+    let (setup, dispute_id) = create_disputed_service();
+
+    setup
+        .lance
+        .propose_settlement(&setup.employer, &dispute_id, &7_000);
+    setup
+        .lance
+        .accept_settlement(&setup.employee, &dispute_id, &7_000);
+
+    // lance-protocol called the market back with the agreed split
+    assert_eq!(setup.market.get_balance(&setup.employer), 700);
+    assert_eq!(setup.market.get_balance(&setup.employee), 300);
+    assert!(matches!(
+        setup.market.try_get_service(&SERVICE_ID),
+        Err(Ok(Error::ServiceNotFound))
+    ));
+
+    setup.market.redeem(&setup.employee);
+    assert_eq!(setup.token.balance(&setup.employee), 300);
+    assert_eq!(setup.token.balance(&setup.market.address), 700);
+}
+
+#[test]
+fn test_only_lance_protocol_settles() {
+    let (setup, dispute_id) = create_disputed_service();
+
+    // without the protocol's authorization the escrow stays locked
+    setup.env.set_auths(&[]);
+    assert!(
+        setup
+            .market
+            .try_dispute_settled(&dispute_id, &10_000)
+            .is_err()
+    );
+    assert_eq!(setup.market.get_balance(&setup.employer), 0);
+    assert_eq!(
+        setup.market.get_service(&SERVICE_ID).milestone_payment,
+        PAYMENT
+    );
+}
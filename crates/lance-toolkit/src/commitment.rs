@@ -0,0 +1,93 @@
+//! BLS12-381 vote commitments, computed exactly like the contract does.
+//!
+//! A commitment to a vote `v` with seed `r` is `C = g*v + h*r` where `g` and
+//! `h` are hashed to G1 from the `VOTE_GENERATOR` and `SEED_GENERATOR`
+//! messages. Points are serialized uncompressed (96 bytes), the format of
+//! `BytesN<96>` in the contract.
+
+use ark_bls12_381::{Fr, G1Affine, G1Projective, g1::Config as G1Config};
+use ark_ec::{
+    CurveGroup,
+    hashing::{HashToCurve, curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher},
+};
+use ark_ff::field_hashers::DefaultFieldHasher;
+use ark_serialize::CanonicalSerialize;
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::Sha256;
+
+use crate::error::Error;
+
+pub const G1_SERIALIZED_SIZE: usize = 96;
+
+const VOTE_GENERATOR: &[u8] = b"VOTE_GENERATOR";
+const VOTE_DST: &[u8] = b"VOTE_COMMITMENT";
+const SEED_GENERATOR: &[u8] = b"SEED_GENERATOR";
+const SEED_DST: &[u8] = b"VOTE_SEED";
+
+/// Largest weight of a single vote, as enforced by the contract.
+pub const MAX_VOTE_WEIGHT: u128 = 100;
+/// Largest number of votes on a dispute or ballot, as enforced by the contract.
+pub const MAX_VOTES: u128 = 1000;
+/// Exclusive upper bound of the seeds, 2^100.
+///
+/// A full tally of `MAX_VOTES` votes of `MAX_VOTE_WEIGHT` each sums to less
+/// than `SEED_BOUND * MAX_VOTE_WEIGHT * MAX_VOTES`, which fits in a u128.
+pub const SEED_BOUND: u128 = 1 << 100;
+
+fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    MapToCurveBasedHasher::<G1Projective, DefaultFieldHasher<Sha256, 128>, WBMap<G1Config>>::new(
+        dst,
+    )
+    .and_then(|hasher| hasher.hash(msg))
+    .expect("hash to curve with constant inputs")
+}
+
+/// Generator point for the votes, `AnonymousVoteConfig.vote_generator_point`
+pub fn vote_generator() -> G1Affine {
+    hash_to_g1(VOTE_GENERATOR, VOTE_DST)
+}
+
+/// Generator point for the seeds, `AnonymousVoteConfig.seed_generator_point`
+pub fn seed_generator() -> G1Affine {
+    hash_to_g1(SEED_GENERATOR, SEED_DST)
+}
+
+/// Serialize a point like the contract's `G1Affine::to_bytes`.
+pub fn to_bytes(point: &G1Affine) -> [u8; G1_SERIALIZED_SIZE] {
+    let mut bytes = [0u8; G1_SERIALIZED_SIZE];
+    point
+        .serialize_uncompressed(&mut bytes[..])
+        .expect("buffer of the serialized size");
+    bytes
+}
+
+/// Commit to a single vote value with its seed.
+pub fn commitment(vote: u128, seed: u128) -> [u8; G1_SERIALIZED_SIZE] {
+    let point = G1Projective::from(vote_generator()) * Fr::from(vote)
+        + G1Projective::from(seed_generator()) * Fr::from(seed);
+    to_bytes(&point.into_affine())
+}
+
+/// Build one commitment per option, like the contract's `build_commitments_from_votes`.
+pub fn build_commitments(
+    votes: &[u128],
+    seeds: &[u128],
+) -> Result<Vec<[u8; G1_SERIALIZED_SIZE]>, Error> {
+    if votes.len() != seeds.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    Ok(votes
+        .iter()
+        .zip(seeds)
+        .map(|(vote, seed)| commitment(*vote, *seed))
+        .collect())
+}
+
+/// Generate one random seed per option.
+///
+/// Seeds are drawn uniformly below `SEED_BOUND` so the weighted sums the
+/// maintainer submits to `execute` always fit in a u128.
+pub fn random_seeds<R: RngCore + CryptoRng>(rng: &mut R, options: usize) -> Vec<u128> {
+    (0..options).map(|_| rng.gen_range(0..SEED_BOUND)).collect()
+}
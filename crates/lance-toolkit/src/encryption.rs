@@ -0,0 +1,75 @@
+//! RSA-OAEP encryption of votes and seeds.
+//!
+//! Matches the front-end (WebCrypto `RSA-OAEP` with SHA-256 and an empty
+//! label): public keys are base64 SubjectPublicKeyInfo documents, as stored in
+//! `AnonymousVoteConfig.public_key`, private keys are base64 PKCS#8 documents
+//! and ciphertexts are base64 strings.
+//!
+//! Keys are parsed and used by the `rsa` crate. Decryption is blinded, and a
+//! failure does not tell which check of the padding failed.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use rand::{CryptoRng, RngCore, rngs::OsRng};
+use rsa::{
+    Oaep, RsaPrivateKey, RsaPublicKey,
+    pkcs8::{DecodePrivateKey, DecodePublicKey},
+};
+use sha2::Sha256;
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey(RsaPublicKey);
+
+#[derive(Clone)]
+pub struct PrivateKey(RsaPrivateKey);
+
+/// Decode base64, ignoring whitespace and line breaks.
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let clean: String = encoded.chars().filter(|c| !c.is_whitespace()).collect();
+    STANDARD.decode(clean).ok()
+}
+
+impl PublicKey {
+    /// Parse a base64 SubjectPublicKeyInfo document.
+    pub fn from_base64(encoded: &str) -> Result<Self, Error> {
+        let der = decode_base64(encoded).ok_or(Error::InvalidKey)?;
+        RsaPublicKey::from_public_key_der(&der)
+            .map(PublicKey)
+            .map_err(|_| Error::InvalidKey)
+    }
+
+    /// Encrypt a message and return the base64 ciphertext.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        message: &[u8],
+    ) -> Result<String, Error> {
+        let ciphertext = self
+            .0
+            .encrypt(rng, Oaep::new::<Sha256>(), message)
+            .map_err(|error| match error {
+                rsa::Error::MessageTooLong => Error::MessageTooLong,
+                _ => Error::InvalidKey,
+            })?;
+        Ok(STANDARD.encode(ciphertext))
+    }
+}
+
+impl PrivateKey {
+    /// Parse a base64 PKCS#8 document.
+    pub fn from_base64(encoded: &str) -> Result<Self, Error> {
+        let der = decode_base64(encoded).ok_or(Error::InvalidKey)?;
+        RsaPrivateKey::from_pkcs8_der(&der)
+            .map(PrivateKey)
+            .map_err(|_| Error::InvalidKey)
+    }
+
+    /// Decrypt a base64 ciphertext.
+    pub fn decrypt(&self, ciphertext: &str) -> Result<Vec<u8>, Error> {
+        let ciphertext = decode_base64(ciphertext).ok_or(Error::Decryption)?;
+        self.0
+            .decrypt_blinded(&mut OsRng, Oaep::new::<Sha256>(), &ciphertext)
+            .map_err(|_| Error::Decryption)
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The key is not a base64 RSA SubjectPublicKeyInfo or PKCS#8 document
+    InvalidKey,
+    /// The message is too long for the key
+    MessageTooLong,
+    /// The ciphertext is not valid base64 or could not be decrypted
+    Decryption,
+    /// The decrypted plaintext is not of the form `vote:<n>` or `seed:<n>`
+    InvalidPlaintext,
+    /// Votes, seeds or encrypted values do not have the same length
+    LengthMismatch,
+    /// The chosen option does not exist
+    InvalidChoice,
+    /// A weighted tally does not fit in a u128
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::InvalidKey => "invalid RSA key",
+            Error::MessageTooLong => "message too long for the RSA key",
+            Error::Decryption => "decryption error",
+            Error::InvalidPlaintext => "invalid decrypted vote or seed",
+            Error::LengthMismatch => "votes, seeds and options do not have the same length",
+            Error::InvalidChoice => "the chosen option does not exist",
+            Error::Overflow => "weighted tally overflows u128",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,21 @@
+//! Off-chain toolkit for Lance Protocol anonymous voting.
+//!
+//! Calling `build_commitments_from_votes` on the contract, even in simulation,
+//! sends the votes and seeds to an RPC node. This crate does the same work
+//! locally:
+//!
+//! - jurors generate seeds, encrypt their votes and seeds to the project's
+//!   public key and commit to them ([`vote::prepare_vote`]),
+//! - maintainers decrypt the votes of a dispute or ballot and sum them into
+//!   the `tallies` and `seeds` expected by `execute` ([`tally::tally`]).
+
+pub mod commitment;
+pub mod encryption;
+pub mod error;
+pub mod tally;
+pub mod vote;
+
+pub use encryption::{PrivateKey, PublicKey};
+pub use error::Error;
+pub use tally::{EncryptedVote, Tally, tally};
+pub use vote::{PreparedVote, one_hot, prepare_vote};
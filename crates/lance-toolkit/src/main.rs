@@ -0,0 +1,194 @@
+use std::{collections::HashMap, fs, process::ExitCode};
+
+use lance_toolkit::{
+    EncryptedVote, PrivateKey, PublicKey,
+    commitment::{build_commitments, seed_generator, to_bytes, vote_generator},
+    one_hot, prepare_vote, tally,
+};
+use rand::rngs::OsRng;
+use serde_json::{Value, json};
+
+const USAGE: &str = "\
+Off-chain juror and tally toolkit for Lance Protocol anonymous voting
+
+Usage:
+  lance-toolkit generators
+  lance-toolkit vote --public-key <KEY> --options <N> --choice <INDEX>
+  lance-toolkit commit --votes <V1,V2,..> --seeds <S1,S2,..>
+  lance-toolkit tally --private-key <KEY> --options <N> --votes <FILE>
+
+Keys are base64 documents, or @<FILE> to read them from a file. The keypair
+JSON downloaded from the front-end can be used directly.
+
+The votes file is the JSON output of `get_dispute`, or a list of votes.
+Disputes have 3 options: creator, counterpart and abstain.";
+
+type CliResult = Result<Value, String>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.split_first() {
+        Some((command, rest)) => parse_flags(rest).and_then(|flags| run(command, &flags)),
+        None => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(output) => {
+            println!("{output:#}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: &str, flags: &HashMap<String, String>) -> CliResult {
+    match command {
+        "generators" => Ok(json!({
+            "vote_generator_point": hex::encode(to_bytes(&vote_generator())),
+            "seed_generator_point": hex::encode(to_bytes(&seed_generator())),
+        })),
+        "vote" => {
+            let public_key =
+                PublicKey::from_base64(&read_key(flag(flags, "public-key")?, "publicKey")?)
+                    .map_err(|e| e.to_string())?;
+            let options = parse_number(flag(flags, "options")?)?;
+            let choice = parse_number(flag(flags, "choice")?)?;
+
+            let votes = one_hot(options, choice).map_err(|e| e.to_string())?;
+            let prepared =
+                prepare_vote(&mut OsRng, &public_key, &votes).map_err(|e| e.to_string())?;
+            Ok(json!({
+                "encrypted_votes": prepared.encrypted_votes,
+                "encrypted_seeds": prepared.encrypted_seeds,
+                "commitments": prepared.commitments.iter().map(hex::encode).collect::<Vec<_>>(),
+            }))
+        }
+        "commit" => {
+            let votes = parse_list(flag(flags, "votes")?)?;
+            let seeds = parse_list(flag(flags, "seeds")?)?;
+            let commitments = build_commitments(&votes, &seeds).map_err(|e| e.to_string())?;
+            Ok(json!(
+                commitments.iter().map(hex::encode).collect::<Vec<_>>()
+            ))
+        }
+        "tally" => {
+            let private_key =
+                PrivateKey::from_base64(&read_key(flag(flags, "private-key")?, "privateKey")?)
+                    .map_err(|e| e.to_string())?;
+            let options = parse_number(flag(flags, "options")?)?;
+            let path = flag(flags, "votes")?;
+            let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            let votes = parse_votes(&serde_json::from_str(&content).map_err(|e| e.to_string())?)?;
+
+            let tally = tally(&private_key, options, &votes).map_err(|e| e.to_string())?;
+            // u128 values are printed as strings, as the stellar CLI expects them
+            Ok(json!({
+                "tallies": tally.tallies.iter().map(u128::to_string).collect::<Vec<_>>(),
+                "seeds": tally.seeds.iter().map(u128::to_string).collect::<Vec<_>>(),
+            }))
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_flags(args: &[String]) -> Result<HashMap<String, String>, String> {
+    let mut flags = HashMap::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let name = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("unexpected argument {arg}\n\n{USAGE}"))?;
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for --{name}"))?;
+        flags.insert(name.to_string(), value.clone());
+    }
+    Ok(flags)
+}
+
+fn flag<'a>(flags: &'a HashMap<String, String>, name: &str) -> Result<&'a str, String> {
+    flags
+        .get(name)
+        .map(String::as_str)
+        .ok_or_else(|| format!("missing --{name}\n\n{USAGE}"))
+}
+
+fn parse_number(value: &str) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("invalid number {value}"))
+}
+
+fn parse_list(value: &str) -> Result<Vec<u128>, String> {
+    value
+        .split(',')
+        .map(|item| {
+            item.trim()
+                .parse()
+                .map_err(|_| format!("invalid number {item}"))
+        })
+        .collect()
+}
+
+/// Read a key given inline or as @<FILE>, possibly a front-end keypair JSON.
+fn read_key(value: &str, field: &str) -> Result<String, String> {
+    let Some(path) = value.strip_prefix('@') else {
+        return Ok(value.to_string());
+    };
+    let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+
+    match serde_json::from_str::<Value>(&content) {
+        Ok(keypair) => keypair[field]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| format!("{path}: missing {field}")),
+        Err(_) => Ok(content),
+    }
+}
+
+/// Collect the anonymous votes of a dispute, skipping public votes.
+fn parse_votes(value: &Value) -> Result<Vec<EncryptedVote>, String> {
+    let votes = match &value["vote_data"]["votes"] {
+        Value::Array(votes) => votes,
+        _ => value
+            .as_array()
+            .ok_or("expected a dispute or a list of votes")?,
+    };
+
+    let mut anonymous_votes = Vec::new();
+    for vote in votes {
+        // enum variants are {"AnonymousVote": {..}} or ["AnonymousVote", {..}]
+        let vote = match vote {
+            Value::Array(variant) if variant.len() == 2 => match variant[0].as_str() {
+                Some("AnonymousVote") => &variant[1],
+                _ => continue,
+            },
+            Value::Object(variant) if variant.contains_key("PublicVote") => continue,
+            Value::Object(variant) => variant.get("AnonymousVote").unwrap_or(vote),
+            _ => return Err(format!("invalid vote {vote}")),
+        };
+
+        let strings = |field: &str| -> Result<Vec<String>, String> {
+            vote[field]
+                .as_array()
+                .and_then(|items| {
+                    items
+                        .iter()
+                        .map(|item| item.as_str().map(str::to_string))
+                        .collect()
+                })
+                .ok_or_else(|| format!("invalid {field} in {vote}"))
+        };
+        anonymous_votes.push(EncryptedVote {
+            weight: vote["weight"]
+                .as_u64()
+                .and_then(|weight| u32::try_from(weight).ok())
+                .ok_or_else(|| format!("invalid weight in {vote}"))?,
+            encrypted_votes: strings("encrypted_votes")?,
+            encrypted_seeds: strings("encrypted_seeds")?,
+        });
+    }
+    Ok(anonymous_votes)
+}
@@ -0,0 +1,75 @@
+//! Maintainer side: decrypt the anonymous votes and sum them.
+
+use crate::{encryption::PrivateKey, error::Error};
+
+/// An anonymous vote as read from the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedVote {
+    pub weight: u32,
+    pub encrypted_votes: Vec<String>,
+    pub encrypted_seeds: Vec<String>,
+}
+
+/// Weighted sums of the votes and seeds, the `tallies` and `seeds` expected
+/// by the contract's `execute` and `finalize_ballot`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally {
+    pub tallies: Vec<u128>,
+    pub seeds: Vec<u128>,
+}
+
+impl Tally {
+    pub fn new(options: usize) -> Self {
+        Tally {
+            tallies: vec![0; options],
+            seeds: vec![0; options],
+        }
+    }
+
+    /// Decrypt a vote and add it, scaled by its weight.
+    pub fn add(&mut self, private_key: &PrivateKey, vote: &EncryptedVote) -> Result<(), Error> {
+        let options = self.tallies.len();
+        if vote.encrypted_votes.len() != options || vote.encrypted_seeds.len() != options {
+            return Err(Error::LengthMismatch);
+        }
+
+        for option in 0..options {
+            let value = decrypt_value(private_key, &vote.encrypted_votes[option], "vote:")?;
+            let seed = decrypt_value(private_key, &vote.encrypted_seeds[option], "seed:")?;
+
+            self.tallies[option] = weighted_add(self.tallies[option], value, vote.weight)?;
+            self.seeds[option] = weighted_add(self.seeds[option], seed, vote.weight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decrypt and sum all the votes of a dispute or ballot.
+pub fn tally(
+    private_key: &PrivateKey,
+    options: usize,
+    votes: &[EncryptedVote],
+) -> Result<Tally, Error> {
+    let mut tally = Tally::new(options);
+    for vote in votes {
+        tally.add(private_key, vote)?;
+    }
+    Ok(tally)
+}
+
+fn decrypt_value(private_key: &PrivateKey, ciphertext: &str, prefix: &str) -> Result<u128, Error> {
+    let plaintext = private_key.decrypt(ciphertext)?;
+    let plaintext = std::str::from_utf8(&plaintext).map_err(|_| Error::InvalidPlaintext)?;
+
+    plaintext
+        .strip_prefix(prefix)
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or(Error::InvalidPlaintext)
+}
+
+fn weighted_add(total: u128, value: u128, weight: u32) -> Result<u128, Error> {
+    value
+        .checked_mul(weight as u128)
+        .and_then(|weighted| total.checked_add(weighted))
+        .ok_or(Error::Overflow)
+}
@@ -0,0 +1,57 @@
+//! Juror side: prepare an anonymous vote.
+
+use rand::{CryptoRng, RngCore};
+
+use crate::{
+    commitment::{G1_SERIALIZED_SIZE, build_commitments, random_seeds},
+    encryption::PublicKey,
+    error::Error,
+};
+
+/// The fields of the contract's `AnonymousVote`, apart from the address and weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedVote {
+    pub encrypted_votes: Vec<String>,
+    pub encrypted_seeds: Vec<String>,
+    pub commitments: Vec<[u8; G1_SERIALIZED_SIZE]>,
+}
+
+/// One-hot encoding of a choice, e.g. `[0, 1, 0]` for the counterpart of a dispute.
+pub fn one_hot(options: usize, choice: usize) -> Result<Vec<u128>, Error> {
+    if choice >= options {
+        return Err(Error::InvalidChoice);
+    }
+    Ok((0..options)
+        .map(|option| (option == choice) as u128)
+        .collect())
+}
+
+/// Encrypt the votes and fresh seeds for the maintainer and commit to them.
+///
+/// The seeds never leave this function in clear, only the maintainer can
+/// decrypt them to compute the seed tallies.
+///
+/// # Arguments
+/// * `rng` - A cryptographically secure random number generator
+/// * `public_key` - The maintainer's key, `AnonymousVoteConfig.public_key`
+/// * `votes` - One value per option, usually from [`one_hot`]
+pub fn prepare_vote<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    public_key: &PublicKey,
+    votes: &[u128],
+) -> Result<PreparedVote, Error> {
+    let seeds = random_seeds(rng, votes.len());
+
+    let mut encrypted_votes = Vec::with_capacity(votes.len());
+    let mut encrypted_seeds = Vec::with_capacity(votes.len());
+    for (vote, seed) in votes.iter().zip(&seeds) {
+        encrypted_votes.push(public_key.encrypt(rng, format!("vote:{vote}").as_bytes())?);
+        encrypted_seeds.push(public_key.encrypt(rng, format!("seed:{seed}").as_bytes())?);
+    }
+
+    Ok(PreparedVote {
+        encrypted_votes,
+        encrypted_seeds,
+        commitments: build_commitments(votes, &seeds)?,
+    })
+}
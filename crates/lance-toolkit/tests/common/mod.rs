@@ -0,0 +1,20 @@
+use lance_toolkit::{PrivateKey, PublicKey};
+use serde_json::Value;
+
+/// Test keypair in the format downloaded from the front-end
+const KEYPAIR: &str = include_str!("../fixtures/keypair.json");
+
+pub fn keypair_field(field: &str) -> String {
+    let keypair: Value = serde_json::from_str(KEYPAIR).unwrap();
+    keypair[field].as_str().unwrap().to_string()
+}
+
+#[allow(dead_code)]
+pub fn public_key() -> PublicKey {
+    PublicKey::from_base64(&keypair_field("publicKey")).unwrap()
+}
+
+#[allow(dead_code)]
+pub fn private_key() -> PrivateKey {
+    PrivateKey::from_base64(&keypair_field("privateKey")).unwrap()
+}
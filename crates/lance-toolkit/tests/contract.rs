@@ -0,0 +1,285 @@
+mod common;
+
+use lance_protocol::{
+    AnonymousVote, BallotConfig, BallotStatus, BallotVote, DisputeStatus, Eligibility,
+    ProtocolConfig, ProtocolContract, ProtocolContractClient, VoteAnon, VotingMode,
+};
+use lance_toolkit::{
+    EncryptedVote,
+    commitment::{build_commitments, seed_generator, to_bytes, vote_generator},
+    one_hot, prepare_vote, tally,
+};
+use rand::rngs::OsRng;
+use soroban_sdk::{
+    Address, BytesN, Env, String, Vec,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+};
+
+use common::{keypair_field, private_key, public_key};
+
+const PROJECT_ID: u32 = 1;
+
+struct Setup {
+    env: Env,
+    contract: ProtocolContractClient<'static>,
+    token: StellarAssetClient<'static>,
+    admin: Address,
+    voting_duration: u64,
+    voting_ends_at: u64,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ProtocolContract, (&admin, sac.address()));
+    let contract = ProtocolContractClient::new(&env, &contract_id);
+    let token = StellarAssetClient::new(&env, &sac.address());
+
+    // the contract only stores the key, the toolkit encrypts to it
+    let public_key = String::from_str(&env, &keypair_field("publicKey"));
+    contract.anonymous_voting_setup(&admin, &PROJECT_ID, &public_key);
+
+    let voting_duration = 24 * 3600;
+    let voting_ends_at = env.ledger().timestamp() + voting_duration;
+    Setup {
+        env,
+        contract,
+        token,
+        admin,
+        voting_duration,
+        voting_ends_at,
+    }
+}
+
+fn to_std_string(string: &String) -> std::string::String {
+    let mut buf = vec![0u8; string.len() as usize];
+    string.copy_into_slice(&mut buf);
+    std::string::String::from_utf8(buf).unwrap()
+}
+
+/// Prepare a vote with the toolkit and convert it for the contract
+fn anonymous_vote(
+    setup: &Setup,
+    voter: &Address,
+    weight: u32,
+    options: usize,
+    choice: usize,
+) -> AnonymousVote {
+    let prepared = prepare_vote(
+        &mut OsRng,
+        &public_key(),
+        &one_hot(options, choice).unwrap(),
+    )
+    .unwrap();
+
+    let strings = |values: &[std::string::String]| {
+        let mut strings = Vec::new(&setup.env);
+        for value in values {
+            strings.push_back(String::from_str(&setup.env, value));
+        }
+        strings
+    };
+    let mut commitments = Vec::new(&setup.env);
+    for commitment in &prepared.commitments {
+        commitments.push_back(BytesN::from_array(&setup.env, commitment));
+    }
+
+    AnonymousVote {
+        address: voter.clone(),
+        weight,
+        encrypted_seeds: strings(&prepared.encrypted_seeds),
+        encrypted_votes: strings(&prepared.encrypted_votes),
+        commitments,
+        justification_commit: None,
+    }
+}
+
+/// Read the encrypted parts of a vote back from the contract
+fn encrypted_vote(vote: &AnonymousVote) -> EncryptedVote {
+    EncryptedVote {
+        weight: vote.weight,
+        encrypted_votes: vote
+            .encrypted_votes
+            .iter()
+            .map(|s| to_std_string(&s))
+            .collect(),
+        encrypted_seeds: vote
+            .encrypted_seeds
+            .iter()
+            .map(|s| to_std_string(&s))
+            .collect(),
+    }
+}
+
+fn to_soroban_vec(env: &Env, values: &[u128]) -> Vec<u128> {
+    let mut vec = Vec::new(env);
+    for value in values {
+        vec.push_back(*value);
+    }
+    vec
+}
+
+#[test]
+fn test_generators_match_contract() {
+    let setup = setup();
+    let config = setup.contract.get_anonymous_voting_config(&PROJECT_ID);
+
+    assert_eq!(
+        config.vote_generator_point.to_array(),
+        to_bytes(&vote_generator())
+    );
+    assert_eq!(
+        config.seed_generator_point.to_array(),
+        to_bytes(&seed_generator())
+    );
+}
+
+#[test]
+fn test_commitments_match_contract() {
+    let setup = setup();
+    let votes = [0u128, 1, 0];
+    let seeds = [u64::MAX as u128, 42, 7];
+
+    let expected = setup.contract.build_commitments_from_votes(
+        &PROJECT_ID,
+        &to_soroban_vec(&setup.env, &votes),
+        &to_soroban_vec(&setup.env, &seeds),
+    );
+    let commitments = build_commitments(&votes, &seeds).unwrap();
+
+    assert_eq!(expected.len() as usize, commitments.len());
+    for (expected, commitment) in expected.iter().zip(&commitments) {
+        assert_eq!(expected.to_array(), *commitment);
+    }
+}
+
+#[test]
+fn test_dispute_executed_with_toolkit_tallies() {
+    let setup = setup();
+
+    // jurors lock a stake covering the weight of their vote
+    let juror_stake = 4;
+    let config = setup.contract.get_protocol_config();
+    setup.contract.set_protocol_config(
+        &setup.admin,
+        &ProtocolConfig {
+            evidence_duration: config.min_voting_duration,
+            juror_stake,
+            ..config
+        },
+    );
+    let creator = Address::generate(&setup.env);
+    let dispute = setup.contract.create_dispute_demo(
+        &PROJECT_ID,
+        &creator,
+        &Address::generate(&setup.env),
+        &String::from_str(&setup.env, "proof"),
+        &setup.voting_duration,
+        &setup.admin,
+        &VotingMode::Anonymous,
+        &None,
+    );
+
+    // creator, counterpart, creator: 3 + 2 against 4
+    for (weight, choice) in [(3, 0), (4, 1), (2, 0)] {
+        let juror = Address::generate(&setup.env);
+        setup.contract.new_voter(&juror);
+        setup.token.mint(&juror, &juror_stake);
+        setup.contract.stake(&juror, &juror_stake);
+        setup.contract.register_to_vote(&juror, &dispute.dispute_id);
+        let vote_ = anonymous_vote(&setup, &juror, weight, 3, choice);
+        setup
+            .contract
+            .vote(&juror, &dispute.dispute_id, &VoteAnon::AnonymousVote(vote_));
+    }
+
+    // the maintainer reads the votes and tallies them off-chain
+    let votes: std::vec::Vec<EncryptedVote> = setup
+        .contract
+        .get_dispute(&dispute.dispute_id)
+        .vote_data
+        .votes
+        .iter()
+        .map(|vote| match vote {
+            VoteAnon::AnonymousVote(vote) => encrypted_vote(&vote),
+            VoteAnon::PublicVote(_) => unreachable!(),
+        })
+        .collect();
+    let tally = tally(&private_key(), 3, &votes).unwrap();
+    assert_eq!(tally.tallies, [5, 4, 0]);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup.contract.execute(
+        &creator,
+        &dispute.dispute_id,
+        &Some(to_soroban_vec(&setup.env, &tally.tallies)),
+        &Some(to_soroban_vec(&setup.env, &tally.seeds)),
+    );
+    assert_eq!(status, DisputeStatus::CREATOR);
+}
+
+#[test]
+fn test_ballot_finalized_with_toolkit_tallies() {
+    let setup = setup();
+    let voters: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&setup.env)).collect();
+
+    let mut options = Vec::new(&setup.env);
+    let mut eligible = Vec::new(&setup.env);
+    for name in ["alpha", "beta", "gamma", "delta"] {
+        options.push_back(String::from_str(&setup.env, name));
+    }
+    for voter in &voters {
+        eligible.push_back(voter.clone());
+    }
+    let creator = Address::generate(&setup.env);
+    let ballot = setup.contract.open_ballot(
+        &creator,
+        &PROJECT_ID,
+        &BallotConfig {
+            title: String::from_str(&setup.env, "Next feature"),
+            options,
+            eligibility: Eligibility::Voters(eligible),
+            voting_mode: VotingMode::Anonymous,
+            voting_starts_at: setup.env.ledger().timestamp(),
+            voting_ends_at: setup.voting_ends_at,
+            quorum: 3,
+        },
+    );
+
+    for (voter, choice) in voters.iter().zip([3, 3, 1]) {
+        let vote_ = anonymous_vote(&setup, voter, 1, 4, choice);
+        setup
+            .contract
+            .vote_ballot(voter, &ballot.ballot_id, &BallotVote::AnonymousVote(vote_));
+    }
+
+    let votes: std::vec::Vec<EncryptedVote> = setup
+        .contract
+        .get_ballot_votes(&ballot.ballot_id, &0, &(voters.len() as u32))
+        .iter()
+        .map(|vote| match vote {
+            BallotVote::AnonymousVote(vote) => encrypted_vote(&vote),
+            BallotVote::PublicVote(_) => unreachable!(),
+        })
+        .collect();
+    let tally = tally(&private_key(), 4, &votes).unwrap();
+    assert_eq!(tally.tallies, [0, 1, 0, 2]);
+
+    setup.env.ledger().set_timestamp(setup.voting_ends_at);
+    let status = setup.contract.finalize_ballot(
+        &creator,
+        &ballot.ballot_id,
+        &Some(to_soroban_vec(&setup.env, &tally.tallies)),
+        &Some(to_soroban_vec(&setup.env, &tally.seeds)),
+    );
+    assert_eq!(status, BallotStatus::Decided);
+    assert_eq!(
+        setup.contract.get_ballot(&ballot.ballot_id).winning_option,
+        Some(3)
+    );
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use lance_toolkit::{
+    Error, PrivateKey, PublicKey,
+    commitment::{MAX_VOTE_WEIGHT, MAX_VOTES, SEED_BOUND, random_seeds},
+    one_hot, prepare_vote, tally,
+    tally::EncryptedVote,
+};
+use rand::rngs::OsRng;
+
+use common::{private_key, public_key};
+
+#[test]
+fn test_encrypt_decrypt_roundtrip() {
+    let ciphertext = public_key().encrypt(&mut OsRng, b"vote:1").unwrap();
+
+    assert_eq!(private_key().decrypt(&ciphertext).unwrap(), b"vote:1");
+}
+
+#[test]
+fn test_decrypt_webcrypto_compatible_ciphertext() {
+    // RSA-OAEP with SHA-256 and an empty label, like the front-end
+    let ciphertext = include_str!("fixtures/oaep_ciphertext.txt");
+
+    assert_eq!(
+        private_key().decrypt(ciphertext).unwrap(),
+        b"seed:18446744073709551615"
+    );
+}
+
+#[test]
+fn test_encryption_is_randomized() {
+    let public_key = public_key();
+
+    assert_ne!(
+        public_key.encrypt(&mut OsRng, b"vote:0").unwrap(),
+        public_key.encrypt(&mut OsRng, b"vote:0").unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_keys_and_ciphertexts() {
+    assert_eq!(
+        PublicKey::from_base64("public key random"),
+        Err(Error::InvalidKey)
+    );
+    // a private key is not a public key
+    assert!(PublicKey::from_base64(&common::keypair_field("privateKey")).is_err());
+    assert!(PrivateKey::from_base64(&common::keypair_field("publicKey")).is_err());
+
+    let mut ciphertext = public_key().encrypt(&mut OsRng, b"vote:1").unwrap();
+    ciphertext.replace_range(0..4, "AAAA");
+    assert_eq!(private_key().decrypt(&ciphertext), Err(Error::Decryption));
+    assert_eq!(private_key().decrypt("not base64!"), Err(Error::Decryption));
+}
+
+#[test]
+fn test_tally_weighted_votes() {
+    let public_key = public_key();
+    let private_key = private_key();
+
+    let mut votes = Vec::new();
+    for (choice, weight) in [(0, 3), (1, 1), (0, 2)] {
+        let prepared = prepare_vote(&mut OsRng, &public_key, &one_hot(3, choice).unwrap()).unwrap();
+        votes.push(EncryptedVote {
+            weight,
+            encrypted_votes: prepared.encrypted_votes,
+            encrypted_seeds: prepared.encrypted_seeds,
+        });
+    }
+
+    let tally = tally(&private_key, 3, &votes).unwrap();
+    assert_eq!(tally.tallies, vec![5, 1, 0]);
+    assert_eq!(tally.seeds.len(), 3);
+
+    // votes must have one value per option
+    assert_eq!(
+        lance_toolkit::tally(&private_key, 4, &votes),
+        Err(Error::LengthMismatch)
+    );
+    assert_eq!(one_hot(3, 3), Err(Error::InvalidChoice));
+}
+
+#[test]
+fn test_random_seeds_bound() {
+    let seeds = random_seeds(&mut OsRng, 64);
+
+    assert_eq!(seeds.len(), 64);
+    assert!(seeds.iter().all(|seed| *seed < SEED_BOUND));
+    // drawn over the whole range, not from a u64
+    assert!(seeds.iter().any(|seed| *seed > u64::MAX as u128));
+
+    // the largest weighted sum of seeds still fits in a u128
+    assert!(
+        SEED_BOUND
+            .checked_mul(MAX_VOTE_WEIGHT)
+            .and_then(|sum| sum.checked_mul(MAX_VOTES))
+            .is_some()
+    );
+}